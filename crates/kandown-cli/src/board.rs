@@ -0,0 +1,42 @@
+// crates/kandown-cli/src/board.rs
+use anyhow::{Result, anyhow};
+use std::path::Path;
+
+use kandown::{Diagnostic, Kanban};
+
+// Build the board from a file, reporting any warnings on stderr
+pub fn load_board(path: &Path) -> Result<Kanban> {
+    let (board, diagnostics) = Kanban::read_file(path)?;
+    print_diagnostics(path, &diagnostics);
+
+    Ok(board)
+}
+
+pub fn print_diagnostics(path: &Path, diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        match diagnostic.location.as_ref().and_then(|l| l.line) {
+            Some(line) => eprintln!("{}:{line}: {diagnostic}", path.display()),
+            None => eprintln!("{}: {diagnostic}", path.display()),
+        }
+    }
+}
+
+// Resolve an optional view name, defaulting to the board's default view
+pub fn view_name(board: &Kanban, view: Option<&str>) -> Result<String> {
+    match view {
+        Some(name) if board.view_by_name.contains_key(name) => Ok(name.to_string()),
+        Some(name) => Err(anyhow!("View not found: {name}")),
+        None => board
+            .default_view()
+            .map(|view| view.borrow().name.clone())
+            .ok_or_else(|| anyhow!("Board has no views")),
+    }
+}
+
+// Parse a `Name=Value` property assignment
+pub fn parse_assignment(s: &str) -> Result<(String, String), String> {
+    s.split_once('=')
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .filter(|(name, _)| !name.is_empty())
+        .ok_or_else(|| format!("expected NAME=VALUE, got '{s}'"))
+}
@@ -0,0 +1,204 @@
+// crates/kandown-cli/src/cards.rs
+use anyhow::{Result, anyhow};
+use clap::Args;
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
+
+use kandown::{KanbanView, KanbanViewType};
+
+use crate::board;
+
+#[derive(Args)]
+pub struct ListArgs {
+    /// Board markdown file
+    file: PathBuf,
+
+    /// View to list (defaults to the board's default view)
+    #[arg(long)]
+    view: Option<String>,
+}
+
+#[derive(Args)]
+pub struct ShowArgs {
+    /// Board markdown file
+    file: PathBuf,
+
+    /// Card title
+    card: String,
+}
+
+#[derive(Args)]
+pub struct AddArgs {
+    /// Board markdown file
+    file: PathBuf,
+
+    /// Card title
+    title: String,
+
+    /// Card description
+    #[arg(long, short, default_value = "")]
+    description: String,
+
+    /// Property value, may be repeated
+    #[arg(long = "set", value_name = "NAME=VALUE", value_parser = board::parse_assignment)]
+    values: Vec<(String, String)>,
+}
+
+#[derive(Args)]
+pub struct MoveArgs {
+    /// Board markdown file
+    file: PathBuf,
+
+    /// Card title
+    card: String,
+
+    /// Destination column
+    column: String,
+
+    /// Board view whose grouping is used (defaults to the board's default view,
+    /// or the first board view when the default view is not a board)
+    #[arg(long)]
+    view: Option<String>,
+}
+
+#[derive(Args)]
+pub struct EditArgs {
+    /// Board markdown file
+    file: PathBuf,
+
+    /// Card title
+    card: String,
+
+    /// New title
+    #[arg(long)]
+    title: Option<String>,
+
+    /// New description
+    #[arg(long, short)]
+    description: Option<String>,
+
+    /// Property value, may be repeated. An empty value clears the property.
+    #[arg(long = "set", value_name = "NAME=VALUE", value_parser = board::parse_assignment)]
+    values: Vec<(String, String)>,
+}
+
+#[derive(Args)]
+pub struct RmArgs {
+    /// Board markdown file
+    file: PathBuf,
+
+    /// Card title
+    card: String,
+}
+
+pub fn list(args: ListArgs) -> Result<()> {
+    let board = board::load_board(&args.file)?;
+    let view = board::view_name(&board, args.view.as_deref())?;
+
+    let is_grouped = matches!(
+        board.view_by_name[&view].borrow().view_layout,
+        KanbanViewType::Board { group_by: Some(_) }
+    );
+
+    if is_grouped {
+        for (column, cards) in board.get_ordered_groups(&view)? {
+            let column = if column.is_empty() {
+                "No value"
+            } else {
+                column.as_str()
+            };
+            println!("{column} ({})", cards.len());
+            for card_rc in cards {
+                println!("  {}", card_rc.borrow().title);
+            }
+        }
+    } else {
+        for card_rc in board.get_view_cards(&view)? {
+            println!("{}", card_rc.borrow().title);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn show(args: ShowArgs) -> Result<()> {
+    let board = board::load_board(&args.file)?;
+    let card = board
+        .card_by_title
+        .get(&args.card)
+        .ok_or_else(|| anyhow!("Card not found: {}", args.card))?
+        .borrow();
+
+    println!("{}", card.title);
+    for (prop_rc, value) in &card.properties {
+        println!("  {}: {value}", prop_rc.borrow().name);
+    }
+    for prop_rc in &board.properties {
+        let name = &prop_rc.borrow().name;
+        if let Some(value) = board.formula_value(&card, name) {
+            println!("  {name}: {value}");
+        }
+    }
+    if !card.description.is_empty() {
+        println!();
+        println!("{}", card.description);
+    }
+    if let Some(progress) = card.computed_value("Subtasks") {
+        println!();
+        println!("Subtasks ({progress})");
+        for subtask in &card.subtasks {
+            let mark = if subtask.done { 'x' } else { ' ' };
+            println!("  [{mark}] {}", subtask.text);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn add(args: AddArgs) -> Result<()> {
+    let mut board = board::load_board(&args.file)?;
+    if board.card_by_title.contains_key(&args.title) {
+        return Err(anyhow!("A card named '{}' already exists", args.title));
+    }
+
+    board.add_card(
+        args.title,
+        args.description,
+        args.values.into_iter().collect(),
+    )?;
+    board.write_file(&args.file)
+}
+
+pub fn move_card(args: MoveArgs) -> Result<()> {
+    let board = board::load_board(&args.file)?;
+
+    let is_board = |view: &Rc<RefCell<KanbanView>>| {
+        matches!(view.borrow().view_layout, KanbanViewType::Board { .. })
+    };
+    let view = match args.view {
+        Some(view) => view,
+        None => board
+            .default_view()
+            .filter(is_board)
+            .or_else(|| board.views.iter().find(|view| is_board(view)).cloned())
+            .map(|view| view.borrow().name.clone())
+            .ok_or_else(|| anyhow!("Board has no board views"))?,
+    };
+
+    board.move_card(&args.card, &view, &args.column)?;
+    board.write_file(&args.file)
+}
+
+pub fn edit(args: EditArgs) -> Result<()> {
+    let mut board = board::load_board(&args.file)?;
+    let values: HashMap<String, String> = args.values.into_iter().collect();
+
+    board.edit_card(&args.card, args.title, args.description, values)?;
+    board.write_file(&args.file)
+}
+
+pub fn rm(args: RmArgs) -> Result<()> {
+    let mut board = board::load_board(&args.file)?;
+
+    board.remove_card(&args.card)?;
+    board.write_file(&args.file)
+}
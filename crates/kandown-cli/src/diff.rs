@@ -0,0 +1,72 @@
+// crates/kandown-cli/src/diff.rs
+use anyhow::{Context, Result, bail};
+use clap::{Args, ValueEnum};
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use kandown::ParsedDocument;
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    /// One line per change
+    Text,
+    /// JSON array of changes
+    Json,
+}
+
+#[derive(Args)]
+pub struct DiffArgs {
+    /// The old version of the board, or - to read it from stdin
+    /// (e.g. `git show HEAD:board.md | kandown diff - board.md`)
+    old: PathBuf,
+
+    /// The new version of the board, or - to read it from stdin
+    new: PathBuf,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "text")]
+    format: OutputFormat,
+}
+
+pub fn run(args: DiffArgs) -> Result<()> {
+    if is_stdin(&args.old) && is_stdin(&args.new) {
+        bail!("Only one version can be read from stdin");
+    }
+
+    let old = read_board(&args.old)?;
+    let new = read_board(&args.new)?;
+    let changes = old.diff(&new);
+
+    match args.format {
+        OutputFormat::Text => {
+            for change in &changes {
+                println!("{change}");
+            }
+        }
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&changes)?),
+    }
+
+    Ok(())
+}
+
+fn is_stdin(path: &Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+fn read_board(path: &Path) -> Result<ParsedDocument> {
+    let markdown = if is_stdin(path) {
+        let mut markdown = String::new();
+        io::stdin()
+            .read_to_string(&mut markdown)
+            .context("Failed to read stdin")?;
+        markdown
+    } else {
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?
+    };
+
+    ParsedDocument::parse_complete(&markdown)
+        .with_context(|| format!("Failed to parse {}", path.display()))
+}
@@ -0,0 +1,52 @@
+// crates/kandown-cli/src/export.rs
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use std::{fs, path::PathBuf};
+
+use crate::board;
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ExportFormat {
+    /// Self-contained HTML page
+    Html,
+    /// GitHub-flavored markdown table or checklist
+    Gfm,
+}
+
+#[derive(Args)]
+pub struct ExportArgs {
+    /// Board markdown file
+    file: PathBuf,
+
+    /// View to export (defaults to the board's default view)
+    #[arg(long)]
+    view: Option<String>,
+
+    /// Output format
+    #[arg(long, value_enum)]
+    format: ExportFormat,
+
+    /// Write to this file instead of stdout
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+pub fn run(args: ExportArgs) -> Result<()> {
+    let board = board::load_board(&args.file)?;
+    let view = board::view_name(&board, args.view.as_deref())?;
+
+    let rendered = match args.format {
+        ExportFormat::Html => board.render_view_html(&view)?,
+        ExportFormat::Gfm => board.render_view_gfm(&view)?,
+    };
+
+    match &args.output {
+        Some(path) => {
+            fs::write(path, rendered).with_context(|| format!("Failed to write {}", path.display()))
+        }
+        None => {
+            print!("{rendered}");
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// crates/kandown-cli/src/format.rs
+use anyhow::{Context, Result, bail};
+use clap::Args;
+use similar::TextDiff;
+use std::{fs, path::PathBuf};
+
+use kandown::{Kanban, ParsedDocument, write_atomic};
+
+#[derive(Args)]
+pub struct FmtArgs {
+    /// Board markdown files
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+
+    /// Don't write anything; print a diff and fail if a file is not formatted
+    #[arg(long)]
+    check: bool,
+}
+
+pub fn fmt(args: FmtArgs) -> Result<()> {
+    let mut unformatted = 0;
+
+    for path in &args.files {
+        let markdown = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let formatted = ParsedDocument::format_markdown(&markdown)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+
+        if formatted == markdown {
+            continue;
+        }
+
+        if args.check {
+            let name = path.display().to_string();
+            let diff = TextDiff::from_lines(&markdown, &formatted);
+            print!("{}", diff.unified_diff().header(&name, &name));
+            unformatted += 1;
+        } else {
+            // Make sure the board is valid before rewriting it
+            Kanban::from_document(ParsedDocument::try_from(formatted.as_str())?)
+                .with_context(|| format!("Invalid board {}", path.display()))?;
+
+            write_atomic(path, &formatted)?;
+        }
+    }
+
+    if unformatted > 0 {
+        bail!("{unformatted} file(s) need formatting");
+    }
+
+    Ok(())
+}
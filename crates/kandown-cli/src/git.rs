@@ -0,0 +1,21 @@
+// crates/kandown-cli/src/git.rs
+use anyhow::{Context, Result, bail};
+use std::process::Command;
+
+// Run a git command, returning its output
+pub fn git(args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .context("Failed to run git")?;
+
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
@@ -0,0 +1,134 @@
+// crates/kandown-cli/src/history.rs
+use anyhow::{Context, Result, anyhow};
+use clap::{Args, ValueEnum};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use kandown::{Activity, Revision, board_history, card_history};
+
+use crate::git::git;
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    /// One line per change
+    Text,
+    /// JSON array of changes with their revisions
+    Json,
+}
+
+#[derive(Args)]
+pub struct HistoryArgs {
+    /// Board file to read the git history of, or snapshots oldest first with
+    /// --snapshots
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+
+    /// Treat the files as successive versions of the board instead of reading
+    /// git history
+    #[arg(long)]
+    snapshots: bool,
+
+    /// Only show the activity of this card, following renames
+    #[arg(long)]
+    card: Option<String>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "text")]
+    format: OutputFormat,
+}
+
+pub fn run(args: HistoryArgs) -> Result<()> {
+    let revisions = if args.snapshots {
+        snapshot_revisions(&args.files)?
+    } else {
+        let [file] = args.files.as_slice() else {
+            return Err(anyhow!("Give one board file, or use --snapshots"));
+        };
+        git_revisions(file)?
+    };
+
+    let activity = board_history(&revisions);
+    let activity: Vec<&Activity> = match &args.card {
+        Some(title) => card_history(&activity, title),
+        None => activity.iter().collect(),
+    };
+
+    match args.format {
+        OutputFormat::Text => {
+            for item in activity {
+                println!("{item}");
+            }
+        }
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&activity)?),
+    }
+
+    Ok(())
+}
+
+fn snapshot_revisions(files: &[PathBuf]) -> Result<Vec<Revision>> {
+    files
+        .iter()
+        .map(|path| {
+            let markdown = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            Ok(Revision {
+                id: path.display().to_string(),
+                author: None,
+                date: None,
+                markdown,
+            })
+        })
+        .collect()
+}
+
+// Every committed version of a file, oldest first, read from the local
+// repository
+fn git_revisions(file: &Path) -> Result<Vec<Revision>> {
+    let dir = file
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(".".as_ref())
+        .to_string_lossy()
+        .to_string();
+    let name = file
+        .file_name()
+        .ok_or_else(|| anyhow!("Not a file: {}", file.display()))?
+        .to_string_lossy()
+        .to_string();
+
+    let log = git(&[
+        "-C",
+        &dir,
+        "log",
+        "--reverse",
+        "--format=%H%x09%an%x09%ad",
+        "--date=short",
+        "--",
+        &name,
+    ])?;
+
+    let mut revisions = Vec::new();
+    for line in log.lines() {
+        let mut fields = line.splitn(3, '\t');
+        let (Some(hash), Some(author), Some(date)) = (fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+
+        // The file is missing from commits that delete it
+        let Ok(markdown) = git(&["-C", &dir, "show", &format!("{hash}:./{name}")]) else {
+            continue;
+        };
+
+        revisions.push(Revision {
+            id: hash[..hash.len().min(7)].to_string(),
+            author: Some(author.to_string()),
+            date: Some(date.to_string()),
+            markdown,
+        });
+    }
+
+    Ok(revisions)
+}
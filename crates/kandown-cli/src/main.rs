@@ -0,0 +1,83 @@
+// crates/kandown-cli/src/main.rs
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+mod board;
+mod cards;
+mod diff;
+mod export;
+mod format;
+mod git;
+mod history;
+mod merge;
+mod migrate;
+mod validate;
+mod workspace;
+
+#[cfg(test)]
+mod test;
+
+#[derive(Parser)]
+#[command(name = "kandown", version, about = "Work with kandown markdown boards")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List the cards of a view, grouped by column for board views
+    List(cards::ListArgs),
+    /// Show a card's properties and description
+    Show(cards::ShowArgs),
+    /// Add a card
+    Add(cards::AddArgs),
+    /// Move a card to another column of a board view
+    Move(cards::MoveArgs),
+    /// Edit a card's title, description or properties
+    Edit(cards::EditArgs),
+    /// Remove a card
+    Rm(cards::RmArgs),
+    /// Lint boards for invalid values, dangling references and duplicates
+    Validate(validate::ValidateArgs),
+    /// Rewrite a board in canonical form
+    Fmt(format::FmtArgs),
+    /// Upgrade boards written in older versions of the format
+    Migrate(migrate::MigrateArgs),
+    /// Render a view of a board to another format
+    Export(export::ExportArgs),
+    /// Show what changed between two versions of a board
+    Diff(diff::DiffArgs),
+    /// Show the activity on a board or card over its git history or snapshots
+    History(history::HistoryArgs),
+    /// Three-way merge a board file, as run by git when installed as a merge driver
+    Merge(merge::MergeArgs),
+    /// Make git merge board files with `kandown merge`
+    InstallMergeDriver(merge::InstallArgs),
+    /// List cards across all boards in a directory, or their cross-board references
+    Workspace(workspace::WorkspaceArgs),
+}
+
+fn main() -> Result<()> {
+    run(Cli::parse())
+}
+
+fn run(cli: Cli) -> Result<()> {
+    match cli.command {
+        Command::List(args) => cards::list(args),
+        Command::Show(args) => cards::show(args),
+        Command::Add(args) => cards::add(args),
+        Command::Move(args) => cards::move_card(args),
+        Command::Edit(args) => cards::edit(args),
+        Command::Rm(args) => cards::rm(args),
+        Command::Validate(args) => validate::run(args),
+        Command::Fmt(args) => format::fmt(args),
+        Command::Migrate(args) => migrate::run(args),
+        Command::Export(args) => export::run(args),
+        Command::Diff(args) => diff::run(args),
+        Command::History(args) => history::run(args),
+        Command::Merge(args) => merge::run(args),
+        Command::InstallMergeDriver(args) => merge::install(args),
+        Command::Workspace(args) => workspace::run(args),
+    }
+}
@@ -0,0 +1,92 @@
+// crates/kandown-cli/src/merge.rs
+use anyhow::{Context, Result, bail};
+use clap::Args;
+use std::{fs, path::PathBuf};
+
+use kandown::{merge_markdown, write_atomic};
+
+use crate::{board::print_diagnostics, git::git};
+
+#[derive(Args)]
+pub struct MergeArgs {
+    /// Common ancestor (git's %O)
+    base: PathBuf,
+
+    /// Our version (git's %A), overwritten with the result unless --output is given
+    ours: PathBuf,
+
+    /// Their version (git's %B)
+    theirs: PathBuf,
+
+    /// Write the merged board here instead
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Path to report conflicts under (git's %P)
+    #[arg(long)]
+    name: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct InstallArgs {
+    /// Paths of board files, as .gitattributes patterns (e.g. "boards/*.md")
+    #[arg(required = true)]
+    patterns: Vec<String>,
+}
+
+// Merge board files. Conflicts keep our side, are reported on stderr and make
+// the command fail, so git marks the file as conflicted.
+pub fn run(args: MergeArgs) -> Result<()> {
+    let read = |path: &PathBuf| {
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+    };
+    let (base, ours, theirs) = (read(&args.base)?, read(&args.ours)?, read(&args.theirs)?);
+
+    let (merged, conflicts) = merge_markdown(&base, &ours, &theirs)?;
+
+    let output = args.output.as_ref().unwrap_or(&args.ours);
+    write_atomic(output, &merged)?;
+
+    if !conflicts.is_empty() {
+        let name = args.name.as_ref().unwrap_or(output);
+        print_diagnostics(name, &conflicts);
+        bail!(
+            "{} merge conflict(s) in {}",
+            conflicts.len(),
+            name.display()
+        );
+    }
+
+    Ok(())
+}
+
+pub fn install(args: InstallArgs) -> Result<()> {
+    git(&["config", "merge.kandown.name", "kandown board merge"])?;
+    git(&[
+        "config",
+        "merge.kandown.driver",
+        "kandown merge %O %A %B --name %P",
+    ])?;
+
+    let root = PathBuf::from(git(&["rev-parse", "--show-toplevel"])?.trim());
+    let attributes_path = root.join(".gitattributes");
+    let mut attributes = fs::read_to_string(&attributes_path).unwrap_or_default();
+
+    for pattern in &args.patterns {
+        let line = format!("{pattern} merge=kandown");
+        if attributes.lines().any(|l| l.trim() == line) {
+            continue;
+        }
+        if !attributes.is_empty() && !attributes.ends_with('\n') {
+            attributes.push('\n');
+        }
+        attributes.push_str(&line);
+        attributes.push('\n');
+    }
+
+    fs::write(&attributes_path, attributes)
+        .with_context(|| format!("Failed to write {}", attributes_path.display()))?;
+    println!("Merge driver installed for {}", args.patterns.join(", "));
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+// crates/kandown-cli/src/migrate.rs
+use anyhow::{Context, Result, bail};
+use clap::Args;
+use std::{fs, path::PathBuf};
+
+use kandown::{Kanban, ParsedDocument, write_atomic};
+
+use crate::board::print_diagnostics;
+
+#[derive(Args)]
+pub struct MigrateArgs {
+    /// Board markdown files
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+
+    /// Don't write anything; report what would change and fail if a file is
+    /// in an older format
+    #[arg(long)]
+    check: bool,
+}
+
+pub fn run(args: MigrateArgs) -> Result<()> {
+    let mut outdated = 0;
+
+    for path in &args.files {
+        let markdown = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let (doc, report) = ParsedDocument::parse_and_migrate(&markdown)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+
+        // Unmarked boards that need no changes still get the version marker
+        if report.from_version == report.to_version {
+            continue;
+        }
+
+        print_diagnostics(path, &report.changes);
+        if args.check {
+            outdated += 1;
+            continue;
+        }
+
+        // Make sure the upgraded board is valid before rewriting it
+        Kanban::from_document(doc.clone())
+            .with_context(|| format!("Invalid board {}", path.display()))?;
+        write_atomic(path, &doc.to_string())?;
+        eprintln!(
+            "{}: migrated from format version {} to {}",
+            path.display(),
+            report.from_version,
+            report.to_version
+        );
+    }
+
+    if outdated > 0 {
+        bail!("{outdated} file(s) need migrating");
+    }
+
+    Ok(())
+}
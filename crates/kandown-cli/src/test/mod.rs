@@ -0,0 +1,2 @@
+// crates/kandown-cli/src/test/mod.rs
+mod test_cards;
@@ -0,0 +1,215 @@
+// crates/kandown-cli/src/test/test_cards.rs
+use clap::Parser;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{Cli, run};
+
+const BOARD: &str = "---
+version: 2
+---
+
+# Properties
+- Status: Select
+\t- Backlog
+\t- Doing
+\t- Done
+
+- Owner: Text
+
+- Points: Number
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+  Sort Type: Manual
+    - Backlog
+      - 2
+      - 0
+    - Doing
+      - 1
+  Display: Owner
+
+- Table
+  Layout: Table
+  Sort: Points
+
+# Cards
+- Write spec
+  Status: Backlog
+  Owner: alice
+  Points: 3
+  Agree on the **format** first
+  - [x] Outline
+  - [ ] Examples
+
+- Build parser
+  Status: Doing
+  Owner: bob
+
+- Add tests
+  Status: Backlog
+  Points: 5
+";
+
+// Each test gets its own board file, named after the test
+fn board_file(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("kandown-cli-{}-{name}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("board.md");
+    fs::write(&path, BOARD).unwrap();
+    path
+}
+
+fn kandown(path: &Path, args: &[&str]) -> anyhow::Result<()> {
+    let (command, rest) = args.split_first().unwrap();
+    let path = path.to_str().unwrap();
+    let args = ["kandown", command, path]
+        .into_iter()
+        .chain(rest.iter().copied());
+    run(Cli::try_parse_from(args)?)
+}
+
+#[test]
+fn test_add() {
+    let path = board_file("add");
+    kandown(
+        &path,
+        &[
+            "add",
+            "Ship it",
+            "-d",
+            "Tag the release",
+            "--set",
+            "Points=1",
+            "--set",
+            "Status=Done",
+        ],
+    )
+    .unwrap();
+
+    let expected = format!("{BOARD}\n- Ship it\n  Status: Done\n  Points: 1\n  Tag the release\n");
+    assert_eq!(fs::read_to_string(&path).unwrap(), expected);
+
+    // Nothing is written when a command fails
+    assert!(kandown(&path, &["add", "Ship it"]).is_err());
+    assert!(kandown(&path, &["add", "Other", "--set", "Status=Blocked"]).is_err());
+    assert_eq!(fs::read_to_string(&path).unwrap(), expected);
+}
+
+#[test]
+fn test_move() {
+    let path = board_file("move");
+    kandown(&path, &["move", "Add tests", "Doing"]).unwrap();
+
+    // Only the card's status changes; manual orderings are left as they are
+    let expected = BOARD.replace(
+        "- Add tests\n  Status: Backlog",
+        "- Add tests\n  Status: Doing",
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), expected);
+
+    assert!(kandown(&path, &["move", "Add tests", "Blocked"]).is_err());
+    assert_eq!(fs::read_to_string(&path).unwrap(), expected);
+}
+
+#[test]
+fn test_move_uses_default_view() {
+    let path = board_file("move-default");
+    let board = BOARD
+        .replace("version: 2\n", "version: 2\ndefault_view: By owner\n")
+        .replace(
+            "- Table\n",
+            "- By owner\n  Layout: Board\n  Group: Owner\n\n- Table\n",
+        );
+    fs::write(&path, &board).unwrap();
+
+    kandown(&path, &["move", "Add tests", "carol"]).unwrap();
+    let expected = board.replace(
+        "- Add tests\n  Status: Backlog\n  Points: 5\n",
+        "- Add tests\n  Status: Backlog\n  Points: 5\n  Owner: carol\n",
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), expected);
+
+    // A default view that is not a board falls back to the first board view
+    let board = board.replace("default_view: By owner", "default_view: Table");
+    fs::write(&path, &board).unwrap();
+    kandown(&path, &["move", "Add tests", "Done"]).unwrap();
+    assert!(
+        fs::read_to_string(&path)
+            .unwrap()
+            .contains("- Add tests\n  Status: Done\n")
+    );
+}
+
+#[test]
+fn test_edit() {
+    let path = board_file("edit");
+    kandown(
+        &path,
+        &[
+            "edit",
+            "Build parser",
+            "--title",
+            "Build the parser",
+            "--set",
+            "Owner=",
+            "--set",
+            "Points=8",
+        ],
+    )
+    .unwrap();
+
+    let expected = BOARD.replace(
+        "- Build parser\n  Status: Doing\n  Owner: bob\n",
+        "- Build the parser\n  Status: Doing\n  Points: 8\n",
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), expected);
+
+    assert!(kandown(&path, &["edit", "Build parser", "--set", "Points=8"]).is_err());
+    assert_eq!(fs::read_to_string(&path).unwrap(), expected);
+}
+
+#[test]
+fn test_rm() {
+    let path = board_file("rm");
+    kandown(&path, &["rm", "Write spec"]).unwrap();
+
+    // Later cards move up and the manual ordering follows their new IDs
+    let expected = BOARD
+        .replace(
+            "      - 2\n      - 0\n    - Doing\n      - 1\n",
+            "      - 1\n    - Doing\n      - 0\n",
+        )
+        .replace(
+            &BOARD[BOARD.find("- Write spec").unwrap()..BOARD.find("- Build parser").unwrap()],
+            "",
+        );
+    assert_eq!(fs::read_to_string(&path).unwrap(), expected);
+
+    assert!(kandown(&path, &["rm", "Write spec"]).is_err());
+    assert_eq!(fs::read_to_string(&path).unwrap(), expected);
+}
+
+#[test]
+fn test_fmt() {
+    let path = board_file("fmt");
+    let messy = BOARD
+        .replace("\t- ", "  * ")
+        .replace(
+            "  Owner: alice\n  Points: 3\n",
+            "  Points: 3\n  Owner: alice\n",
+        )
+        .replace("\n\n- Points", "\n\n\n- Points");
+    fs::write(&path, &messy).unwrap();
+
+    assert!(kandown(&path, &["fmt", "--check"]).is_err());
+    assert_eq!(fs::read_to_string(&path).unwrap(), messy);
+
+    kandown(&path, &["fmt"]).unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), BOARD);
+    kandown(&path, &["fmt", "--check"]).unwrap();
+}
@@ -0,0 +1,129 @@
+// crates/kandown-cli/src/validate.rs
+use anyhow::{Context, Result, bail};
+use clap::{Args, ValueEnum};
+use serde_json::{Value, json};
+use std::{collections::BTreeSet, fs, path::PathBuf};
+
+use kandown::{Diagnostic, DocumentSection, Severity, lint_markdown};
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    /// One line per problem
+    Text,
+    /// JSON array of diagnostics
+    Json,
+    /// SARIF 2.1.0 log for CI code scanning
+    Sarif,
+}
+
+#[derive(Args)]
+pub struct ValidateArgs {
+    /// Board markdown files
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "text")]
+    format: OutputFormat,
+
+    /// Fail on warnings as well as errors
+    #[arg(long)]
+    deny_warnings: bool,
+}
+
+pub fn run(args: ValidateArgs) -> Result<()> {
+    let mut results = Vec::new();
+
+    for path in &args.files {
+        let markdown = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let file = path.display().to_string();
+
+        for diagnostic in lint_markdown(&markdown) {
+            results.push((file.clone(), diagnostic));
+        }
+    }
+
+    match args.format {
+        OutputFormat::Text => {
+            for (file, diagnostic) in &results {
+                match diagnostic.location.as_ref().and_then(|l| l.line) {
+                    Some(line) => println!("{file}:{line}: {diagnostic}"),
+                    None => println!("{file}: {diagnostic}"),
+                }
+            }
+        }
+        OutputFormat::Json => {
+            let json: Vec<Value> = results
+                .iter()
+                .map(|(file, diagnostic)| {
+                    let mut value = serde_json::to_value(diagnostic).unwrap_or_default();
+                    value["file"] = json!(file);
+                    value
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        }
+        OutputFormat::Sarif => println!("{}", serde_json::to_string_pretty(&sarif(&results))?),
+    }
+
+    let errors = results
+        .iter()
+        .filter(|(_, d)| d.severity == Severity::Error)
+        .count();
+    let warnings = results.len() - errors;
+
+    if errors > 0 || (args.deny_warnings && warnings > 0) {
+        bail!("{errors} error(s), {warnings} warning(s)");
+    }
+
+    Ok(())
+}
+
+fn sarif(results: &[(String, Diagnostic)]) -> Value {
+    let rules: BTreeSet<&str> = results.iter().map(|(_, d)| d.code.as_str()).collect();
+
+    let results: Vec<Value> = results
+        .iter()
+        .map(|(file, diagnostic)| {
+            let mut location = json!({
+                "physicalLocation": { "artifactLocation": { "uri": file } }
+            });
+
+            if let Some(loc) = &diagnostic.location {
+                if let Some(line) = loc.line {
+                    location["physicalLocation"]["region"] = json!({ "startLine": line });
+                }
+
+                let kind = match loc.section {
+                    DocumentSection::Properties => "property",
+                    DocumentSection::Views => "view",
+                    DocumentSection::Cards => "card",
+                };
+                location["logicalLocations"] = json!([{ "name": loc.name, "kind": kind }]);
+            }
+
+            json!({
+                "ruleId": diagnostic.code,
+                "level": diagnostic.severity.to_string(),
+                "message": { "text": diagnostic.message },
+                "locations": [location],
+            })
+        })
+        .collect();
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "kandown",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules.iter().map(|id| json!({ "id": id })).collect::<Vec<_>>(),
+                }
+            },
+            "results": results,
+        }]
+    })
+}
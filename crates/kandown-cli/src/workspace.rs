@@ -0,0 +1,76 @@
+// crates/kandown-cli/src/workspace.rs
+use anyhow::Result;
+use clap::Args;
+use std::path::PathBuf;
+
+use kandown::{Workspace, WorkspaceCard};
+
+use crate::board::print_diagnostics;
+
+#[derive(Args)]
+pub struct WorkspaceArgs {
+    /// Directory of board files
+    dir: PathBuf,
+
+    /// Only list cards matching this filter, e.g. "Owner = alice". `Board`
+    /// is the name of the card's board.
+    #[arg(long, default_value = "")]
+    filter: String,
+
+    /// Group the cards by this property
+    #[arg(long)]
+    group: Option<String>,
+
+    /// List cross-board references instead of cards
+    #[arg(long)]
+    references: bool,
+}
+
+pub fn run(args: WorkspaceArgs) -> Result<()> {
+    let (workspace, diagnostics) = Workspace::load(&args.dir)?;
+    for (path, diagnostics) in &diagnostics {
+        print_diagnostics(path, diagnostics);
+    }
+
+    if args.references {
+        for reference in workspace.references() {
+            let target = match &reference.resolved {
+                Some(title) => format!("{}#{title}", reference.target_board),
+                None => format!(
+                    "{}#{} (unresolved)",
+                    reference.target_board, reference.target_card
+                ),
+            };
+            println!(
+                "{}#{} {}: {target}",
+                reference.board, reference.card, reference.property
+            );
+        }
+        return Ok(());
+    }
+
+    let line = |card: &WorkspaceCard| format!("{}#{}", card.board, card.card.borrow().title);
+
+    match &args.group {
+        Some(property) => {
+            for (column, cards) in workspace.grouped_by(property, &args.filter)? {
+                let column = if column.is_empty() {
+                    "No value"
+                } else {
+                    column.as_str()
+                };
+                println!("{column} ({})", cards.len());
+                for card in &cards {
+                    println!("  {}", line(card));
+                }
+            }
+        }
+        None => {
+            for card in &workspace.cards_where(&args.filter)? {
+                println!("{}", line(card));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,445 @@
+// crates/kandown-server/src/api.rs
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::json;
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    rc::Rc,
+};
+
+use kandown::{Kanban, KanbanCard, KanbanProperty, KanbanPropertyType, KanbanViewType};
+
+use crate::store::BoardStore;
+
+pub struct Request<'a> {
+    pub method: &'a str,
+    pub path: &'a str,
+    pub if_match: Option<&'a str>,
+    pub body: &'a str,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+    pub etag: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: u16,
+    pub message: String,
+}
+
+impl ApiError {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        ApiError {
+            status: 400,
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        ApiError {
+            status: 404,
+            message: message.into(),
+        }
+    }
+
+    pub fn precondition_required(message: impl Into<String>) -> Self {
+        ApiError {
+            status: 428,
+            message: message.into(),
+        }
+    }
+
+    pub fn precondition_failed(message: impl Into<String>) -> Self {
+        ApiError {
+            status: 412,
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(error: anyhow::Error) -> Self {
+        ApiError {
+            status: 500,
+            message: format!("{error:#}"),
+        }
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+// Errors from board operations are problems with the request
+impl From<anyhow::Error> for ApiError {
+    fn from(error: anyhow::Error) -> Self {
+        ApiError::bad_request(error.to_string())
+    }
+}
+
+#[derive(Serialize)]
+pub struct CardJson {
+    id: usize,
+    title: String,
+    description: String,
+    properties: BTreeMap<String, String>,
+}
+
+#[derive(Serialize)]
+pub struct PropertyJson {
+    name: String,
+    #[serde(rename = "type")]
+    property_type: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<Vec<String>>,
+}
+
+#[derive(Serialize)]
+pub struct ViewJson {
+    name: String,
+    layout: &'static str,
+    group_by: Option<String>,
+    sort_by: Option<String>,
+    sort_type: String,
+    filter: Option<String>,
+    display: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct ColumnJson {
+    name: String,
+    cards: Vec<CardJson>,
+}
+
+#[derive(Deserialize)]
+struct AddCardBody {
+    title: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    properties: HashMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct EditCardBody {
+    title: Option<String>,
+    description: Option<String>,
+    #[serde(default)]
+    properties: HashMap<String, String>, // An empty value removes the property
+}
+
+#[derive(Deserialize)]
+struct MoveCardBody {
+    view: String,
+    column: String,
+}
+
+pub fn handle(store: &BoardStore, request: &Request) -> Response {
+    match route(store, request) {
+        Ok(response) => response,
+        Err(ApiError { status, message }) => Response {
+            status,
+            content_type: "application/json",
+            body: json!({ "error": message }).to_string(),
+            etag: None,
+        },
+    }
+}
+
+fn route(store: &BoardStore, request: &Request) -> Result<Response, ApiError> {
+    let segments: Vec<String> = request
+        .path
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(percent_decode)
+        .collect();
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+    match (request.method, segments.as_slice()) {
+        ("GET", ["boards"]) => json_response(200, &store.names(), None),
+        ("GET", ["boards", board]) => {
+            let snapshot = store.read(board)?;
+            let body = json!({
+                "name": board,
+                "metadata": &snapshot.board.metadata,
+                "properties": properties_json(&snapshot.board),
+                "views": views_json(&snapshot.board),
+            });
+            json_response(200, &body, Some(snapshot.etag))
+        }
+        ("GET", ["boards", board, "markdown"]) => {
+            let snapshot = store.read(board)?;
+            Ok(Response {
+                status: 200,
+                content_type: "text/markdown; charset=utf-8",
+                body: snapshot.board.to_parsed_document().to_string(),
+                etag: Some(snapshot.etag),
+            })
+        }
+        ("GET", ["boards", board, "views"]) => {
+            let snapshot = store.read(board)?;
+            json_response(200, &views_json(&snapshot.board), Some(snapshot.etag))
+        }
+        ("GET", ["boards", board, "views", view]) => {
+            let snapshot = store.read(board)?;
+            let body = view_data_json(&snapshot.board, view)?;
+            json_response(200, &body, Some(snapshot.etag))
+        }
+        ("GET", ["boards", board, "cards"]) => {
+            let snapshot = store.read(board)?;
+            let cards: Vec<CardJson> = snapshot
+                .board
+                .cards
+                .iter()
+                .map(|card| card_json(&card.borrow()))
+                .collect();
+            json_response(200, &cards, Some(snapshot.etag))
+        }
+        ("POST", ["boards", board, "cards"]) => {
+            let body: AddCardBody = parse_body(request.body)?;
+            let (card, etag) = store.update(board, request.if_match, |kanban| {
+                if kanban.card_by_title.contains_key(&body.title) {
+                    return Err(ApiError::bad_request(format!(
+                        "A card named '{}' already exists",
+                        body.title
+                    )));
+                }
+                let card = kanban.add_card(body.title, body.description, body.properties)?;
+                Ok(card_json(&card.borrow()))
+            })?;
+            json_response(201, &card, Some(etag))
+        }
+        ("GET", ["boards", board, "cards", id]) => {
+            let snapshot = store.read(board)?;
+            let title = card_title(&snapshot.board, id)?;
+            let card = card_json(&snapshot.board.card_by_title[&title].borrow());
+            json_response(200, &card, Some(snapshot.etag))
+        }
+        ("PATCH", ["boards", board, "cards", id]) => {
+            let body: EditCardBody = parse_body(request.body)?;
+            let (card, etag) = store.update(board, Some(card_etag(request)?), |kanban| {
+                let title = card_title(kanban, id)?;
+                let new_title = body.title.clone();
+                kanban.edit_card(&title, body.title, body.description, body.properties)?;
+
+                let title = new_title.unwrap_or(title);
+                Ok(card_json(&kanban.card_by_title[&title].borrow()))
+            })?;
+            json_response(200, &card, Some(etag))
+        }
+        ("DELETE", ["boards", board, "cards", id]) => {
+            let ((), etag) = store.update(board, Some(card_etag(request)?), |kanban| {
+                let title = card_title(kanban, id)?;
+                kanban.remove_card(&title)?;
+                Ok(())
+            })?;
+            Ok(Response {
+                status: 204,
+                content_type: "application/json",
+                body: String::new(),
+                etag: Some(etag),
+            })
+        }
+        ("POST", ["boards", board, "cards", id, "move"]) => {
+            let body: MoveCardBody = parse_body(request.body)?;
+            let (card, etag) = store.update(board, Some(card_etag(request)?), |kanban| {
+                let title = card_title(kanban, id)?;
+                kanban.move_card(&title, &body.view, &body.column)?;
+                Ok(card_json(&kanban.card_by_title[&title].borrow()))
+            })?;
+            json_response(200, &card, Some(etag))
+        }
+        (_, ["boards", ..]) => Err(ApiError {
+            status: 405,
+            message: format!("Method {} not allowed for {}", request.method, request.path),
+        }),
+        _ => Err(ApiError::not_found(format!(
+            "No route for {}",
+            request.path
+        ))),
+    }
+}
+
+fn json_response(
+    status: u16,
+    body: &impl Serialize,
+    etag: Option<String>,
+) -> Result<Response, ApiError> {
+    let body = serde_json::to_string(body).map_err(|e| ApiError::internal(e.into()))?;
+
+    Ok(Response {
+        status,
+        content_type: "application/json",
+        body,
+        etag,
+    })
+}
+
+fn parse_body<T: DeserializeOwned>(body: &str) -> Result<T, ApiError> {
+    serde_json::from_str(body).map_err(|e| ApiError::bad_request(format!("Invalid body: {e}")))
+}
+
+// Card IDs are positions in the board file and change whenever a card is
+// added or removed, so changes addressed by ID must name the board version
+// the ID was read from
+fn card_etag<'a>(request: &Request<'a>) -> Result<&'a str, ApiError> {
+    request
+        .if_match
+        .filter(|etag| etag.trim() != "*")
+        .ok_or_else(|| {
+            ApiError::precondition_required(
+                "Changes to a card need an If-Match header with the board's ETag",
+            )
+        })
+}
+
+fn card_title(board: &Kanban, id: &str) -> Result<String, ApiError> {
+    id.parse::<usize>()
+        .ok()
+        .and_then(|id| board.card_by_id.get(&id))
+        .map(|card| card.borrow().title.clone())
+        .ok_or_else(|| ApiError::not_found(format!("Card not found: {id}")))
+}
+
+fn card_json(card: &KanbanCard) -> CardJson {
+    CardJson {
+        id: card.id,
+        title: card.title.clone(),
+        description: card.description.clone(),
+        properties: card
+            .properties
+            .iter()
+            .map(|(prop, value)| (prop.borrow().name.clone(), value.clone()))
+            .collect(),
+    }
+}
+
+fn properties_json(board: &Kanban) -> Vec<PropertyJson> {
+    board
+        .properties
+        .iter()
+        .map(|prop_rc| {
+            let prop = prop_rc.borrow();
+            let (property_type, options) = match &prop.property_type {
+                KanbanPropertyType::Text => ("Text", None),
+                KanbanPropertyType::Number => ("Number", None),
+                KanbanPropertyType::Date => ("Date", None),
+                KanbanPropertyType::Checkbox => ("Checkbox", None),
+                KanbanPropertyType::Select { options } => ("Select", Some(options.clone())),
+                KanbanPropertyType::Formula { .. } => ("Formula", None),
+            };
+
+            PropertyJson {
+                name: prop.name.clone(),
+                property_type,
+                options,
+            }
+        })
+        .collect()
+}
+
+fn views_json(board: &Kanban) -> Vec<ViewJson> {
+    board
+        .views
+        .iter()
+        .map(|view_rc| {
+            let view = view_rc.borrow();
+            let (layout, group_by) = match &view.view_layout {
+                KanbanViewType::Board { group_by } => ("Board", property_name(group_by)),
+                KanbanViewType::Table { .. } => ("Table", None),
+                KanbanViewType::Calendar { date_property } => {
+                    ("Calendar", property_name(date_property))
+                }
+                KanbanViewType::Timeline { date_property } => {
+                    ("Timeline", property_name(date_property))
+                }
+            };
+
+            ViewJson {
+                name: view.name.clone(),
+                layout,
+                group_by,
+                sort_by: property_name(&view.sort_by),
+                sort_type: format!("{:?}", view.sort_type),
+                filter: view.filter.clone(),
+                display: view
+                    .display
+                    .iter()
+                    .map(|p| p.borrow().name.clone())
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+fn property_name(prop: &Option<Rc<RefCell<KanbanProperty>>>) -> Option<String> {
+    prop.as_ref().map(|prop| prop.borrow().name.clone())
+}
+
+// A view's cards, grouped into columns for board views
+fn view_data_json(board: &Kanban, view_name: &str) -> Result<serde_json::Value, ApiError> {
+    let view_rc = board
+        .view_by_name
+        .get(view_name)
+        .ok_or_else(|| ApiError::not_found(format!("View not found: {view_name}")))?;
+    let is_grouped = matches!(
+        view_rc.borrow().view_layout,
+        KanbanViewType::Board { group_by: Some(_) }
+    );
+
+    let columns = if is_grouped {
+        board.get_ordered_groups(view_name)?
+    } else {
+        vec![(String::new(), board.get_view_cards(view_name)?)]
+    };
+
+    let columns: Vec<ColumnJson> = columns
+        .into_iter()
+        .map(|(name, cards)| ColumnJson {
+            name,
+            cards: cards.iter().map(|card| card_json(&card.borrow())).collect(),
+        })
+        .collect();
+
+    let view = views_json(board)
+        .into_iter()
+        .find(|view| view.name == view_name);
+
+    Ok(json!({ "view": view, "columns": columns }))
+}
+
+pub fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        let hex = bytes
+            .get(idx + 1..idx + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match (bytes[idx], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                idx += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                idx += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).to_string()
+}
@@ -0,0 +1,101 @@
+// crates/kandown-server/src/main.rs
+use anyhow::{Result, anyhow};
+use clap::Parser;
+use std::{net::TcpListener, path::PathBuf, sync::Arc, thread};
+use tiny_http::{Header, Server};
+
+mod api;
+mod store;
+mod sync;
+
+#[cfg(test)]
+mod test;
+
+use store::BoardStore;
+use sync::SyncServer;
+
+#[derive(Parser)]
+#[command(
+    name = "kandown-server",
+    version,
+    about = "Serve kandown boards over a JSON REST API"
+)]
+struct Args {
+    /// Board markdown files, served under /boards/<file stem>
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:7878")]
+    bind: String,
+
+    /// Also accept collaborative editing clients over WebSocket on this
+    /// address, at ws://<addr>/boards/<name>
+    #[arg(long)]
+    sync_bind: Option<String>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let store = Arc::new(BoardStore::new(&args.files)?);
+
+    if let Some(sync_bind) = &args.sync_bind {
+        let listener =
+            TcpListener::bind(sync_bind).map_err(|e| anyhow!("Failed to bind {sync_bind}: {e}"))?;
+        let sync = Arc::new(SyncServer::new(store.clone()));
+        thread::spawn(move || sync::serve(listener, sync));
+        eprintln!("Accepting sync clients on ws://{sync_bind}");
+    }
+
+    // Boards are not Send, so REST requests are handled one at a time
+    let server =
+        Server::http(&args.bind).map_err(|e| anyhow!("Failed to bind {}: {e}", args.bind))?;
+    eprintln!(
+        "Serving {} board(s) on http://{}",
+        store.names().len(),
+        args.bind
+    );
+
+    for mut request in server.incoming_requests() {
+        let mut body = String::new();
+        if let Err(e) = request.as_reader().read_to_string(&mut body) {
+            eprintln!("Failed to read request body: {e}");
+            continue;
+        }
+
+        let method = request.method().as_str().to_string();
+        let path = request.url().to_string();
+        let if_match = request
+            .headers()
+            .iter()
+            .find(|h| h.field.equiv("If-Match"))
+            .map(|h| h.value.as_str().to_string());
+
+        let response = api::handle(
+            &store,
+            &api::Request {
+                method: &method,
+                path: &path,
+                if_match: if_match.as_deref(),
+                body: &body,
+            },
+        );
+
+        let mut http_response = tiny_http::Response::from_string(response.body)
+            .with_status_code(response.status)
+            .with_header(header("Content-Type", response.content_type));
+        if let Some(etag) = &response.etag {
+            http_response.add_header(header("ETag", etag));
+        }
+
+        if let Err(e) = request.respond(http_response) {
+            eprintln!("Failed to send response: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).expect("valid header")
+}
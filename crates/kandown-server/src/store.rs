@@ -0,0 +1,138 @@
+// crates/kandown-server/src/store.rs
+use anyhow::{Context, Result, anyhow};
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    hash::{DefaultHasher, Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+use kandown::{Kanban, ParsedDocument, write_atomic};
+
+use crate::api::ApiError;
+
+// Board files served by name (the file stem)
+pub struct BoardStore {
+    boards: BTreeMap<String, PathBuf>,
+}
+
+// A board as read from disk, with the ETag of the file contents
+pub struct Snapshot {
+    pub board: Kanban,
+    pub etag: String,
+}
+
+impl BoardStore {
+    pub fn new(paths: &[PathBuf]) -> Result<Self> {
+        let mut boards = BTreeMap::new();
+
+        for path in paths {
+            let name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .ok_or_else(|| anyhow!("Not a file: {}", path.display()))?;
+
+            // Fail at startup rather than on the first request
+            Kanban::read_file(path)?;
+
+            if boards.insert(name.clone(), path.clone()).is_some() {
+                return Err(anyhow!("Two boards are named '{name}'"));
+            }
+        }
+
+        Ok(BoardStore { boards })
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.boards.keys().cloned().collect()
+    }
+
+    // Read a board. Files are re-read on every request so edits made outside
+    // the server are picked up.
+    pub fn read(&self, name: &str) -> Result<Snapshot, ApiError> {
+        let path = self.path(name)?;
+        let _lock = lock(path, false).map_err(ApiError::internal)?;
+
+        load(path)
+    }
+
+    // Apply a change to a board and write it back. With `if_match`, the change
+    // is only made if the file still has that ETag.
+    pub fn update<T>(
+        &self,
+        name: &str,
+        if_match: Option<&str>,
+        change: impl FnOnce(&mut Kanban) -> Result<T, ApiError>,
+    ) -> Result<(T, String), ApiError> {
+        let path = self.path(name)?;
+        let _lock = lock(path, true).map_err(ApiError::internal)?;
+
+        let mut snapshot = load(path)?;
+        if let Some(if_match) = if_match
+            && if_match != "*"
+            && !if_match.split(',').any(|tag| tag.trim() == snapshot.etag)
+        {
+            return Err(ApiError::precondition_failed(format!(
+                "Board '{name}' has changed (current ETag {})",
+                snapshot.etag
+            )));
+        }
+
+        let result = change(&mut snapshot.board)?;
+
+        let markdown = snapshot.board.to_parsed_document().to_string();
+        write_atomic(path, &markdown).map_err(ApiError::internal)?;
+
+        Ok((result, etag(&markdown)))
+    }
+
+    fn path(&self, name: &str) -> Result<&PathBuf, ApiError> {
+        self.boards
+            .get(name)
+            .ok_or_else(|| ApiError::not_found(format!("Board not found: {name}")))
+    }
+}
+
+fn load(path: &Path) -> Result<Snapshot, ApiError> {
+    let read = || -> Result<Snapshot> {
+        let markdown = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let doc = ParsedDocument::try_from(markdown.as_str())
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+
+        Ok(Snapshot {
+            board: Kanban::from_document(doc)?,
+            etag: etag(&markdown),
+        })
+    };
+
+    read().map_err(ApiError::internal)
+}
+
+pub fn etag(contents: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    format!("\"{:016x}\"", hasher.finish())
+}
+
+// Hold an advisory lock on a sidecar file for the duration of a request. The
+// board file itself is replaced on write, so it can't carry the lock.
+fn lock(path: &Path, exclusive: bool) -> Result<File> {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let lock_path = path.with_file_name(format!(".{file_name}.lock"));
+
+    let file = File::options()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .with_context(|| format!("Failed to open {}", lock_path.display()))?;
+
+    if exclusive {
+        file.lock()?;
+    } else {
+        file.lock_shared()?;
+    }
+
+    Ok(file)
+}
@@ -0,0 +1,271 @@
+// crates/kandown-server/src/sync.rs
+use anyhow::{Result, anyhow};
+use std::{
+    collections::HashMap,
+    io::ErrorKind,
+    net::{TcpListener, TcpStream},
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, Receiver, Sender},
+    },
+    thread,
+    time::Duration,
+};
+use tungstenite::{
+    Message,
+    error::ProtocolError,
+    handshake::server::{ErrorResponse, Request, Response},
+    http::StatusCode,
+};
+
+use kandown::{Kanban, Operation, ParsedDocument, SyncHub, SyncMessage, merge_markdown};
+
+use crate::{api::percent_decode, store::BoardStore};
+
+// A live editing session for one board and the clients connected to it.
+// `saved` is the board as last read or written by the session and `etag` the
+// file's ETag at that point.
+struct Session {
+    hub: SyncHub,
+    clients: HashMap<u64, Sender<SyncMessage>>,
+    saved: String,
+    etag: String,
+}
+
+// Collaborative editing sessions over WebSocket. A session starts from the
+// board file when its first client connects and ends when the last one
+// leaves. Merged edits are written back after every change, but only over the
+// version of the file the session last saw. If the file was changed through
+// the REST API or on disk in the meantime, the session's edits are merged into
+// it and the session ends, so clients rejoin from the merged board.
+pub struct SyncServer {
+    store: Arc<BoardStore>,
+    sessions: Mutex<HashMap<String, Session>>,
+}
+
+impl SyncServer {
+    pub fn new(store: Arc<BoardStore>) -> Self {
+        SyncServer {
+            store,
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // Join a board's session. Returns the client's replica id, the welcome
+    // message to send it and the channel other clients' operations arrive on.
+    pub fn join(&self, board: &str) -> Result<(u64, SyncMessage, Receiver<SyncMessage>)> {
+        let mut sessions = self
+            .sessions
+            .lock()
+            .map_err(|_| anyhow!("Sync state poisoned"))?;
+
+        if !sessions.contains_key(board) {
+            let snapshot = self.store.read(board)?;
+            let markdown = snapshot.board.to_parsed_document().to_string();
+            let session = Session {
+                hub: SyncHub::new(&markdown)?,
+                clients: HashMap::new(),
+                saved: markdown,
+                etag: snapshot.etag,
+            };
+            sessions.insert(board.to_string(), session);
+        }
+
+        let session = sessions.get_mut(board).expect("session was just created");
+        let (replica, welcome) = session.hub.join();
+        let (sender, receiver) = mpsc::channel();
+        session.clients.insert(replica, sender);
+
+        Ok((replica, welcome, receiver))
+    }
+
+    // Apply a client's operations, save the board and forward the new
+    // operations to the other clients. Returns the messages to send back to
+    // the client: rejections, or the end of the session.
+    pub fn receive(
+        &self,
+        board: &str,
+        client: u64,
+        operations: Vec<Operation>,
+    ) -> Result<Vec<SyncMessage>> {
+        let mut sessions = self
+            .sessions
+            .lock()
+            .map_err(|_| anyhow!("Sync state poisoned"))?;
+        let session = sessions
+            .get_mut(board)
+            .ok_or_else(|| anyhow!("No sync session for {board}"))?;
+
+        let (accepted, rejected) = session.hub.receive(operations);
+        if accepted.is_empty() {
+            return Ok(rejected);
+        }
+
+        let edited = session.hub.board().to_kanban()?;
+        let markdown = edited.to_parsed_document().to_string();
+        match self.store.update(board, Some(&session.etag), |kanban| {
+            *kanban = edited;
+            Ok(())
+        }) {
+            Ok(((), etag)) => {
+                session.saved = markdown;
+                session.etag = etag;
+
+                for (id, sender) in &session.clients {
+                    if *id != client {
+                        // A closed channel means the client is leaving
+                        let _ = sender.send(SyncMessage::Operations {
+                            operations: accepted.clone(),
+                        });
+                    }
+                }
+                Ok(rejected)
+            }
+            Err(e) if e.status == 412 => {
+                let session = sessions.remove(board).expect("session was just used");
+                let reason = self.merge_external_change(board, &session.saved, &markdown)?;
+
+                let ended = SyncMessage::Ended { reason };
+                for (id, sender) in &session.clients {
+                    if *id != client {
+                        let _ = sender.send(ended.clone());
+                    }
+                }
+                Ok(vec![ended])
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    // Merge a session's edits into a board file that was changed outside the
+    // session. Returns why the session has to end.
+    fn merge_external_change(&self, board: &str, saved: &str, edited: &str) -> Result<String> {
+        let (_, conflicts) = self.store.update(board, None, |kanban| {
+            let current = kanban.to_parsed_document().to_string();
+            let (merged, conflicts) = merge_markdown(saved, edited, &current)?;
+            *kanban = Kanban::from_document(ParsedDocument::parse_complete(&merged)?)?;
+            Ok(conflicts)
+        })?;
+
+        let mut reason = format!("Board '{board}' was changed outside the session");
+        if !conflicts.is_empty() {
+            reason.push_str(&format!(
+                "; {} conflicting change(s) kept the session's version",
+                conflicts.len()
+            ));
+        }
+        Ok(reason)
+    }
+
+    pub fn leave(&self, board: &str, client: u64) {
+        let Ok(mut sessions) = self.sessions.lock() else {
+            return;
+        };
+
+        if let Some(session) = sessions.get_mut(board) {
+            session.clients.remove(&client);
+            if session.clients.is_empty() {
+                sessions.remove(board);
+            }
+        }
+    }
+
+    fn has_board(&self, board: &str) -> bool {
+        self.store.names().iter().any(|name| name == board)
+    }
+}
+
+// Accept WebSocket connections at ws://<addr>/boards/<name>, one thread per
+// client
+pub fn serve(listener: TcpListener, sync: Arc<SyncServer>) {
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else {
+            continue;
+        };
+        let sync = sync.clone();
+
+        thread::spawn(move || {
+            if let Err(e) = serve_client(stream, &sync) {
+                eprintln!("Sync client failed: {e:#}");
+            }
+        });
+    }
+}
+
+fn serve_client(stream: TcpStream, sync: &SyncServer) -> Result<()> {
+    let mut board = String::new();
+    #[allow(clippy::result_large_err)] // The callback signature is tungstenite's
+    let mut socket = tungstenite::accept_hdr(stream, |request: &Request, response: Response| {
+        board = request
+            .uri()
+            .path()
+            .strip_prefix("/boards/")
+            .map(percent_decode)
+            .unwrap_or_default();
+
+        if sync.has_board(&board) {
+            Ok(response)
+        } else {
+            let mut error = ErrorResponse::new(Some(format!("Board not found: {board}")));
+            *error.status_mut() = StatusCode::NOT_FOUND;
+            Err(error)
+        }
+    })
+    .map_err(|e| anyhow!("WebSocket handshake failed: {e}"))?;
+
+    let (client, welcome, receiver) = sync.join(&board)?;
+    // Ok(true) when the server ended the session
+    let result = (|| -> Result<bool> {
+        send(&mut socket, &welcome)?;
+
+        // Wake up regularly to forward other clients' operations
+        socket
+            .get_mut()
+            .set_read_timeout(Some(Duration::from_millis(50)))?;
+
+        loop {
+            match socket.read() {
+                Ok(Message::Text(text)) => match serde_json::from_str(&text)? {
+                    SyncMessage::Operations { operations } => {
+                        for reply in sync.receive(&board, client, operations)? {
+                            send(&mut socket, &reply)?;
+                            if matches!(reply, SyncMessage::Ended { .. }) {
+                                return Ok(true);
+                            }
+                        }
+                    }
+                    message => return Err(anyhow!("Unexpected message from client: {message:?}")),
+                },
+                Ok(Message::Close(_)) => return Ok(false),
+                Ok(_) => {}
+                Err(tungstenite::Error::Io(e))
+                    if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+                Err(
+                    tungstenite::Error::ConnectionClosed
+                    | tungstenite::Error::Protocol(ProtocolError::ResetWithoutClosingHandshake),
+                ) => return Ok(false),
+                Err(e) => return Err(e.into()),
+            }
+
+            while let Ok(message) = receiver.try_recv() {
+                send(&mut socket, &message)?;
+                if matches!(message, SyncMessage::Ended { .. }) {
+                    return Ok(true);
+                }
+            }
+        }
+    })();
+
+    // An ended session is already gone, and its client ids may be reused by
+    // the next one
+    if !matches!(result, Ok(true)) {
+        sync.leave(&board, client);
+    }
+    socket.close(None).ok();
+    result.map(|_| ())
+}
+
+fn send(socket: &mut tungstenite::WebSocket<TcpStream>, message: &SyncMessage) -> Result<()> {
+    socket.send(Message::text(serde_json::to_string(message)?))?;
+    Ok(())
+}
@@ -0,0 +1,3 @@
+// crates/kandown-server/src/test/mod.rs
+mod test_api;
+mod test_sync;
@@ -0,0 +1,259 @@
+// crates/kandown-server/src/test/test_api.rs
+use serde_json::{Value, json};
+use std::{env, fs, path::PathBuf};
+
+use crate::{
+    api::{Request, Response, handle},
+    store::BoardStore,
+};
+
+const BOARD: &str = r#"---
+version: 2
+---
+
+# Properties
+- Status: Select
+	- Backlog
+	- In Progress
+	- Done
+
+- Owner: Text
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+  Sort Type: Alpha
+
+# Cards
+- Write spec
+  Status: In Progress
+  Owner: alice
+
+- Add tests
+  Status: Backlog
+"#;
+
+// Each test gets its own board file, named after the test
+fn store(name: &str) -> (BoardStore, PathBuf) {
+    let dir = env::temp_dir().join(format!("kandown-server-{}-{name}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("team.md");
+    fs::write(&path, BOARD).unwrap();
+
+    (BoardStore::new(std::slice::from_ref(&path)).unwrap(), path)
+}
+
+fn call(store: &BoardStore, method: &str, path: &str, body: Value) -> Response {
+    call_if_match(store, method, path, body, None)
+}
+
+fn call_if_match(
+    store: &BoardStore,
+    method: &str,
+    path: &str,
+    body: Value,
+    if_match: Option<&str>,
+) -> Response {
+    let body = if body.is_null() {
+        String::new()
+    } else {
+        body.to_string()
+    };
+
+    handle(
+        store,
+        &Request {
+            method,
+            path,
+            if_match,
+            body: &body,
+        },
+    )
+}
+
+fn json_body(response: &Response) -> Value {
+    serde_json::from_str(&response.body).unwrap()
+}
+
+#[test]
+fn test_read_endpoints() {
+    let (store, _) = store("read");
+
+    let response = call(&store, "GET", "/boards", Value::Null);
+    assert_eq!(json_body(&response), json!(["team"]));
+
+    let response = call(&store, "GET", "/boards/team", Value::Null);
+    assert_eq!(response.status, 200);
+    assert!(response.etag.is_some());
+    let board = json_body(&response);
+    assert_eq!(board["properties"][0]["options"][1], "In Progress");
+    assert_eq!(board["views"][0]["group_by"], "Status");
+
+    let response = call(&store, "GET", "/boards/team/views/Board", Value::Null);
+    let view = json_body(&response);
+    let columns: Vec<&str> = view["columns"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(columns, ["Backlog", "In Progress", "Done"]);
+    assert_eq!(view["columns"][0]["cards"][0]["title"], "Add tests");
+
+    let response = call(&store, "GET", "/boards/team/cards/0", Value::Null);
+    assert_eq!(json_body(&response)["properties"]["Owner"], "alice");
+
+    let response = call(&store, "GET", "/boards/team/markdown", Value::Null);
+    assert_eq!(response.body, BOARD);
+}
+
+#[test]
+fn test_card_changes_are_saved() {
+    let (store, path) = store("changes");
+
+    let response = call(
+        &store,
+        "POST",
+        "/boards/team/cards",
+        json!({ "title": "Ship it", "properties": { "Status": "Backlog" } }),
+    );
+    assert_eq!(response.status, 201);
+    assert_eq!(json_body(&response)["id"], 2);
+
+    let response = call_if_match(
+        &store,
+        "POST",
+        "/boards/team/cards/2/move",
+        json!({ "view": "Board", "column": "Done" }),
+        response.etag.as_deref(),
+    );
+    assert_eq!(json_body(&response)["properties"]["Status"], "Done");
+
+    let response = call_if_match(
+        &store,
+        "PATCH",
+        "/boards/team/cards/0",
+        json!({ "title": "Write the spec", "properties": { "Owner": "" } }),
+        response.etag.as_deref(),
+    );
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        json_body(&response)["properties"],
+        json!({ "Status": "In Progress" })
+    );
+
+    let response = call_if_match(
+        &store,
+        "DELETE",
+        "/boards/team/cards/1",
+        Value::Null,
+        response.etag.as_deref(),
+    );
+    assert_eq!(response.status, 204);
+
+    let saved = fs::read_to_string(&path).unwrap();
+    assert!(saved.contains("- Write the spec\n  Status: In Progress\n"));
+    assert!(saved.contains("- Ship it\n  Status: Done\n"));
+    assert!(!saved.contains("Add tests"));
+    assert!(!saved.contains("alice"));
+}
+
+#[test]
+fn test_etag_mismatch_is_rejected() {
+    let (store, path) = store("etag");
+
+    let etag = call(&store, "GET", "/boards/team", Value::Null)
+        .etag
+        .unwrap();
+
+    // Someone else edits the file
+    fs::write(&path, BOARD.replace("alice", "bob")).unwrap();
+
+    let edit = json!({ "description": "Stale edit" });
+    let response = call_if_match(
+        &store,
+        "PATCH",
+        "/boards/team/cards/0",
+        edit.clone(),
+        Some(&etag),
+    );
+    assert_eq!(response.status, 412);
+    assert!(!fs::read_to_string(&path).unwrap().contains("Stale edit"));
+
+    let etag = call(&store, "GET", "/boards/team", Value::Null)
+        .etag
+        .unwrap();
+    let response = call_if_match(&store, "PATCH", "/boards/team/cards/0", edit, Some(&etag));
+    assert_eq!(response.status, 200);
+    assert_ne!(response.etag.unwrap(), etag);
+}
+
+#[test]
+fn test_card_changes_need_etag() {
+    let (store, path) = store("card-etag");
+
+    // Card IDs are positions, so without an ETag a change could hit whichever
+    // card took the ID after another client removed one
+    for (method, path, body) in [
+        (
+            "PATCH",
+            "/boards/team/cards/0",
+            json!({ "description": "Blind edit" }),
+        ),
+        ("DELETE", "/boards/team/cards/0", Value::Null),
+        (
+            "POST",
+            "/boards/team/cards/0/move",
+            json!({ "view": "Board", "column": "Done" }),
+        ),
+    ] {
+        let response = call(&store, method, path, body.clone());
+        assert_eq!(response.status, 428, "{method} {path}");
+        let response = call_if_match(&store, method, path, body, Some("*"));
+        assert_eq!(response.status, 428, "{method} {path}");
+    }
+    assert_eq!(fs::read_to_string(&path).unwrap(), BOARD);
+
+    // Adding a card doesn't depend on IDs
+    let response = call(
+        &store,
+        "POST",
+        "/boards/team/cards",
+        json!({ "title": "Ship it" }),
+    );
+    assert_eq!(response.status, 201);
+}
+
+#[test]
+fn test_errors() {
+    let (store, _) = store("errors");
+
+    let response = call(&store, "GET", "/boards/other", Value::Null);
+    assert_eq!(response.status, 404);
+    assert_eq!(json_body(&response)["error"], "Board not found: other");
+
+    let response = call(&store, "GET", "/boards/team/cards/9", Value::Null);
+    assert_eq!(response.status, 404);
+
+    let etag = call(&store, "GET", "/boards/team", Value::Null).etag;
+    let response = call_if_match(
+        &store,
+        "POST",
+        "/boards/team/cards/0/move",
+        json!({ "view": "Board", "column": "Someday" }),
+        etag.as_deref(),
+    );
+    assert_eq!(response.status, 400);
+
+    let response = call(
+        &store,
+        "POST",
+        "/boards/team/cards",
+        json!({ "title": "Write spec" }),
+    );
+    assert_eq!(response.status, 400);
+
+    let response = call(&store, "PUT", "/boards/team", Value::Null);
+    assert_eq!(response.status, 405);
+}
@@ -0,0 +1,110 @@
+// crates/kandown-server/src/test/test_sync.rs
+use std::{env, fs, path::PathBuf, sync::Arc};
+
+use kandown::{BoardReplica, SyncMessage};
+
+use crate::{store::BoardStore, sync::SyncServer};
+
+const BOARD: &str = r#"---
+version: 2
+---
+
+# Properties
+- Status: Select
+	- Backlog
+	- Done
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+
+# Cards
+- Write spec
+  Status: Backlog
+"#;
+
+// Each test gets its own board file, named after the test
+fn sync_server(name: &str) -> (SyncServer, PathBuf) {
+    let dir = env::temp_dir().join(format!("kandown-server-{}-{name}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("team.md");
+    fs::write(&path, BOARD).unwrap();
+
+    let store = Arc::new(BoardStore::new(std::slice::from_ref(&path)).unwrap());
+    (SyncServer::new(store), path)
+}
+
+#[test]
+fn test_sync_session() {
+    let (sync, path) = sync_server("sync");
+
+    let (alice_id, welcome, alice_inbox) = sync.join("team").unwrap();
+    let mut alice = BoardReplica::from_welcome(&welcome).unwrap();
+    let (bob_id, welcome, bob_inbox) = sync.join("team").unwrap();
+    let mut bob = BoardReplica::from_welcome(&welcome).unwrap();
+    assert_ne!(alice_id, bob_id);
+
+    let spec = alice.card_id("Write spec").unwrap();
+    let op = alice.move_card(spec, "Board", "Done").unwrap();
+    let rejected = sync.receive("team", alice_id, vec![op]).unwrap();
+    assert!(rejected.is_empty());
+
+    // Only the other client hears about it, and the file is updated
+    assert!(alice_inbox.try_recv().is_err());
+    let message = bob_inbox.try_recv().unwrap();
+    assert!(matches!(message, SyncMessage::Operations { .. }));
+    bob.apply_message(&message).unwrap();
+    assert_eq!(bob.to_markdown().unwrap(), alice.to_markdown().unwrap());
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        alice.to_markdown().unwrap()
+    );
+
+    // A new session starts from the saved file once everyone has left
+    sync.leave("team", alice_id);
+    sync.leave("team", bob_id);
+    let (carol_id, welcome, _) = sync.join("team").unwrap();
+    assert_eq!(carol_id, 1);
+    assert!(matches!(
+        welcome,
+        SyncMessage::Welcome { markdown, operations, .. }
+            if markdown.contains("Status: Done") && operations.is_empty()
+    ));
+
+    assert!(sync.join("other").is_err());
+}
+
+#[test]
+fn test_external_change_ends_session() {
+    let (sync, path) = sync_server("sync-external");
+
+    let (alice_id, welcome, _) = sync.join("team").unwrap();
+    let mut alice = BoardReplica::from_welcome(&welcome).unwrap();
+    let (_, _, bob_inbox) = sync.join("team").unwrap();
+
+    // Someone adds a card through the REST API or an editor
+    let external = format!("{BOARD}\n- Add tests\n  Status: Backlog\n");
+    fs::write(&path, &external).unwrap();
+
+    let spec = alice.card_id("Write spec").unwrap();
+    let op = alice.move_card(spec, "Board", "Done").unwrap();
+    let replies = sync.receive("team", alice_id, vec![op]).unwrap();
+
+    // Both changes are kept and every client is told to rejoin
+    let saved = fs::read_to_string(&path).unwrap();
+    assert!(saved.contains("- Write spec\n  Status: Done\n"));
+    assert!(saved.contains("- Add tests\n  Status: Backlog\n"));
+    assert!(
+        matches!(&replies[..], [SyncMessage::Ended { reason }] if reason.contains("changed outside"))
+    );
+    assert!(matches!(
+        bob_inbox.try_recv().unwrap(),
+        SyncMessage::Ended { .. }
+    ));
+    assert!(alice.apply_message(&replies[0]).is_err());
+
+    let (_, welcome, _) = sync.join("team").unwrap();
+    let carol = BoardReplica::from_welcome(&welcome).unwrap();
+    assert_eq!(carol.to_markdown().unwrap(), saved);
+}
@@ -0,0 +1,395 @@
+// crates/kandown-tui/src/app.rs
+use anyhow::{Result, anyhow, bail};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::{collections::HashMap, path::PathBuf, rc::Rc};
+
+use kandown::{Kanban, KanbanColumn, KanbanPropertyType, KanbanViewType};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldKind {
+    Title,
+    Property(Vec<String>), // Select options, empty for free text
+    Description,
+}
+
+#[derive(Debug, Clone)]
+pub struct FormField {
+    pub label: String,
+    pub kind: FieldKind,
+    pub value: String,
+}
+
+// Editor for a new or existing card
+#[derive(Debug, Clone)]
+pub struct CardForm {
+    pub card: Option<String>, // Title of the card being edited, None when adding
+    pub fields: Vec<FormField>,
+    pub selected: usize,
+}
+
+pub enum Mode {
+    Browse,
+    Edit(CardForm),
+}
+
+pub struct App {
+    pub board: Kanban,
+    pub path: PathBuf,
+    pub view: usize, // Index into board.views
+    pub column: usize,
+    pub row: usize,
+    pub mode: Mode,
+    pub status: String,
+    pub dirty: bool,
+    pub quit: bool,
+    confirm_quit: bool,
+}
+
+impl App {
+    pub fn new(board: Kanban, path: PathBuf) -> Self {
+        // Start on the board's default view
+        let view = board
+            .default_view()
+            .and_then(|default| board.views.iter().position(|v| Rc::ptr_eq(v, &default)))
+            .unwrap_or(0);
+
+        App {
+            board,
+            path,
+            view,
+            column: 0,
+            row: 0,
+            mode: Mode::Browse,
+            status: String::new(),
+            dirty: false,
+            quit: false,
+            confirm_quit: false,
+        }
+    }
+
+    pub fn view_name(&self) -> Option<String> {
+        self.board
+            .views
+            .get(self.view)
+            .map(|view| view.borrow().name.clone())
+    }
+
+    // Name of the property the current view groups by, if it is a board
+    pub fn group_by(&self) -> Option<String> {
+        let view = self.board.views.get(self.view)?.borrow();
+        match &view.view_layout {
+            KanbanViewType::Board {
+                group_by: Some(prop),
+            } => Some(prop.borrow().name.clone()),
+            _ => None,
+        }
+    }
+
+    // Columns of the current view: board groups in column order, or a single
+    // column holding the view's cards for other layouts
+    pub fn columns(&self) -> Result<Vec<KanbanColumn>> {
+        let Some(view_name) = self.view_name() else {
+            return Ok(Vec::new());
+        };
+
+        if self.group_by().is_some() {
+            self.board.get_ordered_groups(&view_name)
+        } else {
+            Ok(vec![(
+                view_name.clone(),
+                self.board.get_view_cards(&view_name)?,
+            )])
+        }
+    }
+
+    pub fn selected_card(&self) -> Option<String> {
+        let columns = self.columns().ok()?;
+        let (_, cards) = columns.get(self.column)?;
+        cards.get(self.row).map(|card| card.borrow().title.clone())
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> Result<()> {
+        let result = match self.mode {
+            Mode::Browse => self.handle_browse_key(key),
+            Mode::Edit(_) => self.handle_edit_key(key),
+        };
+
+        if let Err(e) = &result {
+            self.status = e.to_string();
+        }
+        self.clamp_selection();
+
+        Ok(())
+    }
+
+    fn handle_browse_key(&mut self, key: KeyEvent) -> Result<()> {
+        let quitting = self.confirm_quit;
+        self.confirm_quit = false;
+        self.status.clear();
+
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => {
+                if self.dirty && !quitting {
+                    self.confirm_quit = true;
+                    self.status = "Unsaved changes: press q again to quit, s to save".to_string();
+                } else {
+                    self.quit = true;
+                }
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => self.quit = true,
+            KeyCode::Left if shift => self.move_card(-1)?,
+            KeyCode::Right if shift => self.move_card(1)?,
+            KeyCode::Char('H') => self.move_card(-1)?,
+            KeyCode::Char('L') => self.move_card(1)?,
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.column = self.column.saturating_sub(1);
+                self.row = 0;
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.column += 1;
+                self.row = 0;
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.row = self.row.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.row += 1,
+            KeyCode::Tab => self.switch_view(1),
+            KeyCode::BackTab => self.switch_view(-1),
+            KeyCode::Enter | KeyCode::Char('e') => {
+                if let Some(title) = self.selected_card() {
+                    self.mode = Mode::Edit(self.card_form(Some(&title))?);
+                }
+            }
+            KeyCode::Char('a') => self.mode = Mode::Edit(self.card_form(None)?),
+            KeyCode::Char('s') => self.save()?,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn handle_edit_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Mode::Edit(form) = &mut self.mode else {
+            return Ok(());
+        };
+        let field = &mut form.fields[form.selected];
+
+        match key.code {
+            KeyCode::Esc => self.mode = Mode::Browse,
+            KeyCode::Enter
+                if key.modifiers.contains(KeyModifiers::ALT)
+                    && field.kind == FieldKind::Description =>
+            {
+                field.value.push('\n');
+            }
+            KeyCode::Enter => {
+                let form = form.clone();
+                self.submit(form)?;
+                self.mode = Mode::Browse;
+            }
+            KeyCode::Up | KeyCode::BackTab => {
+                form.selected = form
+                    .selected
+                    .checked_sub(1)
+                    .unwrap_or(form.fields.len() - 1);
+            }
+            KeyCode::Down | KeyCode::Tab => form.selected = (form.selected + 1) % form.fields.len(),
+            // Left/Right cycle through the options of Select properties
+            KeyCode::Left | KeyCode::Right => {
+                if let FieldKind::Property(options) = &field.kind
+                    && !options.is_empty()
+                {
+                    let current = options.iter().position(|o| *o == field.value);
+                    let next = match (current, key.code) {
+                        (None, KeyCode::Left) => options.len() - 1,
+                        (None, _) => 0,
+                        (Some(idx), KeyCode::Left) => (idx + options.len() - 1) % options.len(),
+                        (Some(idx), _) => (idx + 1) % options.len(),
+                    };
+                    field.value = options[next].clone();
+                }
+            }
+            KeyCode::Backspace => {
+                field.value.pop();
+            }
+            KeyCode::Char(c) => field.value.push(c),
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn switch_view(&mut self, step: isize) {
+        let count = self.board.views.len();
+        if count > 0 {
+            self.view = (self.view as isize + step).rem_euclid(count as isize) as usize;
+            self.column = 0;
+            self.row = 0;
+        }
+    }
+
+    // Move the selected card to the neighbouring column and keep it selected
+    fn move_card(&mut self, step: isize) -> Result<()> {
+        let Some(group_by) = self.group_by() else {
+            bail!("Cards can only be moved in a grouped board view");
+        };
+        let Some(title) = self.selected_card() else {
+            return Ok(());
+        };
+
+        let columns = self.columns()?;
+        let target = self.column as isize + step;
+        if target < 0 || target as usize >= columns.len() {
+            return Ok(());
+        }
+        let (column, _) = &columns[target as usize];
+
+        if column.is_empty() {
+            // The "no value" column clears the property
+            let values = HashMap::from([(group_by, String::new())]);
+            self.board.edit_card(&title, None, None, values)?;
+        } else {
+            let view_name = self.view_name().unwrap_or_default();
+            self.board.move_card(&title, &view_name, column)?;
+        }
+        self.dirty = true;
+
+        // Follow the card to its new column
+        self.select_card(&title)
+    }
+
+    fn card_form(&self, title: Option<&str>) -> Result<CardForm> {
+        let card = match title {
+            Some(title) => Some(
+                self.board
+                    .card_by_title
+                    .get(title)
+                    .ok_or_else(|| anyhow!("Card not found: {title}"))?
+                    .borrow(),
+            ),
+            None => None,
+        };
+
+        let mut fields = vec![FormField {
+            label: "Title".to_string(),
+            kind: FieldKind::Title,
+            value: card.as_ref().map(|c| c.title.clone()).unwrap_or_default(),
+        }];
+
+        // New cards start in the selected column, with defaults filled in
+        let group_by = self.group_by();
+        let column = self
+            .columns()?
+            .get(self.column)
+            .map(|(column, _)| column.clone())
+            .unwrap_or_default();
+
+        for prop_rc in &self.board.properties {
+            let prop = prop_rc.borrow();
+
+            // Formula values are computed, not entered
+            if matches!(prop.property_type, KanbanPropertyType::Formula { .. }) {
+                continue;
+            }
+
+            let value = match &card {
+                Some(card) => card.get_value(&prop.name).unwrap_or_default().to_string(),
+                None if group_by.as_deref() == Some(prop.name.as_str()) => column.clone(),
+                None => prop.rules.default.clone().unwrap_or_default(),
+            };
+            let options = match &prop.property_type {
+                KanbanPropertyType::Select { options } => options.clone(),
+                KanbanPropertyType::Checkbox => vec!["true".to_string(), "false".to_string()],
+                _ => Vec::new(),
+            };
+
+            fields.push(FormField {
+                label: prop.name.clone(),
+                kind: FieldKind::Property(options),
+                value,
+            });
+        }
+
+        fields.push(FormField {
+            label: "Description".to_string(),
+            kind: FieldKind::Description,
+            value: card
+                .as_ref()
+                .map(|c| c.description.clone())
+                .unwrap_or_default(),
+        });
+
+        Ok(CardForm {
+            card: title.map(str::to_string),
+            fields,
+            selected: 0,
+        })
+    }
+
+    fn submit(&mut self, form: CardForm) -> Result<()> {
+        let mut title = String::new();
+        let mut description = String::new();
+        let mut values = HashMap::new();
+
+        for field in form.fields {
+            let value = field.value.trim().to_string();
+            match field.kind {
+                FieldKind::Title => title = value,
+                FieldKind::Description => description = value,
+                FieldKind::Property(_) => {
+                    values.insert(field.label, value);
+                }
+            }
+        }
+
+        if title.is_empty() {
+            bail!("Title cannot be empty");
+        }
+
+        match form.card {
+            Some(original) => {
+                let new_title = (title != original).then(|| title.clone());
+                self.board
+                    .edit_card(&original, new_title, Some(description), values)?;
+            }
+            None => {
+                if self.board.card_by_title.contains_key(&title) {
+                    bail!("A card named '{title}' already exists");
+                }
+                values.retain(|_, value| !value.is_empty());
+                self.board.add_card(title.clone(), description, values)?;
+            }
+        }
+        self.dirty = true;
+
+        // Select the edited card wherever it ended up
+        self.select_card(&title)
+    }
+
+    fn select_card(&mut self, title: &str) -> Result<()> {
+        for (column_idx, (_, cards)) in self.columns()?.iter().enumerate() {
+            if let Some(row) = cards.iter().position(|c| c.borrow().title == title) {
+                self.column = column_idx;
+                self.row = row;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn save(&mut self) -> Result<()> {
+        self.board.write_file(&self.path)?;
+        self.dirty = false;
+        self.status = format!("Saved {}", self.path.display());
+        Ok(())
+    }
+
+    fn clamp_selection(&mut self) {
+        let Ok(columns) = self.columns() else {
+            return;
+        };
+
+        self.column = self.column.min(columns.len().saturating_sub(1));
+        let rows = columns.get(self.column).map_or(0, |(_, cards)| cards.len());
+        self.row = self.row.min(rows.saturating_sub(1));
+    }
+}
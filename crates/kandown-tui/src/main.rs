@@ -0,0 +1,55 @@
+// crates/kandown-tui/src/main.rs
+use anyhow::{Result, bail};
+use ratatui::{
+    DefaultTerminal,
+    crossterm::event::{self, Event, KeyEventKind},
+};
+use std::{env, path::PathBuf};
+
+use kandown::Kanban;
+
+mod app;
+mod ui;
+
+#[cfg(test)]
+mod test;
+
+use app::App;
+
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 2 {
+        bail!(
+            "Usage: {} <board.md>",
+            args.first().map_or("kandown-tui", |s| s.as_str())
+        )
+    }
+
+    let path = PathBuf::from(&args[1]);
+    let (board, diagnostics) = Kanban::read_file(&path)?;
+
+    let mut app = App::new(board, path);
+    if let Some(diagnostic) = diagnostics.first() {
+        app.status = format!("{diagnostic} ({} warning(s))", diagnostics.len());
+    }
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, &mut app);
+    ratatui::restore();
+
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal, app: &mut App) -> Result<()> {
+    while !app.quit {
+        terminal.draw(|frame| ui::draw(frame, app))?;
+
+        if let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            app.handle_key(key)?;
+        }
+    }
+
+    Ok(())
+}
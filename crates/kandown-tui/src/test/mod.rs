@@ -0,0 +1,2 @@
+// crates/kandown-tui/src/test/mod.rs
+mod test_app;
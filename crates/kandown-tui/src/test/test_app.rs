@@ -0,0 +1,208 @@
+// crates/kandown-tui/src/test/test_app.rs
+use ratatui::{
+    Terminal,
+    backend::TestBackend,
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+};
+use std::{env, fs, path::PathBuf};
+
+use kandown::{Kanban, ParsedDocument};
+
+use crate::{
+    app::{App, Mode},
+    ui,
+};
+
+const BOARD: &str = r#"# Properties
+- Status: Select
+	- Backlog
+	- In Progress
+	- Done
+
+- Owner: Text
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+  Sort Type: Alpha
+  Display: Owner
+
+- All
+  Layout: Table
+
+# Cards
+- Write spec
+  Status: In Progress
+  Owner: alice
+
+- Add tests
+  Status: Backlog
+  Owner: carol
+
+- Benchmark
+  Status: Backlog
+"#;
+
+fn app(path: PathBuf) -> App {
+    let board = Kanban::from_document(ParsedDocument::try_from(BOARD).unwrap()).unwrap();
+    App::new(board, path)
+}
+
+fn press(app: &mut App, code: KeyCode) {
+    app.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+        .unwrap();
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        press(app, KeyCode::Char(c));
+    }
+}
+
+fn render(app: &App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(80, 16)).unwrap();
+    terminal.draw(|frame| ui::draw(frame, app)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let mut screen = String::new();
+    for y in 0..buffer.area.height {
+        for x in 0..buffer.area.width {
+            screen.push_str(buffer[(x, y)].symbol());
+        }
+        screen.push('\n');
+    }
+    screen
+}
+
+#[test]
+fn test_render_board_columns() {
+    let app = app(PathBuf::from("board.md"));
+    let screen = render(&app);
+
+    assert!(screen.contains("Backlog (2)"), "{screen}");
+    assert!(screen.contains("In Progress (1)"), "{screen}");
+    assert!(screen.contains("Done (0)"), "{screen}");
+    assert!(screen.contains("Add tests"), "{screen}");
+    assert!(screen.contains("carol"), "{screen}");
+    assert!(screen.contains("q quit"), "{screen}");
+}
+
+#[test]
+fn test_navigate_and_move_card() {
+    let mut app = app(PathBuf::from("board.md"));
+
+    assert_eq!(app.selected_card().as_deref(), Some("Add tests"));
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.selected_card().as_deref(), Some("Benchmark"));
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.selected_card().as_deref(), Some("Benchmark"));
+
+    // Move Benchmark two columns right; the selection follows it
+    press(&mut app, KeyCode::Char('L'));
+    press(&mut app, KeyCode::Char('L'));
+    assert_eq!(app.column, 2);
+    assert_eq!(app.selected_card().as_deref(), Some("Benchmark"));
+    assert!(app.dirty);
+
+    let card = app.board.card_by_title["Benchmark"].borrow();
+    assert_eq!(card.get_value("Status"), Some("Done"));
+}
+
+#[test]
+fn test_switch_views() {
+    let mut app = app(PathBuf::from("board.md"));
+
+    press(&mut app, KeyCode::Tab);
+    assert_eq!(app.view_name().as_deref(), Some("All"));
+    assert_eq!(app.columns().unwrap().len(), 1);
+    assert!(render(&app).contains("All (3)"));
+
+    // Moving needs a grouped board
+    press(&mut app, KeyCode::Char('L'));
+    assert!(app.status.contains("grouped board"));
+
+    press(&mut app, KeyCode::BackTab);
+    assert_eq!(app.view_name().as_deref(), Some("Board"));
+}
+
+#[test]
+fn test_edit_card() {
+    let mut app = app(PathBuf::from("board.md"));
+
+    press(&mut app, KeyCode::Char('e'));
+    assert!(matches!(app.mode, Mode::Edit(_)));
+    assert!(render(&app).contains("Edit card"));
+
+    // Title field: replace "Add tests" with "Add more tests"
+    for _ in 0.."tests".len() {
+        press(&mut app, KeyCode::Backspace);
+    }
+    type_text(&mut app, "more tests");
+
+    // Status field: cycle from Backlog to In Progress
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Right);
+
+    // Description field
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    type_text(&mut app, "Cover the parser");
+
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.mode, Mode::Browse));
+    assert_eq!(app.selected_card().as_deref(), Some("Add more tests"));
+
+    let card = app.board.card_by_title["Add more tests"].borrow();
+    assert_eq!(card.get_value("Status"), Some("In Progress"));
+    assert_eq!(card.get_value("Owner"), Some("carol"));
+    assert_eq!(card.description, "Cover the parser");
+}
+
+#[test]
+fn test_add_card_rejects_invalid_values() {
+    let mut app = app(PathBuf::from("board.md"));
+    press(&mut app, KeyCode::Right);
+
+    press(&mut app, KeyCode::Char('a'));
+    type_text(&mut app, "Triage");
+    press(&mut app, KeyCode::Down);
+    type_text(&mut app, "x");
+    press(&mut app, KeyCode::Enter);
+
+    // "In Progressx" is not an option, so the form stays open
+    assert!(matches!(app.mode, Mode::Edit(_)));
+    assert!(app.status.contains("Invalid value"), "{}", app.status);
+
+    press(&mut app, KeyCode::Backspace);
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.mode, Mode::Browse));
+    assert_eq!(app.selected_card().as_deref(), Some("Triage"));
+    assert_eq!(app.columns().unwrap()[1].1.len(), 2);
+}
+
+#[test]
+fn test_save_and_quit() {
+    let path = env::temp_dir().join(format!("kandown-tui-{}.md", std::process::id()));
+    fs::write(&path, BOARD).unwrap();
+    let mut app = app(path.clone());
+
+    press(&mut app, KeyCode::Char('L'));
+
+    // Quitting with unsaved changes asks for confirmation
+    press(&mut app, KeyCode::Char('q'));
+    assert!(!app.quit);
+    assert!(app.status.contains("Unsaved"));
+
+    press(&mut app, KeyCode::Char('s'));
+    assert!(!app.dirty);
+    let saved = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert!(
+        saved.contains("- Add tests\n  Status: In Progress\n"),
+        "{saved}"
+    );
+
+    press(&mut app, KeyCode::Char('q'));
+    assert!(app.quit);
+}
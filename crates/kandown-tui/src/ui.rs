@@ -0,0 +1,187 @@
+// crates/kandown-tui/src/ui.rs
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Tabs, Wrap},
+};
+
+use crate::app::{App, CardForm, FieldKind, Mode};
+
+const BROWSE_HELP: &str = "←→↑↓ select  H/L move  Tab view  e edit  a add  s save  q quit";
+const EDIT_HELP: &str = "↑↓ field  ←→ option  Enter save  Alt+Enter newline  Esc cancel";
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [tabs_area, main_area, status_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let titles: Vec<String> = app
+        .board
+        .views
+        .iter()
+        .map(|view| view.borrow().name.clone())
+        .collect();
+    let tabs = Tabs::new(titles)
+        .select(app.view)
+        .highlight_style(Style::new().add_modifier(Modifier::BOLD | Modifier::REVERSED));
+    frame.render_widget(tabs, tabs_area);
+
+    draw_columns(frame, app, main_area);
+
+    let status = match (&app.mode, app.status.is_empty()) {
+        (_, false) => Line::from(app.status.as_str()).fg(Color::Yellow),
+        (Mode::Browse, true) => {
+            let dirty = if app.dirty { "[modified] " } else { "" };
+            Line::from(format!("{dirty}{BROWSE_HELP}")).dim()
+        }
+        (Mode::Edit(_), true) => Line::from(EDIT_HELP).dim(),
+    };
+    frame.render_widget(Paragraph::new(status), status_area);
+
+    if let Mode::Edit(form) = &app.mode {
+        draw_form(frame, form, main_area);
+    }
+}
+
+fn draw_columns(frame: &mut Frame, app: &App, area: Rect) {
+    let columns = match app.columns() {
+        Ok(columns) => columns,
+        Err(e) => {
+            frame.render_widget(Paragraph::new(e.to_string()).fg(Color::Red), area);
+            return;
+        }
+    };
+    if columns.is_empty() {
+        frame.render_widget(Paragraph::new("No cards").dim(), area);
+        return;
+    }
+
+    let display: Vec<String> = app
+        .board
+        .views
+        .get(app.view)
+        .map(|view| {
+            view.borrow()
+                .display
+                .iter()
+                .map(|p| p.borrow().name.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let areas = Layout::horizontal(vec![Constraint::Fill(1); columns.len()]).split(area);
+
+    for (idx, ((column, cards), area)) in columns.iter().zip(areas.iter()).enumerate() {
+        let selected = idx == app.column;
+        let name = if column.is_empty() {
+            "No value"
+        } else {
+            column.as_str()
+        };
+
+        let items: Vec<ListItem> = cards
+            .iter()
+            .map(|card_rc| {
+                let card = card_rc.borrow();
+                let mut lines = vec![Line::from(card.title.clone())];
+                let values: Vec<String> = display
+                    .iter()
+                    .filter_map(|name| card.get_value(name).filter(|v| !v.is_empty()))
+                    .map(str::to_string)
+                    .collect();
+                if !values.is_empty() {
+                    lines.push(Line::from(values.join(" · ")).dim());
+                }
+                ListItem::new(lines)
+            })
+            .collect();
+
+        let border_style = if selected {
+            Style::new().fg(Color::Cyan)
+        } else {
+            Style::new()
+        };
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(border_style)
+                    .title(format!(" {name} ({}) ", cards.len())),
+            )
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+
+        let mut state = ListState::default();
+        if selected && !cards.is_empty() {
+            state.select(Some(app.row));
+        }
+        frame.render_stateful_widget(list, *area, &mut state);
+    }
+}
+
+fn draw_form(frame: &mut Frame, form: &CardForm, area: Rect) {
+    let title = match &form.card {
+        Some(_) => " Edit card ",
+        None => " New card ",
+    };
+
+    let width = area.width.saturating_sub(8).min(70);
+    let height = (form.fields.len() as u16 + 6).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let mut lines = Vec::new();
+    for (idx, field) in form.fields.iter().enumerate() {
+        let selected = idx == form.selected;
+        let label_style = if selected {
+            Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        } else {
+            Style::new().dim()
+        };
+        let cursor = if selected { "▏" } else { "" };
+
+        let mut value_lines = field.value.split('\n');
+        let first = value_lines.next().unwrap_or_default();
+        let mut spans = vec![
+            Span::styled(format!("{}: ", field.label), label_style),
+            Span::raw(first.to_string()),
+        ];
+
+        let rest: Vec<&str> = value_lines.collect();
+        if rest.is_empty() {
+            spans.push(Span::raw(cursor));
+        }
+        if let FieldKind::Property(options) = &field.kind
+            && selected
+            && !options.is_empty()
+        {
+            spans.push(Span::raw(format!("  ({})", options.join(" / "))).dim());
+        }
+        lines.push(Line::from(spans));
+
+        for (line_idx, line) in rest.iter().enumerate() {
+            let cursor = if line_idx == rest.len() - 1 {
+                cursor
+            } else {
+                ""
+            };
+            lines.push(Line::from(format!("  {line}{cursor}")));
+        }
+    }
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::ALL).title(title)),
+        popup,
+    );
+}
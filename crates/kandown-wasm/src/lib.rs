@@ -10,18 +10,63 @@ pub struct WasmKanbanBoard {
     board: kandown::Kanban,
     // Store mapping from ID to title for efficient lookups
     id_to_title: HashMap<String, String>,
+    // Warnings from building the board, for the UI to display
+    diagnostics: Vec<DiagnosticData>,
 }
 
 // Comprehensive data structure for board views
 #[derive(Serialize, Deserialize, Reflect)]
 #[serde(rename_all = "camelCase")]
 pub struct KanbanViewData {
+    metadata: MetadataData,
+    default_view: Option<String>, // The metadata's default view, else the first
     views: Vec<ViewInfo>,
     columns: Vec<ColumnData>,
     cards: HashMap<String, CardData>,
     properties: Vec<PropertyData>,
     group_by_property: Option<String>,
     items: HashMap<String, Vec<String>>, // For DnD structure
+    diagnostics: Vec<DiagnosticData>,
+}
+
+#[derive(Default, Serialize, Deserialize, Reflect)]
+#[serde(default, rename_all = "camelCase")]
+struct MetadataData {
+    title: Option<String>,
+    description: Option<String>,
+    owner: Option<String>,
+    version: Option<u32>,
+    default_view: Option<String>,
+    timezone: Option<String>,
+    locale: Option<String>,
+}
+
+impl From<&kandown::BoardMetadata> for MetadataData {
+    fn from(metadata: &kandown::BoardMetadata) -> Self {
+        MetadataData {
+            title: metadata.title.clone(),
+            description: metadata.description.clone(),
+            owner: metadata.owner.clone(),
+            version: metadata.version,
+            default_view: metadata.default_view.clone(),
+            timezone: metadata.timezone.clone(),
+            locale: metadata.locale.clone(),
+        }
+    }
+}
+
+impl From<MetadataData> for kandown::BoardMetadata {
+    fn from(data: MetadataData) -> Self {
+        kandown::BoardMetadata {
+            title: data.title,
+            description: data.description,
+            owner: data.owner,
+            version: data.version,
+            default_view: data.default_view,
+            timezone: data.timezone,
+            locale: data.locale,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Reflect)]
@@ -40,6 +85,9 @@ struct PropertyData {
     name: String,
     property_type: String,
     options: Option<Vec<String>>,
+    formula: Option<String>, // Expression of a formula property, which is read-only
+    default_value: Option<String>,
+    required: bool,
 }
 
 #[derive(Serialize, Deserialize, Reflect)]
@@ -50,6 +98,31 @@ struct ColumnData {
     cards: Vec<String>, // Just the card IDs
 }
 
+#[derive(Clone, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+struct DiagnosticData {
+    severity: String, // "error" or "warning"
+    code: String,
+    message: String,
+    section: Option<String>,
+    name: Option<String>, // Property name, view name or card title
+    line: Option<usize>,
+}
+
+impl From<kandown::Diagnostic> for DiagnosticData {
+    fn from(diagnostic: kandown::Diagnostic) -> Self {
+        let location = diagnostic.location;
+        DiagnosticData {
+            severity: diagnostic.severity.to_string(),
+            code: diagnostic.code,
+            message: diagnostic.message,
+            section: location.as_ref().map(|l| format!("{:?}", l.section)),
+            name: location.as_ref().map(|l| l.name.clone()),
+            line: location.and_then(|l| l.line),
+        }
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, Reflect)]
 #[serde(rename_all = "camelCase")]
 struct CardData {
@@ -57,6 +130,53 @@ struct CardData {
     title: String,
     description: String,
     properties: HashMap<String, String>,
+    blocked: bool,           // Some blocker is not done yet
+    blocked_by: Vec<String>, // Titles of the open blockers
+    subtasks: Vec<SubtaskData>,
+    progress: Option<String>, // Done out of total subtasks, e.g. "3/5"
+    parent: Option<String>,
+    children: Vec<String>,
+    rollup: Option<RollupData>, // Status of the children, if any
+}
+
+#[derive(Clone, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+struct SubtaskData {
+    text: String,
+    done: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+struct RollupData {
+    total: usize,
+    done: usize,
+    by_status: Vec<StatusCount>, // In column order, "" for no status
+}
+
+#[derive(Clone, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "camelCase")]
+struct StatusCount {
+    status: String,
+    count: usize,
+}
+
+// The library has no clock on wasm, so formulas use the browser's local date
+fn browser_today() -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let now = js_sys::Date::new_0();
+        Some(format!(
+            "{:04}-{:02}-{:02}",
+            now.get_full_year(),
+            now.get_month() + 1,
+            now.get_date()
+        ))
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
 }
 
 #[wasm_bindgen]
@@ -65,15 +185,50 @@ impl WasmKanbanBoard {
     pub fn new(markdown: &str) -> Result<WasmKanbanBoard, JsValue> {
         let parsed_doc = kandown::ParsedDocument::try_from(markdown)
             .map_err(|e| JsValue::from_str(&e.to_string()))?;
-        let board = kandown::Kanban::from_document(parsed_doc)
-            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let (mut board, mut diagnostics) =
+            kandown::Kanban::from_document_with_diagnostics(parsed_doc)
+                .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        kandown::locate_diagnostics(markdown, &mut diagnostics);
+
+        if let Some(today) = browser_today() {
+            board.today = Some(today);
+        }
 
         Ok(WasmKanbanBoard {
             board,
             id_to_title: HashMap::new(),
+            diagnostics: diagnostics.into_iter().map(DiagnosticData::from).collect(),
         })
     }
 
+    // Get the warnings produced while building the board, as JSON
+    #[wasm_bindgen(js_name = getDiagnostics)]
+    pub fn get_diagnostics(&self) -> Result<JsValue, JsValue> {
+        let json = serde_json::to_string(&self.diagnostics)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(JsValue::from_str(&json))
+    }
+
+    // Get the board's title, owner and other settings, as JSON
+    #[wasm_bindgen(js_name = getMetadata)]
+    pub fn get_metadata(&self) -> Result<JsValue, JsValue> {
+        let json = serde_json::to_string(&MetadataData::from(&self.board.metadata))
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(JsValue::from_str(&json))
+    }
+
+    // Replace the board's settings from JSON in the shape getMetadata returns
+    #[wasm_bindgen(js_name = setMetadata)]
+    pub fn set_metadata(&mut self, metadata_json: &str) -> Result<(), JsValue> {
+        let metadata: MetadataData =
+            serde_json::from_str(metadata_json).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        self.board.metadata = metadata.into();
+
+        Ok(())
+    }
+
     // Get all available views
     #[wasm_bindgen(js_name = getViewNames)]
     pub fn get_view_names(&self) -> Result<js_sys::Array, JsValue> {
@@ -100,6 +255,8 @@ impl WasmKanbanBoard {
         let mut id_to_title = HashMap::new();
         let mut cards_map = HashMap::new();
         let mut items_map = HashMap::new();
+        // Blocking and parent links are worked out once for all cards
+        let links = self.board.card_links();
 
         // Process columns and cards
         for (group_name, cards) in &grouped_cards {
@@ -113,6 +270,18 @@ impl WasmKanbanBoard {
                     let prop = prop_rc.borrow();
                     properties.insert(prop.name.clone(), value.clone());
                 }
+                for prop_rc in &self.board.properties {
+                    let name = &prop_rc.borrow().name;
+                    if let Some(value) = self.board.formula_value_with(&links, &card, name) {
+                        properties.insert(name.clone(), value);
+                    }
+                }
+
+                let blocked_by: Vec<String> = links
+                    .open_blockers(card_rc)
+                    .iter()
+                    .map(|blocker| blocker.borrow().title.clone())
+                    .collect();
 
                 // Generate a unique ID based on the card
                 let card_id = format!("card_{}", card.id);
@@ -123,6 +292,34 @@ impl WasmKanbanBoard {
                     title: card.title.clone(),
                     description: card.description.clone(),
                     properties,
+                    blocked: !blocked_by.is_empty(),
+                    blocked_by,
+                    subtasks: card
+                        .subtasks
+                        .iter()
+                        .map(|subtask| SubtaskData {
+                            text: subtask.text.clone(),
+                            done: subtask.done,
+                        })
+                        .collect(),
+                    progress: card.computed_value("Subtasks"),
+                    parent: links
+                        .parent(card_rc)
+                        .map(|parent| parent.borrow().title.clone()),
+                    children: links
+                        .children(card_rc)
+                        .iter()
+                        .map(|child| child.borrow().title.clone())
+                        .collect(),
+                    rollup: links.child_rollup(card_rc).map(|rollup| RollupData {
+                        total: rollup.total,
+                        done: rollup.done,
+                        by_status: rollup
+                            .by_status
+                            .into_iter()
+                            .map(|(status, count)| StatusCount { status, count })
+                            .collect(),
+                    }),
                 };
 
                 // Update maps
@@ -156,12 +353,22 @@ impl WasmKanbanBoard {
                     kandown::KanbanPropertyType::Select { options } => {
                         ("Select", Some(options.clone()))
                     }
+                    kandown::KanbanPropertyType::Formula { .. } => ("Formula", None),
+                };
+                let formula = match &prop.property_type {
+                    kandown::KanbanPropertyType::Formula { formula } => {
+                        Some(formula.source().to_string())
+                    }
+                    _ => None,
                 };
 
                 PropertyData {
                     name: prop.name.clone(),
                     property_type: prop_type.to_string(),
                     options,
+                    formula,
+                    default_value: prop.rules.default.clone(),
+                    required: prop.rules.required,
                 }
             })
             .collect();
@@ -186,6 +393,8 @@ impl WasmKanbanBoard {
 
         // Create full view data
         let view_data = KanbanViewData {
+            metadata: MetadataData::from(&self.board.metadata),
+            default_view: self.board.default_view().map(|v| v.borrow().name.clone()),
             views: self
                 .board
                 .get_views()
@@ -230,6 +439,7 @@ impl WasmKanbanBoard {
             properties,
             group_by_property,
             items: items_map,
+            diagnostics: self.diagnostics.clone(),
         };
 
         let json =
@@ -291,9 +501,212 @@ impl WasmKanbanBoard {
         Ok(card_id)
     }
 
+    // Check or uncheck a card's subtask, returning whether it is now done
+    #[wasm_bindgen(js_name = toggleSubtask)]
+    pub fn toggle_subtask(&self, card_id: &str, index: usize) -> Result<bool, JsValue> {
+        let title = self.card_title(card_id)?;
+        self.board
+            .toggle_subtask(&title, index)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    #[wasm_bindgen(js_name = addSubtask)]
+    pub fn add_subtask(&self, card_id: &str, text: &str) -> Result<(), JsValue> {
+        let title = self.card_title(card_id)?;
+        self.board
+            .add_subtask(&title, text)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    #[wasm_bindgen(js_name = removeSubtask)]
+    pub fn remove_subtask(&self, card_id: &str, index: usize) -> Result<(), JsValue> {
+        let title = self.card_title(card_id)?;
+        self.board
+            .remove_subtask(&title, index)
+            .map(|_| ())
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    // Look up a card's title from an id such as "card_1"
+    fn card_title(&self, card_id: &str) -> Result<String, JsValue> {
+        card_id
+            .strip_prefix("card_")
+            .and_then(|id| id.parse::<usize>().ok())
+            .and_then(|id| self.board.card_by_id.get(&id))
+            .map(|card_rc| card_rc.borrow().title.clone())
+            .ok_or_else(|| JsValue::from_str(&format!("Card not found: {card_id}")))
+    }
+
     // Get the current markdown representation of the board
     #[wasm_bindgen(js_name = getMarkdown)]
     pub fn get_markdown(&self) -> String {
         self.board.to_parsed_document().to_string()
     }
+
+    // Render a view as GitHub-flavored markdown (table or per-column checklist)
+    #[wasm_bindgen(js_name = getViewGfm)]
+    pub fn get_view_gfm(&self, view_name: &str) -> Result<String, JsValue> {
+        self.board
+            .render_view_gfm(view_name)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+// A copy of a board in a collaborative editing session. Edits are applied
+// locally and return the sync message to send to the server, as JSON.
+#[wasm_bindgen]
+pub struct WasmBoardReplica {
+    replica: kandown::BoardReplica,
+}
+
+#[wasm_bindgen]
+impl WasmBoardReplica {
+    // Join a session from the welcome message the server sends on connect
+    #[wasm_bindgen(constructor)]
+    pub fn new(welcome_json: &str) -> Result<WasmBoardReplica, JsValue> {
+        let welcome: kandown::SyncMessage =
+            serde_json::from_str(welcome_json).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let replica = kandown::BoardReplica::from_welcome(&welcome)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        Ok(WasmBoardReplica { replica })
+    }
+
+    // Apply a message from the server, returning whether the board changed
+    pub fn receive(&mut self, message_json: &str) -> Result<bool, JsValue> {
+        let message: kandown::SyncMessage =
+            serde_json::from_str(message_json).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        self.replica
+            .apply_message(&message)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    // Get the merged board as markdown, for building a WasmKanbanBoard
+    #[wasm_bindgen(js_name = getMarkdown)]
+    pub fn get_markdown(&self) -> Result<String, JsValue> {
+        self.replica
+            .to_markdown()
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    #[wasm_bindgen(js_name = addCard)]
+    pub fn add_card(
+        &mut self,
+        title: &str,
+        description: &str,
+        properties_json: &str,
+    ) -> Result<String, JsValue> {
+        let properties: HashMap<String, String> =
+            serde_json::from_str(properties_json).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        let op = self.replica.insert_card(title, description, properties);
+        Self::message(op)
+    }
+
+    #[wasm_bindgen(js_name = moveCard)]
+    pub fn move_card(
+        &mut self,
+        title: &str,
+        view_name: &str,
+        column: &str,
+    ) -> Result<String, JsValue> {
+        let card = self.card_id(title)?;
+        let op = self.replica.move_card(card, view_name, column);
+        Self::message(op)
+    }
+
+    // Set a property value; an empty value removes it
+    #[wasm_bindgen(js_name = setProperty)]
+    pub fn set_property(
+        &mut self,
+        title: &str,
+        property: &str,
+        value: &str,
+    ) -> Result<String, JsValue> {
+        let card = self.card_id(title)?;
+        let field = kandown::CardField::Property(property.to_string());
+        let op = self.replica.set_field(card, field, value);
+        Self::message(op)
+    }
+
+    #[wasm_bindgen(js_name = renameCard)]
+    pub fn rename_card(&mut self, title: &str, new_title: &str) -> Result<String, JsValue> {
+        let card = self.card_id(title)?;
+        let op = self
+            .replica
+            .set_field(card, kandown::CardField::Title, new_title);
+        Self::message(op)
+    }
+
+    #[wasm_bindgen(js_name = setDescription)]
+    pub fn set_description(&mut self, title: &str, description: &str) -> Result<String, JsValue> {
+        let card = self.card_id(title)?;
+        let op = self
+            .replica
+            .set_field(card, kandown::CardField::Description, description);
+        Self::message(op)
+    }
+
+    #[wasm_bindgen(js_name = deleteCard)]
+    pub fn delete_card(&mut self, title: &str) -> Result<String, JsValue> {
+        let card = self.card_id(title)?;
+        let op = self.replica.delete_card(card);
+        Self::message(op)
+    }
+
+    // Set the manual order of a column from a JSON array of card titles
+    #[wasm_bindgen(js_name = reorderColumn)]
+    pub fn reorder_column(
+        &mut self,
+        view_name: &str,
+        column: &str,
+        titles_json: &str,
+    ) -> Result<String, JsValue> {
+        let titles: Vec<String> =
+            serde_json::from_str(titles_json).map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let order = titles
+            .iter()
+            .map(|title| self.card_id(title))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let op = self.replica.reorder_column(view_name, column, order);
+        Self::message(op)
+    }
+
+    #[wasm_bindgen(js_name = addSubtask)]
+    pub fn add_subtask(&mut self, title: &str, text: &str) -> Result<String, JsValue> {
+        let card = self.card_id(title)?;
+        let op = self.replica.add_subtask(card, text);
+        Self::message(op)
+    }
+
+    #[wasm_bindgen(js_name = toggleSubtask)]
+    pub fn toggle_subtask(&mut self, title: &str, index: usize) -> Result<String, JsValue> {
+        let card = self.card_id(title)?;
+        let op = self.replica.toggle_subtask(card, index);
+        Self::message(op)
+    }
+
+    #[wasm_bindgen(js_name = removeSubtask)]
+    pub fn remove_subtask(&mut self, title: &str, index: usize) -> Result<String, JsValue> {
+        let card = self.card_id(title)?;
+        let op = self.replica.remove_subtask(card, index);
+        Self::message(op)
+    }
+
+    fn card_id(&self, title: &str) -> Result<kandown::CardId, JsValue> {
+        self.replica
+            .card_id(title)
+            .ok_or_else(|| JsValue::from_str(&format!("Card not found: {title}")))
+    }
+
+    fn message(op: anyhow::Result<kandown::Operation>) -> Result<String, JsValue> {
+        let op = op.map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let message = kandown::SyncMessage::Operations {
+            operations: vec![op],
+        };
+
+        serde_json::to_string(&message).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
 }
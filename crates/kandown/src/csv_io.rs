@@ -0,0 +1,350 @@
+// crates/kandown/src/csv_io.rs
+
+use anyhow::{Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::{Kanban, KanbanCard, KanbanPropertyType, KanbanViewType, PARENT_PROPERTY};
+
+/// What to do when an imported cell holds a value that is not one of a
+/// Select property's options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CsvSelectPolicy {
+    /// Skip the row and report an error
+    #[default]
+    Reject,
+    /// Append the value to the property's options
+    CreateOption,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CsvImportOptions {
+    pub select_policy: CsvSelectPolicy,
+    /// Validate and report without modifying the board
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CsvColumnTarget {
+    Title,
+    Description,
+    Property(String),
+    Ignored,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CsvColumnMapping {
+    pub header: String,
+    pub target: CsvColumnTarget,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CsvCreatedOption {
+    pub property: String,
+    pub option: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CsvRowError {
+    pub line: u64, // 1-based line in the CSV input
+    pub message: String,
+}
+
+/// Outcome of a CSV import. In dry-run mode it describes what would happen.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CsvImportReport {
+    pub dry_run: bool,
+    pub columns: Vec<CsvColumnMapping>,
+    pub added_cards: Vec<String>,
+    pub created_options: Vec<CsvCreatedOption>,
+    pub errors: Vec<CsvRowError>,
+}
+
+impl std::fmt::Display for CsvImportReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verb = if self.dry_run { "Would add" } else { "Added" };
+
+        writeln!(f, "Columns:")?;
+        for CsvColumnMapping { header, target } in &self.columns {
+            match target {
+                CsvColumnTarget::Title => writeln!(f, "  {header} -> title")?,
+                CsvColumnTarget::Description => writeln!(f, "  {header} -> description")?,
+                CsvColumnTarget::Property(name) => writeln!(f, "  {header} -> property {name}")?,
+                CsvColumnTarget::Ignored => writeln!(f, "  {header} -> (ignored)")?,
+            }
+        }
+
+        writeln!(f, "{verb} {} card(s)", self.added_cards.len())?;
+        for title in &self.added_cards {
+            writeln!(f, "  + {title}")?;
+        }
+
+        if !self.created_options.is_empty() {
+            let verb = if self.dry_run {
+                "Would create"
+            } else {
+                "Created"
+            };
+            writeln!(f, "{verb} {} option(s)", self.created_options.len())?;
+            for CsvCreatedOption { property, option } in &self.created_options {
+                writeln!(f, "  + {property}: {option}")?;
+            }
+        }
+
+        if !self.errors.is_empty() {
+            writeln!(f, "Skipped {} row(s)", self.errors.len())?;
+            for CsvRowError { line, message } in &self.errors {
+                writeln!(f, "  line {line}: {message}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Kanban {
+    // Export a view as CSV: a Title column followed by the view's display
+    // properties (or every property when the view displays none)
+    pub fn export_view_csv(&self, view_name: &str) -> Result<String> {
+        let view_rc = self
+            .view_by_name
+            .get(view_name)
+            .ok_or_else(|| anyhow!("View not found: {view_name}"))?;
+
+        let (columns, is_grouped) = {
+            let view = view_rc.borrow();
+            let columns = self.shown_properties(&view, false);
+            let is_grouped = matches!(
+                view.view_layout,
+                KanbanViewType::Board { group_by: Some(_) }
+            );
+
+            (columns, is_grouped)
+        };
+
+        // Board rows follow the column order, other layouts the sorted view
+        let rows: Vec<Rc<RefCell<KanbanCard>>> = if is_grouped {
+            self.get_ordered_groups(view_name)?
+                .into_iter()
+                .flat_map(|(_, cards)| cards)
+                .collect()
+        } else {
+            self.get_view_cards(view_name)?
+        };
+
+        let mut writer = csv::Writer::from_writer(vec![]);
+
+        let mut header = vec!["Title".to_string()];
+        header.extend(columns.iter().map(|prop| prop.borrow().name.clone()));
+        writer.write_record(&header)?;
+
+        for card_rc in rows {
+            let card = card_rc.borrow();
+            let mut record = vec![card.title.clone()];
+            for prop_rc in &columns {
+                let value = self
+                    .card_value(&card, prop_rc)
+                    .map(Cow::into_owned)
+                    .unwrap_or_default();
+                record.push(value);
+            }
+            writer.write_record(&record)?;
+        }
+
+        let bytes = writer.into_inner().map_err(|e| anyhow!("{e}"))?;
+        Ok(String::from_utf8(bytes)?)
+    }
+
+    // Import cards from CSV. Header cells are matched case-insensitively to
+    // `Title`, `Description` or a declared property; other columns are ignored.
+    // Rows that fail validation are skipped and reported.
+    pub fn import_csv(
+        &mut self,
+        input: &str,
+        options: &CsvImportOptions,
+    ) -> Result<CsvImportReport> {
+        let mut reader = csv::ReaderBuilder::new()
+            .flexible(true)
+            .from_reader(input.as_bytes());
+
+        let headers = reader.headers()?.clone();
+        let columns: Vec<CsvColumnMapping> = headers
+            .iter()
+            .map(|header| {
+                let name = header.trim();
+                let target = if name.eq_ignore_ascii_case("Title") {
+                    CsvColumnTarget::Title
+                } else if name.eq_ignore_ascii_case("Description") {
+                    CsvColumnTarget::Description
+                } else if let Some(prop_name) = self
+                    .properties
+                    .iter()
+                    .map(|prop| prop.borrow().name.clone())
+                    .find(|prop_name| prop_name.eq_ignore_ascii_case(name))
+                    && !self.is_formula(&prop_name)
+                {
+                    CsvColumnTarget::Property(prop_name)
+                } else {
+                    CsvColumnTarget::Ignored
+                };
+
+                CsvColumnMapping {
+                    header: header.to_string(),
+                    target,
+                }
+            })
+            .collect();
+
+        if !columns.iter().any(|c| c.target == CsvColumnTarget::Title) {
+            bail!("CSV input has no Title column");
+        }
+
+        let mut report = CsvImportReport {
+            dry_run: options.dry_run,
+            columns,
+            ..Default::default()
+        };
+
+        // Options accepted so far under the CreateOption policy, per property
+        let mut pending_options: HashMap<String, Vec<String>> = HashMap::new();
+
+        for record in reader.records() {
+            let record = record?;
+            let line = record.position().map(|p| p.line()).unwrap_or_default();
+
+            let mut title = String::new();
+            let mut description = String::new();
+            let mut values = HashMap::new();
+            let mut new_options = Vec::new();
+            let mut error = None;
+
+            for (mapping, cell) in report.columns.iter().zip(record.iter()) {
+                let cell = cell.trim();
+                match &mapping.target {
+                    CsvColumnTarget::Title => title = cell.to_string(),
+                    CsvColumnTarget::Description => description = cell.to_string(),
+                    CsvColumnTarget::Ignored => {}
+                    CsvColumnTarget::Property(_) if cell.is_empty() => {}
+                    CsvColumnTarget::Property(prop_name) => {
+                        let prop = self.property_by_name[prop_name].borrow();
+
+                        if let KanbanPropertyType::Select { options: choices } = &prop.property_type
+                        {
+                            let known = choices.iter().any(|o| o == cell)
+                                || pending_options
+                                    .get(prop_name)
+                                    .is_some_and(|o| o.iter().any(|o| o == cell));
+
+                            if !known {
+                                match options.select_policy {
+                                    CsvSelectPolicy::CreateOption => {
+                                        new_options.push((prop_name.clone(), cell.to_string()));
+                                    }
+                                    CsvSelectPolicy::Reject => {
+                                        error =
+                                            Some(format!("Invalid value for {prop_name}: {cell}"));
+                                        break;
+                                    }
+                                }
+                            }
+                        } else if let Err(e) = prop.validate_value(cell) {
+                            error = Some(e.to_string());
+                            break;
+                        }
+
+                        values.insert(prop_name.clone(), cell.to_string());
+                    }
+                }
+            }
+
+            if error.is_none() && title.is_empty() {
+                error = Some("Missing title".to_string());
+            }
+
+            // Check the rest of what adding the card checks, so a dry run
+            // reports the same rows as a real import
+            if error.is_none() {
+                error = self
+                    .check_csv_row(&title, &values, &report.added_cards)
+                    .err()
+                    .map(|e| e.to_string());
+            }
+
+            if error.is_none() && !options.dry_run {
+                error = self
+                    .add_csv_row(&title, description, values, &new_options)
+                    .err()
+                    .map(|e| e.to_string());
+            }
+
+            if let Some(message) = error {
+                report.errors.push(CsvRowError { line, message });
+                continue;
+            }
+
+            for (property, option) in new_options {
+                pending_options
+                    .entry(property.clone())
+                    .or_default()
+                    .push(option.clone());
+                report
+                    .created_options
+                    .push(CsvCreatedOption { property, option });
+            }
+            report.added_cards.push(title);
+        }
+
+        Ok(report)
+    }
+
+    // Defaults, required properties and the parent of an imported row. The
+    // parent may be a card imported by an earlier row.
+    fn check_csv_row(
+        &self,
+        title: &str,
+        values: &HashMap<String, String>,
+        imported: &[String],
+    ) -> Result<()> {
+        self.default_values(values)?;
+
+        if let Some(parent) = values.get(PARENT_PROPERTY).map(|p| p.trim())
+            && !parent.is_empty()
+            && !imported.iter().any(|t| t == parent)
+        {
+            self.check_parent(title, parent)?;
+        }
+
+        Ok(())
+    }
+
+    // Add a checked row with the Select options it introduces, leaving the
+    // options out again if the card cannot be added
+    fn add_csv_row(
+        &mut self,
+        title: &str,
+        description: String,
+        values: HashMap<String, String>,
+        new_options: &[(String, String)],
+    ) -> Result<()> {
+        for (property, option) in new_options {
+            if let KanbanPropertyType::Select { options } =
+                &mut self.property_by_name[property].borrow_mut().property_type
+            {
+                options.push(option.clone());
+            }
+        }
+
+        let result = self.add_card(title.to_string(), description, values);
+        if result.is_err() {
+            for (property, option) in new_options {
+                if let KanbanPropertyType::Select { options } =
+                    &mut self.property_by_name[property].borrow_mut().property_type
+                {
+                    options.retain(|o| o != option);
+                }
+            }
+        }
+
+        result.map(|_| ())
+    }
+}
@@ -0,0 +1,481 @@
+// crates/kandown/src/dependencies.rs
+
+use anyhow::{Result, bail};
+use std::{
+    cell::RefCell,
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    rc::Rc,
+};
+
+use crate::{
+    Diagnostic, DocumentSection, Kanban, KanbanCard, KanbanProperty, KanbanPropertyType,
+    KanbanViewType, ParsedDocument,
+};
+
+// Card properties holding comma-separated titles of cards on the same board
+pub const BLOCKS_PROPERTY: &str = "Blocks";
+pub const BLOCKED_BY_PROPERTY: &str = "Blocked by";
+
+// Blocking links between cards, as positions in the board's card list. Both
+// `Blocks` and `Blocked by` feed into the same edges, so a link only needs to
+// be written on one of the two cards.
+struct DependencyGraph {
+    blockers: Vec<Vec<usize>>,     // For each card, the cards blocking it
+    blocking: Vec<Vec<usize>>,     // For each card, the cards it blocks
+    unknown: Vec<(usize, String)>, // Links naming cards that do not exist
+}
+
+impl DependencyGraph {
+    // `cards` are titles with each card's `Blocks` and `Blocked by` values
+    fn build(cards: &[(&str, Option<&str>, Option<&str>)]) -> Self {
+        // Later cards win on duplicate titles, as with `card_by_title`
+        let index: HashMap<&str, usize> = cards
+            .iter()
+            .enumerate()
+            .map(|(idx, (title, _, _))| (*title, idx))
+            .collect();
+
+        let mut graph = DependencyGraph {
+            blockers: vec![Vec::new(); cards.len()],
+            blocking: vec![Vec::new(); cards.len()],
+            unknown: Vec::new(),
+        };
+
+        for (idx, (_, blocks, blocked_by)) in cards.iter().enumerate() {
+            let links = linked_titles(*blocks)
+                .map(|title| (title, true))
+                .chain(linked_titles(*blocked_by).map(|title| (title, false)));
+
+            for (title, blocks) in links {
+                let Some(&other) = index.get(title) else {
+                    // `board#card` links to other boards belong to workspaces
+                    if !title.contains('#') {
+                        graph.unknown.push((idx, title.to_string()));
+                    }
+                    continue;
+                };
+
+                let (blocker, blocked) = if blocks { (idx, other) } else { (other, idx) };
+                if !graph.blockers[blocked].contains(&blocker) {
+                    graph.blockers[blocked].push(blocker);
+                    graph.blocking[blocker].push(blocked);
+                }
+            }
+        }
+
+        graph
+    }
+
+    // Cards a card blocks, in board order
+    fn blocked_by(&self, blocker: usize) -> Vec<usize> {
+        let mut blocked = self.blocking[blocker].clone();
+        blocked.sort();
+        blocked
+    }
+
+    // Groups of cards that block each other, each sorted by position. Found as
+    // the strongly connected components with more than one card, or a card
+    // that blocks itself.
+    fn cycles(&self) -> Vec<Vec<usize>> {
+        let len = self.blockers.len();
+        let mut next = 0;
+        let mut index: Vec<Option<usize>> = vec![None; len];
+        let mut low = vec![0; len];
+        let mut stack = Vec::new();
+        let mut on_stack = vec![false; len];
+        let mut cycles = Vec::new();
+
+        // Tarjan's algorithm with an explicit call stack of cards and how many
+        // of their blockers have been looked at, so long chains of blocking
+        // cards cannot overflow the thread's stack
+        let mut calls: Vec<(usize, usize)> = Vec::new();
+
+        for start in 0..len {
+            if index[start].is_some() {
+                continue;
+            }
+            calls.push((start, 0));
+
+            while let Some((card, edge)) = calls.pop() {
+                if edge == 0 {
+                    index[card] = Some(next);
+                    low[card] = next;
+                    next += 1;
+                    stack.push(card);
+                    on_stack[card] = true;
+                } else {
+                    // Back from the blocker visited last
+                    let blocker = self.blockers[card][edge - 1];
+                    low[card] = low[card].min(low[blocker]);
+                }
+
+                let mut visit = None;
+                for (edge, &blocker) in self.blockers[card].iter().enumerate().skip(edge) {
+                    match index[blocker] {
+                        None => {
+                            visit = Some((edge, blocker));
+                            break;
+                        }
+                        Some(index) if on_stack[blocker] => low[card] = low[card].min(index),
+                        Some(_) => {}
+                    }
+                }
+                if let Some((edge, blocker)) = visit {
+                    calls.push((card, edge + 1));
+                    calls.push((blocker, 0));
+                    continue;
+                }
+
+                if Some(low[card]) != index[card] {
+                    continue;
+                }
+
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == card {
+                        break;
+                    }
+                }
+
+                if component.len() > 1 || self.blockers[card].contains(&card) {
+                    component.sort();
+                    cycles.push(component);
+                }
+            }
+        }
+
+        cycles.sort();
+        cycles
+    }
+
+    // Cards ordered so that blockers come first, otherwise keeping board
+    // order. Returns the cycles instead when there is no such order.
+    fn topological_order(&self) -> Result<Vec<usize>, Vec<Vec<usize>>> {
+        let mut remaining: Vec<usize> = self.blockers.iter().map(Vec::len).collect();
+        // Ready cards, earliest on the board first
+        let mut ready: BinaryHeap<Reverse<usize>> = (0..remaining.len())
+            .filter(|&idx| remaining[idx] == 0)
+            .map(Reverse)
+            .collect();
+        let mut order = Vec::new();
+
+        while let Some(Reverse(card)) = ready.pop() {
+            order.push(card);
+            for &blocked in &self.blocking[card] {
+                remaining[blocked] -= 1;
+                if remaining[blocked] == 0 {
+                    ready.push(Reverse(blocked));
+                }
+            }
+        }
+
+        if order.len() == remaining.len() {
+            Ok(order)
+        } else {
+            Err(self.cycles())
+        }
+    }
+}
+
+// Split a dependency value into card titles
+fn linked_titles(value: Option<&str>) -> impl Iterator<Item = &str> {
+    value
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+fn cycle_message(titles: &[&str]) -> String {
+    let quoted: Vec<String> = titles.iter().map(|title| format!("'{title}'")).collect();
+    match quoted.as_slice() {
+        [only] => format!("Card {only} blocks itself"),
+        [rest @ .., last] => format!("Cards {} and {last} block each other", rest.join(", ")),
+        [] => String::new(),
+    }
+}
+
+impl KanbanCard {
+    // Titles this card lists under a dependency property
+    pub fn linked_titles(&self, property_name: &str) -> Vec<&str> {
+        linked_titles(self.get_value(property_name)).collect()
+    }
+}
+
+/// Blocking and parent links between a board's cards, worked out once for
+/// all of them. Build one with [`Kanban::card_links`] to look at many cards
+/// without going through the whole board for each; it does not follow later
+/// changes to the board.
+pub struct CardLinks {
+    pub(crate) cards: Vec<Rc<RefCell<KanbanCard>>>,
+    positions: HashMap<*const RefCell<KanbanCard>, usize>,
+    graph: DependencyGraph,
+    pub(crate) parents: Vec<Option<usize>>,
+    pub(crate) children: Vec<Vec<usize>>,
+    pub(crate) done: Vec<bool>,
+    pub(crate) status: Option<Rc<RefCell<KanbanProperty>>>,
+}
+
+impl CardLinks {
+    pub(crate) fn position(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Option<usize> {
+        self.positions.get(&Rc::as_ptr(card_rc)).copied()
+    }
+
+    pub(crate) fn cards_at(
+        &self,
+        positions: impl IntoIterator<Item = usize>,
+    ) -> Vec<Rc<RefCell<KanbanCard>>> {
+        positions
+            .into_iter()
+            .map(|idx| Rc::clone(&self.cards[idx]))
+            .collect()
+    }
+
+    // Cards that block this one, whichever side the link is written on
+    pub fn blockers(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Vec<Rc<RefCell<KanbanCard>>> {
+        let Some(idx) = self.position(card_rc) else {
+            return Vec::new();
+        };
+        let mut blockers = self.graph.blockers[idx].clone();
+        blockers.sort();
+
+        self.cards_at(blockers)
+    }
+
+    // Cards this one blocks
+    pub fn blocked_cards(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Vec<Rc<RefCell<KanbanCard>>> {
+        let Some(idx) = self.position(card_rc) else {
+            return Vec::new();
+        };
+
+        self.cards_at(self.graph.blocked_by(idx))
+    }
+
+    pub fn is_done(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> bool {
+        self.position(card_rc).is_some_and(|idx| self.done[idx])
+    }
+
+    // Blockers of a card that are not done yet
+    pub fn open_blockers(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Vec<Rc<RefCell<KanbanCard>>> {
+        let Some(idx) = self.position(card_rc) else {
+            return Vec::new();
+        };
+        let mut open: Vec<usize> = self.graph.blockers[idx]
+            .iter()
+            .copied()
+            .filter(|&blocker| !self.done[blocker])
+            .collect();
+        open.sort();
+
+        self.cards_at(open)
+    }
+
+    pub fn is_blocked(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> bool {
+        self.position(card_rc).is_some_and(|idx| {
+            self.graph.blockers[idx]
+                .iter()
+                .any(|&blocker| !self.done[blocker])
+        })
+    }
+}
+
+impl Kanban {
+    // Links between all cards, for looking at many cards at once
+    pub fn card_links(&self) -> CardLinks {
+        let graph = self.dependency_graph();
+        let (parents, _) = self.parent_links();
+
+        let mut children = vec![Vec::new(); parents.len()];
+        for (child, parent) in parents.iter().enumerate() {
+            if let Some(parent) = parent {
+                children[*parent].push(child);
+            }
+        }
+
+        CardLinks {
+            cards: self.cards.clone(),
+            positions: self
+                .cards
+                .iter()
+                .enumerate()
+                .map(|(idx, card_rc)| (Rc::as_ptr(card_rc), idx))
+                .collect(),
+            graph,
+            parents,
+            children,
+            done: self
+                .cards
+                .iter()
+                .map(|card_rc| self.is_done(card_rc))
+                .collect(),
+            status: self.status_property(),
+        }
+    }
+
+    fn dependency_graph(&self) -> DependencyGraph {
+        let cards: Vec<_> = self.cards.iter().map(|card_rc| card_rc.borrow()).collect();
+        let links: Vec<(&str, Option<&str>, Option<&str>)> = cards
+            .iter()
+            .map(|card| {
+                (
+                    card.title.as_str(),
+                    card.get_value(BLOCKS_PROPERTY),
+                    card.get_value(BLOCKED_BY_PROPERTY),
+                )
+            })
+            .collect();
+
+        DependencyGraph::build(&links)
+    }
+
+    pub(crate) fn position(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Option<usize> {
+        self.cards.iter().position(|c| Rc::ptr_eq(c, card_rc))
+    }
+
+    pub(crate) fn cards_at(
+        &self,
+        positions: impl IntoIterator<Item = usize>,
+    ) -> Vec<Rc<RefCell<KanbanCard>>> {
+        positions
+            .into_iter()
+            .map(|idx| Rc::clone(&self.cards[idx]))
+            .collect()
+    }
+
+    // Cards that block this one, whichever side the link is written on
+    pub fn blockers(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Vec<Rc<RefCell<KanbanCard>>> {
+        self.card_links().blockers(card_rc)
+    }
+
+    // Cards this one blocks
+    pub fn blocked_cards(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Vec<Rc<RefCell<KanbanCard>>> {
+        self.card_links().blocked_cards(card_rc)
+    }
+
+    // The property the first board view groups by, which holds card status
+    pub fn status_property(&self) -> Option<Rc<RefCell<KanbanProperty>>> {
+        self.views
+            .iter()
+            .find_map(|view_rc| match &view_rc.borrow().view_layout {
+                KanbanViewType::Board {
+                    group_by: Some(group_by),
+                } => Some(Rc::clone(group_by)),
+                _ => None,
+            })
+    }
+
+    // A card is done when it sits in the last column of the first board view,
+    // e.g. `Done` for a `Todo, Doing, Done` status
+    pub fn is_done(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> bool {
+        let Some(status) = self.status_property() else {
+            return false;
+        };
+
+        let prop = status.borrow();
+        let KanbanPropertyType::Select { options } = &prop.property_type else {
+            return false;
+        };
+        let done = options.last().map(String::as_str);
+
+        done.is_some() && card_rc.borrow().get_value(&prop.name) == done
+    }
+
+    // Blockers of a card that are not done yet
+    pub fn open_blockers(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Vec<Rc<RefCell<KanbanCard>>> {
+        self.card_links().open_blockers(card_rc)
+    }
+
+    pub fn is_blocked(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> bool {
+        self.card_links().is_blocked(card_rc)
+    }
+
+    // Cards that are not done and whose blockers are all done
+    pub fn ready_cards(&self) -> Vec<Rc<RefCell<KanbanCard>>> {
+        let links = self.card_links();
+
+        self.cards
+            .iter()
+            .filter(|card_rc| !links.is_done(card_rc) && !links.is_blocked(card_rc))
+            .cloned()
+            .collect()
+    }
+
+    // Groups of cards that block each other, in board order
+    pub fn dependency_cycles(&self) -> Vec<Vec<Rc<RefCell<KanbanCard>>>> {
+        self.dependency_graph()
+            .cycles()
+            .into_iter()
+            .map(|cycle| self.cards_at(cycle))
+            .collect()
+    }
+
+    // All cards with blockers before the cards they block
+    pub fn topological_order(&self) -> Result<Vec<Rc<RefCell<KanbanCard>>>> {
+        match self.dependency_graph().topological_order() {
+            Ok(order) => Ok(self.cards_at(order)),
+            Err(cycles) => {
+                let cards = self
+                    .cards
+                    .iter()
+                    .map(|card_rc| card_rc.borrow())
+                    .collect::<Vec<_>>();
+                let titles: Vec<&str> = cycles[0]
+                    .iter()
+                    .map(|&idx| cards[idx].title.as_str())
+                    .collect();
+                bail!("Cannot order cards: {}", cycle_message(&titles))
+            }
+        }
+    }
+}
+
+impl ParsedDocument {
+    // Report dependency links to missing cards and cycles of blocking cards
+    pub(crate) fn lint_dependencies(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let value = |idx: usize, name: &str| {
+            self.cards[idx]
+                .properties
+                .iter()
+                .find(|p| p.property_name == name)
+                .map(|p| p.value.as_str())
+        };
+        let links: Vec<(&str, Option<&str>, Option<&str>)> = (0..self.cards.len())
+            .map(|idx| {
+                (
+                    self.cards[idx].title.as_str(),
+                    value(idx, BLOCKS_PROPERTY),
+                    value(idx, BLOCKED_BY_PROPERTY),
+                )
+            })
+            .collect();
+        let graph = DependencyGraph::build(&links);
+
+        for (idx, missing) in &graph.unknown {
+            let title = &self.cards[*idx].title;
+            diagnostics.push(
+                Diagnostic::warning(
+                    "unknown-dependency",
+                    format!("Card '{title}' depends on '{missing}', but there is no such card"),
+                )
+                .at(DocumentSection::Cards, *idx, title),
+            );
+        }
+
+        for cycle in graph.cycles() {
+            let titles: Vec<&str> = cycle
+                .iter()
+                .map(|&idx| self.cards[idx].title.as_str())
+                .collect();
+            diagnostics.push(
+                Diagnostic::error("dependency-cycle", cycle_message(&titles)).at(
+                    DocumentSection::Cards,
+                    cycle[0],
+                    titles[0],
+                ),
+            );
+        }
+    }
+}
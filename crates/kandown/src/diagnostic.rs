@@ -0,0 +1,137 @@
+// crates/kandown/src/diagnostic.rs
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ParserConfig, parse_front_matter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DocumentSection {
+    Properties,
+    Views,
+    Cards,
+}
+
+/// Where in a board a diagnostic applies: the section, the position of the
+/// item within it, and the source line when the markdown is known.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticLocation {
+    pub section: DocumentSection,
+    pub index: usize,
+    pub name: String, // Property name, view name or card title
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>, // 1-based
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<DiagnosticLocation>,
+}
+
+impl Diagnostic {
+    pub fn error(code: &str, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            code: code.to_string(),
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    pub fn warning(code: &str, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity: Severity::Warning,
+            ..Diagnostic::error(code, message)
+        }
+    }
+
+    pub fn at(mut self, section: DocumentSection, index: usize, name: &str) -> Self {
+        self.location = Some(DiagnosticLocation {
+            section,
+            index,
+            name: name.to_string(),
+            line: None,
+        });
+        self
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}]: {}", self.severity, self.code, self.message)
+    }
+}
+
+// Fill in source lines for diagnostics by finding the top-level list item each
+// one points at. Items are matched by position within their section, so
+// duplicate names still resolve to the right line.
+pub fn locate_diagnostics(source: &str, diagnostics: &mut [Diagnostic]) {
+    locate_diagnostics_with(source, &ParserConfig::default(), diagnostics);
+}
+
+// Locate diagnostics in a board written with the given keywords, unless its
+// front matter names a locale
+pub fn locate_diagnostics_with(
+    source: &str,
+    config: &ParserConfig,
+    diagnostics: &mut [Diagnostic],
+) {
+    let locale_config = parse_front_matter(source)
+        .ok()
+        .and_then(|(_, metadata)| metadata.locale)
+        .and_then(|locale| ParserConfig::for_locale(&locale).ok());
+    let config = locale_config.as_ref().unwrap_or(config);
+
+    let mut items: Vec<(DocumentSection, usize)> = Vec::new();
+    let mut section = None;
+
+    for (idx, line) in source.lines().enumerate() {
+        let trimmed = line.trim_start_matches('#').trim();
+        if line.starts_with('#') {
+            section = config.section_for_heading(trimmed);
+        } else if let Some(section) = section
+            && is_top_level_item(line)
+        {
+            items.push((section, idx + 1));
+        }
+    }
+
+    for diagnostic in diagnostics {
+        if let Some(location) = &mut diagnostic.location {
+            location.line = items
+                .iter()
+                .filter(|(section, _)| *section == location.section)
+                .nth(location.index)
+                .map(|(_, line)| *line);
+        }
+    }
+}
+
+fn is_top_level_item(line: &str) -> bool {
+    let marker_end = line
+        .find(|c: char| !c.is_ascii_digit())
+        .filter(|&end| end > 0 && matches!(line[end..].chars().next(), Some('.') | Some(')')))
+        .map(|end| end + 1)
+        .or_else(|| line.starts_with(['-', '*', '+']).then_some(1));
+
+    marker_end.is_some_and(|end| line[end..].starts_with([' ', '\t']))
+}
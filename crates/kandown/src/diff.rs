@@ -0,0 +1,412 @@
+// crates/kandown/src/diff.rs
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+use crate::{ParsedCard, ParsedDocument, ParsedPropertyType, ParsedView, ParsedViewType, Subtask};
+
+/// One semantic difference between two versions of a board
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BoardChange {
+    PropertyAdded {
+        property: String,
+    },
+    PropertyRemoved {
+        property: String,
+    },
+    PropertyTypeChanged {
+        property: String,
+        from: String,
+        to: String,
+    },
+    OptionAdded {
+        property: String,
+        option: String,
+    },
+    OptionRemoved {
+        property: String,
+        option: String,
+    },
+    ViewAdded {
+        view: String,
+    },
+    ViewRemoved {
+        view: String,
+    },
+    ViewChanged {
+        view: String,
+        setting: String,
+        from: Option<String>,
+        to: Option<String>,
+    },
+    CardAdded {
+        card: String,
+    },
+    CardRemoved {
+        card: String,
+    },
+    CardRenamed {
+        from: String,
+        to: String,
+    },
+    // A change to a property some board view groups by
+    CardMoved {
+        card: String,
+        property: String,
+        from: Option<String>,
+        to: Option<String>,
+    },
+    ValueChanged {
+        card: String,
+        property: String,
+        from: Option<String>,
+        to: Option<String>,
+    },
+    DescriptionChanged {
+        card: String,
+    },
+    SubtasksChanged {
+        card: String,
+        from: Option<String>, // Progress such as `2/5`
+        to: Option<String>,
+    },
+}
+
+impl std::fmt::Display for BoardChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = |v: &Option<String>| match v {
+            Some(v) => format!("'{v}'"),
+            None => "(none)".to_string(),
+        };
+
+        match self {
+            BoardChange::PropertyAdded { property } => write!(f, "Added property '{property}'"),
+            BoardChange::PropertyRemoved { property } => {
+                write!(f, "Removed property '{property}'")
+            }
+            BoardChange::PropertyTypeChanged { property, from, to } => {
+                write!(f, "Changed property '{property}' from {from} to {to}")
+            }
+            BoardChange::OptionAdded { property, option } => {
+                write!(f, "Added option '{option}' to '{property}'")
+            }
+            BoardChange::OptionRemoved { property, option } => {
+                write!(f, "Removed option '{option}' from '{property}'")
+            }
+            BoardChange::ViewAdded { view } => write!(f, "Added view '{view}'"),
+            BoardChange::ViewRemoved { view } => write!(f, "Removed view '{view}'"),
+            BoardChange::ViewChanged {
+                view,
+                setting,
+                from,
+                to,
+            } => write!(
+                f,
+                "Changed {setting} of view '{view}' from {} to {}",
+                value(from),
+                value(to)
+            ),
+            BoardChange::CardAdded { card } => write!(f, "Added card '{card}'"),
+            BoardChange::CardRemoved { card } => write!(f, "Removed card '{card}'"),
+            BoardChange::CardRenamed { from, to } => write!(f, "Renamed card '{from}' to '{to}'"),
+            BoardChange::CardMoved {
+                card,
+                property,
+                from,
+                to,
+            } => write!(
+                f,
+                "Moved card '{card}' from {} to {} ({property})",
+                value(from),
+                value(to)
+            ),
+            BoardChange::ValueChanged {
+                card,
+                property,
+                from,
+                to,
+            } => write!(
+                f,
+                "Changed '{property}' of card '{card}' from {} to {}",
+                value(from),
+                value(to)
+            ),
+            BoardChange::DescriptionChanged { card } => {
+                write!(f, "Changed description of card '{card}'")
+            }
+            BoardChange::SubtasksChanged { card, from, to } => write!(
+                f,
+                "Changed checklist of card '{card}' from {} to {}",
+                value(from),
+                value(to)
+            ),
+        }
+    }
+}
+
+impl ParsedDocument {
+    // Describe how `new` differs from this board. Items are matched by name;
+    // a removed card and an added card with the same contents count as a
+    // rename.
+    pub fn diff(&self, new: &ParsedDocument) -> Vec<BoardChange> {
+        let mut changes = Vec::new();
+
+        self.diff_properties(new, &mut changes);
+        self.diff_views(new, &mut changes);
+        self.diff_cards(new, &mut changes);
+
+        changes
+    }
+
+    fn diff_properties(&self, new: &ParsedDocument, changes: &mut Vec<BoardChange>) {
+        for old_prop in &self.properties {
+            let property = old_prop.name.clone();
+            let Some(new_prop) = new.properties.iter().find(|p| p.name == property) else {
+                changes.push(BoardChange::PropertyRemoved { property });
+                continue;
+            };
+
+            match (&old_prop.property_type, &new_prop.property_type) {
+                (
+                    ParsedPropertyType::Select { options: old },
+                    ParsedPropertyType::Select { options: new },
+                ) => {
+                    for option in old.iter().filter(|o| !new.contains(o)) {
+                        changes.push(BoardChange::OptionRemoved {
+                            property: property.clone(),
+                            option: option.clone(),
+                        });
+                    }
+                    for option in new.iter().filter(|o| !old.contains(o)) {
+                        changes.push(BoardChange::OptionAdded {
+                            property: property.clone(),
+                            option: option.clone(),
+                        });
+                    }
+                }
+                (old, new) if old != new => changes.push(BoardChange::PropertyTypeChanged {
+                    property,
+                    from: type_name(old),
+                    to: type_name(new),
+                }),
+                _ => {}
+            }
+        }
+
+        for new_prop in &new.properties {
+            if !self.properties.iter().any(|p| p.name == new_prop.name) {
+                changes.push(BoardChange::PropertyAdded {
+                    property: new_prop.name.clone(),
+                });
+            }
+        }
+    }
+
+    fn diff_views(&self, new: &ParsedDocument, changes: &mut Vec<BoardChange>) {
+        for old_view in &self.views {
+            let view = old_view.name.clone();
+            let Some(new_view) = new.views.iter().find(|v| v.name == view) else {
+                changes.push(BoardChange::ViewRemoved { view });
+                continue;
+            };
+
+            let old_settings = view_settings(old_view, self);
+            let new_settings = view_settings(new_view, new);
+            let settings: Vec<&String> = old_settings
+                .keys()
+                .chain(
+                    new_settings
+                        .keys()
+                        .filter(|s| !old_settings.contains_key(*s)),
+                )
+                .collect();
+
+            for setting in settings {
+                let from = old_settings.get(setting).cloned();
+                let to = new_settings.get(setting).cloned();
+                if from != to {
+                    changes.push(BoardChange::ViewChanged {
+                        view: view.clone(),
+                        setting: setting.clone(),
+                        from,
+                        to,
+                    });
+                }
+            }
+        }
+
+        for new_view in &new.views {
+            if !self.views.iter().any(|v| v.name == new_view.name) {
+                changes.push(BoardChange::ViewAdded {
+                    view: new_view.name.clone(),
+                });
+            }
+        }
+    }
+
+    fn diff_cards(&self, new: &ParsedDocument, changes: &mut Vec<BoardChange>) {
+        let old_titles: HashSet<&str> = self.cards.iter().map(|c| c.title.as_str()).collect();
+        let new_titles: HashSet<&str> = new.cards.iter().map(|c| c.title.as_str()).collect();
+
+        let mut removed: Vec<&ParsedCard> = self
+            .cards
+            .iter()
+            .filter(|c| !new_titles.contains(c.title.as_str()))
+            .collect();
+        let mut added: Vec<&ParsedCard> = new
+            .cards
+            .iter()
+            .filter(|c| !old_titles.contains(c.title.as_str()))
+            .collect();
+
+        // Pair up removed and added cards with the same contents as renames
+        let mut renamed = Vec::new();
+        removed.retain(|old| {
+            match added.iter().position(|new| {
+                card_values(new) == card_values(old)
+                    && new.description == old.description
+                    && new.subtasks == old.subtasks
+            }) {
+                Some(idx) => {
+                    renamed.push((*old, added.remove(idx)));
+                    false
+                }
+                None => true,
+            }
+        });
+
+        for card in removed {
+            changes.push(BoardChange::CardRemoved {
+                card: card.title.clone(),
+            });
+        }
+        for card in added {
+            changes.push(BoardChange::CardAdded {
+                card: card.title.clone(),
+            });
+        }
+        for (old, new) in renamed {
+            changes.push(BoardChange::CardRenamed {
+                from: old.title.clone(),
+                to: new.title.clone(),
+            });
+        }
+
+        // Properties that board views group by, where a change is a move
+        let group_properties: HashSet<&str> = new
+            .views
+            .iter()
+            .filter(|v| v.layout == ParsedViewType::Board)
+            .filter_map(|v| v.group.as_deref())
+            .collect();
+
+        for old_card in &self.cards {
+            let Some(new_card) = new.cards.iter().find(|c| c.title == old_card.title) else {
+                continue;
+            };
+            let card = new_card.title.clone();
+            let old_values = card_values(old_card);
+            let new_values = card_values(new_card);
+
+            // Report properties in declaration order
+            let mut names: Vec<&String> = old_values
+                .keys()
+                .chain(new_values.keys().filter(|k| !old_values.contains_key(*k)))
+                .collect();
+            names.sort_by_key(|name| new.properties.iter().position(|p| p.name == **name));
+            for property in names {
+                let from = old_values.get(property).cloned();
+                let to = new_values.get(property).cloned();
+                if from == to {
+                    continue;
+                }
+
+                let (card, property) = (card.clone(), property.clone());
+                changes.push(if group_properties.contains(property.as_str()) {
+                    BoardChange::CardMoved {
+                        card,
+                        property,
+                        from,
+                        to,
+                    }
+                } else {
+                    BoardChange::ValueChanged {
+                        card,
+                        property,
+                        from,
+                        to,
+                    }
+                });
+            }
+
+            if old_card.description != new_card.description {
+                changes.push(BoardChange::DescriptionChanged { card: card.clone() });
+            }
+
+            if old_card.subtasks != new_card.subtasks {
+                changes.push(BoardChange::SubtasksChanged {
+                    card,
+                    from: progress(&old_card.subtasks),
+                    to: progress(&new_card.subtasks),
+                });
+            }
+        }
+    }
+}
+
+fn type_name(property_type: &ParsedPropertyType) -> String {
+    match property_type {
+        ParsedPropertyType::Text => "Text".to_string(),
+        ParsedPropertyType::Select { .. } => "Select".to_string(),
+        ParsedPropertyType::Number => "Number".to_string(),
+        ParsedPropertyType::Date => "Date".to_string(),
+        ParsedPropertyType::Checkbox => "Checkbox".to_string(),
+        ParsedPropertyType::Formula { expression } => format!("Formula({expression})"),
+    }
+}
+
+fn progress(subtasks: &[Subtask]) -> Option<String> {
+    let done = subtasks.iter().filter(|s| s.done).count();
+    (!subtasks.is_empty()).then(|| format!("{done}/{}", subtasks.len()))
+}
+
+fn card_values(card: &ParsedCard) -> BTreeMap<String, String> {
+    card.properties
+        .iter()
+        .map(|p| (p.property_name.clone(), p.value.clone()))
+        .collect()
+}
+
+// A view's settings by name, with manual column orders as card titles so that
+// cards moving position in the file do not show up as changes
+fn view_settings(view: &ParsedView, doc: &ParsedDocument) -> BTreeMap<String, String> {
+    let mut settings = BTreeMap::from([
+        ("layout".to_string(), view.layout.to_string()),
+        ("sort type".to_string(), format!("{:?}", view.sort_type)),
+    ]);
+
+    let optional = [
+        ("group", &view.group),
+        ("filter", &view.filter),
+        ("sort", &view.sort_by),
+        ("display", &view.display),
+    ];
+    for (name, value) in optional {
+        if let Some(value) = value {
+            settings.insert(name.to_string(), value.clone());
+        }
+    }
+
+    for sort in &view.column_sorts {
+        let titles: Vec<&str> = sort
+            .order
+            .iter()
+            .filter_map(|id| doc.cards.iter().find(|c| c.id == *id))
+            .map(|card| card.title.as_str())
+            .collect();
+        settings.insert(format!("order of '{}'", sort.column), titles.join(", "));
+    }
+
+    settings
+}
@@ -0,0 +1,327 @@
+// crates/kandown/src/exchange.rs
+
+use anyhow::{Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    BoardMetadata, ColumnSort, KanbanSortType, ParsedCard, ParsedDocument, ParsedProperty,
+    ParsedPropertyType, ParsedPropertyValue, ParsedView, ParsedViewType, ParserConfig,
+    PropertyRules, Subtask,
+};
+
+/// Current version of the JSON/YAML board schema.
+///
+/// Bump this whenever the shape of [`BoardExchange`] changes in a way older
+/// readers could misinterpret. Every earlier version is still accepted on
+/// import.
+///
+/// - 2: board metadata, card subtasks, formula properties and property rules
+/// - 1: properties, views and cards
+pub const BOARD_SCHEMA_VERSION: u32 = 2;
+
+/// Versioned, serializer-agnostic representation of a board used for JSON and
+/// YAML import/export.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BoardExchange {
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "BoardMetadata::is_empty")]
+    pub metadata: BoardMetadata,
+    #[serde(default)]
+    pub properties: Vec<ExchangeProperty>,
+    #[serde(default)]
+    pub views: Vec<ExchangeView>,
+    #[serde(default)]
+    pub cards: Vec<ExchangeCard>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExchangeProperty {
+    pub name: String,
+    #[serde(flatten)]
+    pub property_type: ExchangePropertyType,
+    #[serde(default, skip_serializing_if = "PropertyRules::is_empty")]
+    pub rules: PropertyRules,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum ExchangePropertyType {
+    Text,
+    Number,
+    Date,
+    Checkbox,
+    Select { options: Vec<String> },
+    Formula { expression: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExchangeView {
+    pub name: String,
+    pub layout: ParsedViewType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<String>,
+    #[serde(default)]
+    pub sort_type: KanbanSortType,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub column_sorts: Vec<ExchangeColumnSort>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExchangeColumnSort {
+    pub column: String,
+    pub order: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExchangeCard {
+    pub id: usize,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    #[serde(default)]
+    pub properties: Vec<ExchangePropertyValue>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subtasks: Vec<Subtask>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExchangePropertyValue {
+    pub name: String,
+    pub value: ExchangeValue,
+}
+
+/// A card value typed according to its property declaration.
+///
+/// Values are only emitted as numbers or booleans when converting them back to
+/// text reproduces the original markdown exactly, so exports stay lossless.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ExchangeValue {
+    Bool(bool),
+    Number(serde_json::Number),
+    Text(String),
+}
+
+impl ExchangeValue {
+    fn from_raw(raw: &str, property_type: Option<&ParsedPropertyType>) -> Self {
+        match property_type {
+            Some(ParsedPropertyType::Checkbox) => match raw {
+                "true" => return ExchangeValue::Bool(true),
+                "false" => return ExchangeValue::Bool(false),
+                _ => {}
+            },
+            Some(ParsedPropertyType::Number) => {
+                if let Ok(number) = raw.parse::<serde_json::Number>()
+                    && number.to_string() == raw
+                {
+                    return ExchangeValue::Number(number);
+                }
+            }
+            _ => {}
+        }
+
+        ExchangeValue::Text(raw.to_string())
+    }
+
+    fn into_raw(self) -> String {
+        match self {
+            ExchangeValue::Bool(value) => value.to_string(),
+            ExchangeValue::Number(number) => number.to_string(),
+            ExchangeValue::Text(text) => text,
+        }
+    }
+}
+
+impl From<&ParsedDocument> for BoardExchange {
+    fn from(doc: &ParsedDocument) -> Self {
+        let properties = doc
+            .properties
+            .iter()
+            .map(|prop| ExchangeProperty {
+                name: prop.name.clone(),
+                property_type: match &prop.property_type {
+                    ParsedPropertyType::Text => ExchangePropertyType::Text,
+                    ParsedPropertyType::Number => ExchangePropertyType::Number,
+                    ParsedPropertyType::Date => ExchangePropertyType::Date,
+                    ParsedPropertyType::Checkbox => ExchangePropertyType::Checkbox,
+                    ParsedPropertyType::Select { options } => ExchangePropertyType::Select {
+                        options: options.clone(),
+                    },
+                    ParsedPropertyType::Formula { expression } => ExchangePropertyType::Formula {
+                        expression: expression.clone(),
+                    },
+                },
+                rules: prop.rules.clone(),
+            })
+            .collect();
+
+        let views = doc
+            .views
+            .iter()
+            .map(|view| ExchangeView {
+                name: view.name.clone(),
+                layout: view.layout.clone(),
+                group: view.group.clone(),
+                filter: view.filter.clone(),
+                sort_by: view.sort_by.clone(),
+                sort_type: view.sort_type.clone(),
+                column_sorts: view
+                    .column_sorts
+                    .iter()
+                    .map(|sort| ExchangeColumnSort {
+                        column: sort.column.clone(),
+                        order: sort.order.clone(),
+                    })
+                    .collect(),
+                display: view.display.clone(),
+            })
+            .collect();
+
+        let cards = doc
+            .cards
+            .iter()
+            .map(|card| ExchangeCard {
+                id: card.id,
+                title: card.title.clone(),
+                description: card.description.clone(),
+                properties: card
+                    .properties
+                    .iter()
+                    .map(|value| {
+                        let property_type = doc
+                            .properties
+                            .iter()
+                            .find(|prop| prop.name == value.property_name)
+                            .map(|prop| &prop.property_type);
+
+                        ExchangePropertyValue {
+                            name: value.property_name.clone(),
+                            value: ExchangeValue::from_raw(&value.value, property_type),
+                        }
+                    })
+                    .collect(),
+                subtasks: card.subtasks.clone(),
+            })
+            .collect();
+
+        BoardExchange {
+            version: BOARD_SCHEMA_VERSION,
+            metadata: doc.metadata.clone(),
+            properties,
+            views,
+            cards,
+        }
+    }
+}
+
+impl TryFrom<BoardExchange> for ParsedDocument {
+    type Error = anyhow::Error;
+
+    fn try_from(board: BoardExchange) -> Result<Self> {
+        if board.version == 0 || board.version > BOARD_SCHEMA_VERSION {
+            bail!(
+                "Unsupported board schema version: {} (expected at most {BOARD_SCHEMA_VERSION})",
+                board.version
+            );
+        }
+
+        let properties = board
+            .properties
+            .into_iter()
+            .map(|prop| ParsedProperty {
+                name: prop.name,
+                property_type: match prop.property_type {
+                    ExchangePropertyType::Text => ParsedPropertyType::Text,
+                    ExchangePropertyType::Number => ParsedPropertyType::Number,
+                    ExchangePropertyType::Date => ParsedPropertyType::Date,
+                    ExchangePropertyType::Checkbox => ParsedPropertyType::Checkbox,
+                    ExchangePropertyType::Select { options } => {
+                        ParsedPropertyType::Select { options }
+                    }
+                    ExchangePropertyType::Formula { expression } => {
+                        ParsedPropertyType::Formula { expression }
+                    }
+                },
+                rules: prop.rules,
+            })
+            .collect();
+
+        let views = board
+            .views
+            .into_iter()
+            .map(|view| ParsedView {
+                name: view.name,
+                layout: view.layout,
+                group: view.group,
+                filter: view.filter,
+                sort_by: view.sort_by,
+                sort_type: view.sort_type,
+                column_sorts: view
+                    .column_sorts
+                    .into_iter()
+                    .map(|sort| ColumnSort {
+                        column: sort.column,
+                        order: sort.order,
+                    })
+                    .collect(),
+                display: view.display,
+            })
+            .collect();
+
+        let cards = board
+            .cards
+            .into_iter()
+            .map(|card| ParsedCard {
+                id: card.id,
+                title: card.title,
+                description: card.description,
+                properties: card
+                    .properties
+                    .into_iter()
+                    .map(|value| ParsedPropertyValue {
+                        property_name: value.name,
+                        value: value.value.into_raw(),
+                    })
+                    .collect(),
+                subtasks: card.subtasks,
+            })
+            .collect();
+
+        board.metadata.parser_config(&ParserConfig::default())?;
+
+        Ok(ParsedDocument {
+            metadata: board.metadata,
+            properties,
+            cards,
+            views,
+        })
+    }
+}
+
+impl ParsedDocument {
+    /// Serialize the document as pretty-printed JSON using [`BoardExchange`].
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(&BoardExchange::from(self)).map_err(|e| anyhow!("{e}"))
+    }
+
+    pub fn from_json(s: &str) -> Result<Self> {
+        let board: BoardExchange = serde_json::from_str(s).map_err(|e| anyhow!("{e}"))?;
+        ParsedDocument::try_from(board)
+    }
+
+    /// Serialize the document as YAML using [`BoardExchange`].
+    pub fn to_yaml(&self) -> Result<String> {
+        serde_norway::to_string(&BoardExchange::from(self)).map_err(|e| anyhow!("{e}"))
+    }
+
+    pub fn from_yaml(s: &str) -> Result<Self> {
+        let board: BoardExchange = serde_norway::from_str(s).map_err(|e| anyhow!("{e}"))?;
+        ParsedDocument::try_from(board)
+    }
+}
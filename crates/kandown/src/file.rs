@@ -0,0 +1,53 @@
+// crates/kandown/src/file.rs
+
+use anyhow::{Context, Result, anyhow};
+use std::{fs, io::Write, path::Path};
+
+use crate::{Diagnostic, Kanban, ParsedDocument, ParserConfig, locate_diagnostics};
+
+impl Kanban {
+    // Read a board from a markdown file. Warnings come back with their source
+    // lines filled in.
+    pub fn read_file(path: &Path) -> Result<(Kanban, Vec<Diagnostic>)> {
+        let markdown = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let doc = ParsedDocument::try_from(markdown.as_str())
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+
+        let (board, mut diagnostics) = Kanban::from_document_with_diagnostics(doc)
+            .with_context(|| format!("Invalid board {}", path.display()))?;
+        locate_diagnostics(&markdown, &mut diagnostics);
+
+        Ok((board, diagnostics))
+    }
+
+    // Write the board back to a markdown file atomically
+    pub fn write_file(&self, path: &Path) -> Result<()> {
+        let markdown = self
+            .to_parsed_document()
+            .to_markdown_with(&ParserConfig::default())?;
+        write_atomic(path, &markdown)
+    }
+}
+
+// Write to a temporary file next to the target and rename it into place, so
+// an interrupted write never leaves a truncated board behind
+pub fn write_atomic(path: &Path, contents: &str) -> Result<()> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow!("Not a file: {}", path.display()))?;
+    let tmp_path = path.with_file_name(format!(".{}.tmp", file_name.to_string_lossy()));
+
+    let result = (|| -> Result<()> {
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+    result.with_context(|| format!("Failed to write {}", path.display()))
+}
@@ -0,0 +1,144 @@
+// crates/kandown/src/filter.rs
+
+use anyhow::{Result, bail};
+
+/// Comparison operator used in a view filter condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOp {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterCondition {
+    pub property: String,
+    pub op: FilterOp,
+    pub value: String,
+}
+
+/// A parsed view `Filter:` line.
+///
+/// Filters are a list of `Property <op> Value` conditions joined by `and`,
+/// e.g. `Status != Done and Points >= 3`. Values may be wrapped in double
+/// quotes; the pseudo-property `Title` refers to the card title.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewFilter {
+    pub conditions: Vec<FilterCondition>,
+}
+
+// Longer operators first so `!=` is not mistaken for `=`
+const OPERATORS: [(&str, FilterOp); 6] = [
+    ("!=", FilterOp::NotEq),
+    ("<=", FilterOp::LtEq),
+    (">=", FilterOp::GtEq),
+    ("=", FilterOp::Eq),
+    ("<", FilterOp::Lt),
+    (">", FilterOp::Gt),
+];
+
+impl ViewFilter {
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut conditions = Vec::new();
+
+        for part in split_and(s) {
+            let part = part.trim();
+            if part.is_empty() {
+                continue;
+            }
+
+            let Some((idx, op_str, op)) = OPERATORS
+                .iter()
+                .filter_map(|(op_str, op)| part.find(op_str).map(|idx| (idx, *op_str, *op)))
+                .min_by_key(|(idx, op_str, _)| (*idx, usize::MAX - op_str.len()))
+            else {
+                bail!("Invalid filter condition (missing operator): {part}");
+            };
+
+            let property = part[..idx].trim();
+            let value = unquote(part[idx + op_str.len()..].trim());
+
+            if property.is_empty() {
+                bail!("Invalid filter condition (missing property): {part}");
+            }
+
+            conditions.push(FilterCondition {
+                property: property.to_string(),
+                op,
+                value: value.to_string(),
+            });
+        }
+
+        Ok(ViewFilter { conditions })
+    }
+
+    /// Check a card against the filter. `lookup` returns the card's value for a
+    /// property name, or `None` when the card does not set it.
+    pub fn matches<V: AsRef<str>>(&self, lookup: impl Fn(&str) -> Option<V>) -> bool {
+        self.conditions.iter().all(|condition| {
+            let actual = lookup(&condition.property);
+            let actual = actual.as_ref().map_or("", AsRef::as_ref);
+            condition.op.compare(actual, &condition.value)
+        })
+    }
+}
+
+impl FilterOp {
+    fn compare(self, actual: &str, expected: &str) -> bool {
+        let ordering = match (actual.parse::<f64>(), expected.parse::<f64>()) {
+            (Ok(a), Ok(b)) => a.partial_cmp(&b),
+            _ => Some(actual.cmp(expected)),
+        };
+
+        let Some(ordering) = ordering else {
+            return false;
+        };
+
+        match self {
+            FilterOp::Eq => ordering.is_eq(),
+            FilterOp::NotEq => ordering.is_ne(),
+            FilterOp::Lt => ordering.is_lt(),
+            FilterOp::LtEq => ordering.is_le(),
+            FilterOp::Gt => ordering.is_gt(),
+            FilterOp::GtEq => ordering.is_ge(),
+        }
+    }
+}
+
+// Split on the `and` keyword, ignoring occurrences inside quoted values
+fn split_and(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+
+    let mut idx = 0;
+    while idx < s.len() {
+        let is_and = !in_quotes
+            && s.get(idx..idx + 5)
+                .is_some_and(|word| word.eq_ignore_ascii_case(" and "));
+
+        if is_and {
+            parts.push(&s[start..idx]);
+            idx += 5;
+            start = idx;
+            continue;
+        }
+
+        if s.as_bytes()[idx] == b'"' {
+            in_quotes = !in_quotes;
+        }
+        idx += 1;
+    }
+
+    parts.push(&s[start..]);
+    parts
+}
+
+fn unquote(s: &str) -> &str {
+    s.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(s)
+}
@@ -0,0 +1,752 @@
+// crates/kandown/src/formula.rs
+
+use anyhow::{Result, bail};
+use std::{borrow::Cow, cmp::Ordering, fmt};
+
+use crate::{
+    COMPUTED_PROPERTIES, CardLinks, Kanban, KanbanCard, KanbanPropertyType, normalize_date,
+};
+
+/// A parsed `Formula(...)` property expression.
+///
+/// Expressions read other properties of the same card by name, e.g.
+/// `today() - Created` or `Due < today() and Status != Done`. Names may span
+/// several words (`Points total`); a name that is not a property is taken as
+/// text, as in view filters. Supported are `+ - * /`, comparisons
+/// (`= != < <= > >=`), `and`, `or`, `not`, `"quoted"` text and the functions
+/// in `FUNCTIONS`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Formula {
+    source: String,
+    expr: Expr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Field(String), // A property, the title or a computed value
+    Call(String, Vec<Expr>),
+    Not(Box<Expr>),
+    Neg(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BinaryOp {
+    Or,
+    And,
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+// Function names with their minimum and maximum number of arguments
+const FUNCTIONS: [(&str, usize, usize); 10] = [
+    ("today", 0, 0),
+    ("if", 3, 3),
+    ("empty", 1, 1),
+    ("round", 1, 2),
+    ("abs", 1, 1),
+    ("min", 1, usize::MAX),
+    ("max", 1, usize::MAX),
+    ("sum_children", 1, 1),   // Sum of an expression over the card's children
+    ("count_children", 0, 0), // Number of children
+    ("done_children", 0, 0),  // Number of children that are done
+];
+
+const KEYWORDS: [&str; 5] = ["and", "or", "not", "true", "false"];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Text(String),
+    Word(String),
+    Op(&'static str),
+    Open,
+    Close,
+    Comma,
+}
+
+// Longer operators first so `<=` is not read as `<`
+const OPERATORS: [&str; 11] = ["!=", "<=", ">=", "==", "=", "<", ">", "+", "-", "*", "/"];
+
+fn tokenize(s: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut rest = s.trim_start();
+
+    while let Some(c) = rest.chars().next() {
+        let len = if c == '(' || c == ')' || c == ',' {
+            tokens.push(match c {
+                '(' => Token::Open,
+                ')' => Token::Close,
+                _ => Token::Comma,
+            });
+            1
+        } else if c == '"' {
+            let Some(end) = rest[1..].find('"') else {
+                bail!("Unterminated text in formula: {rest}");
+            };
+            tokens.push(Token::Text(rest[1..=end].to_string()));
+            end + 2
+        } else if c.is_ascii_digit() {
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(rest.len());
+            let number = rest[..end]
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid number in formula: {}", &rest[..end]))?;
+            tokens.push(Token::Number(number));
+            end
+        } else if c.is_alphabetic() || c == '_' {
+            let end = rest
+                .find(|c: char| !c.is_alphanumeric() && c != '_')
+                .unwrap_or(rest.len());
+            tokens.push(Token::Word(rest[..end].to_string()));
+            end
+        } else if let Some(op) = OPERATORS.iter().find(|op| rest.starts_with(**op)) {
+            tokens.push(Token::Op(op));
+            op.len()
+        } else {
+            bail!("Unexpected '{c}' in formula");
+        };
+
+        rest = rest[len..].trim_start();
+    }
+
+    Ok(tokens)
+}
+
+// Recursive descent over the tokens, lowest precedence first:
+// or, and, not, comparison, + -, * /, unary minus, operands
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat_word(&mut self, word: &str) -> bool {
+        let found = matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(word));
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn eat_op(&mut self, ops: &[&'static str]) -> Option<&'static str> {
+        match self.peek() {
+            Some(Token::Op(op)) if ops.contains(op) => {
+                let op = *op;
+                self.pos += 1;
+                Some(op)
+            }
+            _ => None,
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr> {
+        let mut expr = self.and()?;
+        while self.eat_word("or") {
+            expr = Expr::Binary(BinaryOp::Or, Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr> {
+        let mut expr = self.not()?;
+        while self.eat_word("and") {
+            expr = Expr::Binary(BinaryOp::And, Box::new(expr), Box::new(self.not()?));
+        }
+        Ok(expr)
+    }
+
+    fn not(&mut self) -> Result<Expr> {
+        if self.eat_word("not") {
+            return Ok(Expr::Not(Box::new(self.not()?)));
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Result<Expr> {
+        let expr = self.sum()?;
+        let Some(op) = self.eat_op(&["!=", "<=", ">=", "==", "=", "<", ">"]) else {
+            return Ok(expr);
+        };
+
+        let op = match op {
+            "!=" => BinaryOp::NotEq,
+            "<=" => BinaryOp::LtEq,
+            ">=" => BinaryOp::GtEq,
+            "<" => BinaryOp::Lt,
+            ">" => BinaryOp::Gt,
+            _ => BinaryOp::Eq,
+        };
+        Ok(Expr::Binary(op, Box::new(expr), Box::new(self.sum()?)))
+    }
+
+    fn sum(&mut self) -> Result<Expr> {
+        let mut expr = self.product()?;
+        while let Some(op) = self.eat_op(&["+", "-"]) {
+            let op = if op == "+" {
+                BinaryOp::Add
+            } else {
+                BinaryOp::Sub
+            };
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.product()?));
+        }
+        Ok(expr)
+    }
+
+    fn product(&mut self) -> Result<Expr> {
+        let mut expr = self.unary()?;
+        while let Some(op) = self.eat_op(&["*", "/"]) {
+            let op = if op == "*" {
+                BinaryOp::Mul
+            } else {
+                BinaryOp::Div
+            };
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr> {
+        if self.eat_op(&["-"]).is_some() {
+            return Ok(Expr::Neg(Box::new(self.unary()?)));
+        }
+        self.operand()
+    }
+
+    fn operand(&mut self) -> Result<Expr> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Expr::Number(n)),
+            Some(Token::Text(s)) => Ok(Expr::Text(s)),
+            Some(Token::Open) => {
+                let expr = self.or()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(expr),
+                    _ => bail!("Missing ')' in formula"),
+                }
+            }
+            Some(Token::Word(word)) if KEYWORDS.contains(&word.to_lowercase().as_str()) => {
+                match word.to_lowercase().as_str() {
+                    "true" => Ok(Expr::Bool(true)),
+                    "false" => Ok(Expr::Bool(false)),
+                    _ => bail!("Unexpected '{word}' in formula"),
+                }
+            }
+            Some(Token::Word(word)) if self.peek() == Some(&Token::Open) => {
+                self.pos += 1;
+                self.call(word)
+            }
+            Some(Token::Word(word)) => {
+                // Names run over several words until an operator or keyword
+                let mut name = word;
+                while let Some(Token::Word(next)) = self.peek()
+                    && !KEYWORDS.contains(&next.to_lowercase().as_str())
+                {
+                    name = format!("{name} {next}");
+                    self.pos += 1;
+                }
+                Ok(Expr::Field(name))
+            }
+            Some(token) => bail!("Unexpected {} in formula", describe(&token)),
+            None => bail!("Formula ends unexpectedly"),
+        }
+    }
+
+    // Arguments of a function call, after the opening parenthesis
+    fn call(&mut self, name: String) -> Result<Expr> {
+        let mut args = Vec::new();
+        if self.peek() == Some(&Token::Close) {
+            self.pos += 1;
+        } else {
+            loop {
+                args.push(self.or()?);
+                match self.next() {
+                    Some(Token::Comma) => continue,
+                    Some(Token::Close) => break,
+                    _ => bail!("Missing ')' after the arguments of {name}()"),
+                }
+            }
+        }
+
+        let Some((_, min, max)) = FUNCTIONS.iter().find(|(f, _, _)| *f == name) else {
+            bail!("Unknown function in formula: {name}()");
+        };
+        if args.len() < *min || args.len() > *max {
+            bail!("Wrong number of arguments for {name}()");
+        }
+
+        Ok(Expr::Call(name, args))
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Number(n) => format!("'{}'", format_number(*n)),
+        Token::Text(s) => format!("'\"{s}\"'"),
+        Token::Word(word) => format!("'{word}'"),
+        Token::Op(op) => format!("'{op}'"),
+        Token::Open => "'('".to_string(),
+        Token::Close => "')'".to_string(),
+        Token::Comma => "','".to_string(),
+    }
+}
+
+impl Formula {
+    pub fn parse(source: &str) -> Result<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(source)?,
+            pos: 0,
+        };
+        if parser.tokens.is_empty() {
+            bail!("Formula is empty");
+        }
+
+        let expr = parser.or()?;
+        if let Some(token) = parser.peek() {
+            bail!("Unexpected {} in formula", describe(token));
+        }
+
+        Ok(Formula {
+            source: source.trim().to_string(),
+            expr,
+        })
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+impl fmt::Display for Formula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+// The result of evaluating an expression. Dates are days since 1970-01-01.
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Empty,
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Date(i64),
+}
+
+impl Value {
+    fn is_true(&self) -> bool {
+        match self {
+            Value::Empty => false,
+            Value::Number(n) => *n != 0.0,
+            Value::Text(s) => !s.is_empty(),
+            Value::Bool(b) => *b,
+            Value::Date(_) => true,
+        }
+    }
+
+    // Written the way card values are, or None for no value
+    fn to_text(&self) -> Option<String> {
+        match self {
+            Value::Empty => None,
+            Value::Number(n) => Some(format_number(*n)),
+            Value::Text(s) => Some(s.clone()),
+            Value::Bool(b) => Some(b.to_string()),
+            Value::Date(days) => Some(date_from_days(*days)),
+        }
+    }
+
+    fn compare(&self, other: &Value) -> Option<Ordering> {
+        let as_date = |s: &str| normalize_date(s).and_then(|d| days_from_date(&d));
+        match (self, other) {
+            (Value::Empty, _) | (_, Value::Empty) => None,
+            (Value::Number(a), Value::Number(b)) => a.partial_cmp(b),
+            (Value::Date(a), Value::Date(b)) => Some(a.cmp(b)),
+            (Value::Date(a), Value::Text(b)) => as_date(b).map(|b| a.cmp(&b)),
+            (Value::Text(a), Value::Date(b)) => as_date(a).map(|a| a.cmp(b)),
+            (Value::Number(a), Value::Text(b)) => a.partial_cmp(&b.parse().ok()?),
+            (Value::Text(a), Value::Number(b)) => a.parse::<f64>().ok()?.partial_cmp(b),
+            (a, b) => Some(a.to_text().cmp(&b.to_text())),
+        }
+    }
+}
+
+fn format_number(n: f64) -> String {
+    if n.fract() == 0.0 && n.abs() < 1e15 {
+        return format!("{}", n as i64);
+    }
+
+    let rounded = format!("{n:.2}");
+    rounded
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+// Days since 1970-01-01 for a `YYYY-MM-DD` date
+fn days_from_date(date: &str) -> Option<i64> {
+    let mut parts = date.splitn(3, '-').map(|p| p.parse::<i64>().ok());
+    let (y, m, d) = (parts.next()??, parts.next()??, parts.next()??);
+
+    // Days from civil, counting years from March so leap days come last
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    Some(era * 146097 + doe - 719468)
+}
+
+// Days since 1970-01-01 of the first and last dates with four-digit years
+const MIN_DAYS: i64 = -719528;
+const MAX_DAYS: i64 = 2932896;
+
+// A date moved by a number of days, or empty when the result can't be
+// written as a date. `n` is checked before converting, as `as` saturates.
+fn shift_date(days: i64, n: f64) -> Value {
+    if n.is_nan() || n.abs() > (MAX_DAYS - MIN_DAYS) as f64 {
+        return Value::Empty;
+    }
+
+    days.checked_add(n as i64)
+        .filter(|days| (MIN_DAYS..=MAX_DAYS).contains(days))
+        .map_or(Value::Empty, Value::Date)
+}
+
+fn date_from_days(days: i64) -> String {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    format!("{y:04}-{m:02}-{d:02}")
+}
+
+// Today's date in UTC, where the platform has a clock
+pub(crate) fn system_today() -> Option<String> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()?;
+        Some(date_from_days((now.as_secs() / 86400) as i64))
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        None
+    }
+}
+
+// Formulas being evaluated, as card ids with property names, so formulas
+// that read each other give no value instead of looping. Card links are
+// worked out the first time a formula needs them, unless they were given.
+#[derive(Default)]
+struct EvalStack<'a> {
+    active: Vec<(usize, String)>,
+    given_links: Option<&'a CardLinks>,
+    links: Option<CardLinks>,
+}
+
+impl EvalStack<'_> {
+    fn links(&mut self, board: &Kanban) -> &CardLinks {
+        match self.given_links {
+            Some(links) => links,
+            None => self.links.get_or_insert_with(|| board.card_links()),
+        }
+    }
+}
+
+impl Kanban {
+    // Evaluate a formula property for a card, or None if the property is not a
+    // formula or the formula has no value for this card
+    pub fn formula_value(&self, card: &KanbanCard, name: &str) -> Option<String> {
+        self.evaluate_formula(card, name, EvalStack::default())
+    }
+
+    // Evaluate a formula property with the board's links worked out once
+    // beforehand, for evaluating formulas of many cards
+    pub fn formula_value_with(
+        &self,
+        links: &CardLinks,
+        card: &KanbanCard,
+        name: &str,
+    ) -> Option<String> {
+        let stack = EvalStack {
+            given_links: Some(links),
+            ..Default::default()
+        };
+        self.evaluate_formula(card, name, stack)
+    }
+
+    fn evaluate_formula(
+        &self,
+        card: &KanbanCard,
+        name: &str,
+        mut stack: EvalStack<'_>,
+    ) -> Option<String> {
+        let prop_rc = self.property_by_name.get(name)?;
+        if !matches!(
+            prop_rc.borrow().property_type,
+            KanbanPropertyType::Formula { .. }
+        ) {
+            return None;
+        }
+
+        self.field(card, name, &mut stack).to_text()
+    }
+
+    // Get a property value, the title, a computed value or a formula result,
+    // as used by view filters, sorting and grouping
+    pub fn field_value<'a>(&self, card: &'a KanbanCard, name: &str) -> Option<Cow<'a, str>> {
+        match self.formula_value(card, name) {
+            Some(value) => Some(Cow::Owned(value)),
+            None if self.is_formula(name) => None,
+            None => card.field_value(name),
+        }
+    }
+
+    pub fn is_formula(&self, name: &str) -> bool {
+        self.property_by_name.get(name).is_some_and(|prop_rc| {
+            matches!(
+                prop_rc.borrow().property_type,
+                KanbanPropertyType::Formula { .. }
+            )
+        })
+    }
+
+    fn field(&self, card: &KanbanCard, name: &str, stack: &mut EvalStack<'_>) -> Value {
+        let Some(prop_rc) = self.property_by_name.get(name) else {
+            if name == "Title" {
+                return Value::Text(card.title.clone());
+            }
+            if COMPUTED_PROPERTIES.contains(&name) {
+                return match card.computed_value(name) {
+                    Some(value) => match value.parse() {
+                        Ok(n) => Value::Number(n),
+                        Err(_) => Value::Text(value),
+                    },
+                    None => Value::Empty,
+                };
+            }
+            // Bare words that name nothing are text, e.g. `Status = Done`
+            return Value::Text(name.to_string());
+        };
+
+        let prop = prop_rc.borrow();
+        if let KanbanPropertyType::Formula { formula } = &prop.property_type {
+            let key = (card.id, name.to_string());
+            if stack.active.contains(&key) {
+                return Value::Empty;
+            }
+
+            stack.active.push(key);
+            let value = self.eval(&formula.expr, card, stack);
+            stack.active.pop();
+            return value;
+        }
+
+        let Some(raw) = card.get_value(name).filter(|v| !v.is_empty()) else {
+            return Value::Empty;
+        };
+        match prop.property_type {
+            KanbanPropertyType::Number => raw
+                .parse()
+                .map_or_else(|_| Value::Text(raw.to_string()), Value::Number),
+            KanbanPropertyType::Date => normalize_date(raw)
+                .and_then(|date| days_from_date(&date))
+                .map_or_else(|| Value::Text(raw.to_string()), Value::Date),
+            KanbanPropertyType::Checkbox => Value::Bool(raw == "true"),
+            _ => Value::Text(raw.to_string()),
+        }
+    }
+
+    fn eval(&self, expr: &Expr, card: &KanbanCard, stack: &mut EvalStack<'_>) -> Value {
+        match expr {
+            Expr::Number(n) => Value::Number(*n),
+            Expr::Text(s) => Value::Text(s.clone()),
+            Expr::Bool(b) => Value::Bool(*b),
+            Expr::Field(name) => self.field(card, name, stack),
+            Expr::Not(expr) => Value::Bool(!self.eval(expr, card, stack).is_true()),
+            Expr::Neg(expr) => match self.eval(expr, card, stack) {
+                Value::Number(n) => Value::Number(-n),
+                _ => Value::Empty,
+            },
+            Expr::Binary(op, a, b) => {
+                let a = self.eval(a, card, stack);
+                let b = self.eval(b, card, stack);
+                binary(*op, a, b)
+            }
+            Expr::Call(name, args) => self.call(name, args, card, stack),
+        }
+    }
+
+    fn call(
+        &self,
+        name: &str,
+        args: &[Expr],
+        card: &KanbanCard,
+        stack: &mut EvalStack<'_>,
+    ) -> Value {
+        let number =
+            |idx: usize, stack: &mut EvalStack<'_>| match self.eval(&args[idx], card, stack) {
+                Value::Number(n) => Some(n),
+                Value::Text(s) => s.parse().ok(),
+                _ => None,
+            };
+
+        match name {
+            "today" => self
+                .today
+                .as_deref()
+                .and_then(days_from_date)
+                .map_or(Value::Empty, Value::Date),
+            "if" => {
+                let branch = if self.eval(&args[0], card, stack).is_true() {
+                    1
+                } else {
+                    2
+                };
+                self.eval(&args[branch], card, stack)
+            }
+            "empty" => Value::Bool(self.eval(&args[0], card, stack) == Value::Empty),
+            "round" => {
+                let digits = if args.len() > 1 {
+                    number(1, stack).unwrap_or(0.0)
+                } else {
+                    0.0
+                };
+                let scale = 10f64.powi(digits as i32);
+                number(0, stack)
+                    .map_or(Value::Empty, |n| Value::Number((n * scale).round() / scale))
+            }
+            "abs" => number(0, stack).map_or(Value::Empty, |n| Value::Number(n.abs())),
+            "min" | "max" => {
+                let values = (0..args.len()).filter_map(|idx| number(idx, stack));
+                let result = if name == "min" {
+                    values.reduce(f64::min)
+                } else {
+                    values.reduce(f64::max)
+                };
+                result.map_or(Value::Empty, Value::Number)
+            }
+            "sum_children" | "count_children" | "done_children" => {
+                // Parent loops would otherwise roll up forever
+                let key = (card.id, "children".to_string());
+                let Some(card_rc) = self.card_by_id.get(&card.id) else {
+                    return Value::Empty;
+                };
+                if stack.active.contains(&key) {
+                    return Value::Empty;
+                }
+                let links = stack.links(self);
+                let children = links.children(card_rc);
+                let done = children.iter().filter(|c| links.is_done(c)).count();
+                stack.active.push(key);
+
+                let total = match name {
+                    "count_children" => children.len() as f64,
+                    "done_children" => done as f64,
+                    _ => children
+                        .iter()
+                        .filter_map(|child| match self.eval(&args[0], &child.borrow(), stack) {
+                            Value::Number(n) => Some(n),
+                            Value::Text(s) => s.parse().ok(),
+                            _ => None,
+                        })
+                        .sum(),
+                };
+                stack.active.pop();
+                Value::Number(total)
+            }
+            _ => Value::Empty,
+        }
+    }
+}
+
+fn binary(op: BinaryOp, a: Value, b: Value) -> Value {
+    // Missing numbers count as zero, so sums skip cards without a value
+    let arithmetic = matches!(
+        op,
+        BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div
+    );
+    let (a, b) = match (a, b) {
+        (Value::Empty, b @ Value::Number(_)) if arithmetic => (Value::Number(0.0), b),
+        (a @ Value::Number(_), Value::Empty) if arithmetic => (a, Value::Number(0.0)),
+        pair => pair,
+    };
+
+    match op {
+        BinaryOp::Eq | BinaryOp::NotEq => {
+            let equal = match (&a, &b) {
+                (Value::Empty, other) | (other, Value::Empty) => {
+                    other == &Value::Empty || other == &Value::Text(String::new())
+                }
+                _ => a.compare(&b) == Some(Ordering::Equal),
+            };
+            Value::Bool(equal == (op == BinaryOp::Eq))
+        }
+        BinaryOp::Lt | BinaryOp::LtEq | BinaryOp::Gt | BinaryOp::GtEq => {
+            let Some(ordering) = a.compare(&b) else {
+                return Value::Bool(false);
+            };
+            Value::Bool(match op {
+                BinaryOp::Lt => ordering.is_lt(),
+                BinaryOp::LtEq => ordering.is_le(),
+                BinaryOp::Gt => ordering.is_gt(),
+                _ => ordering.is_ge(),
+            })
+        }
+        BinaryOp::Add => match (a, b) {
+            (Value::Number(a), Value::Number(b)) => Value::Number(a + b),
+            (Value::Date(d), Value::Number(n)) | (Value::Number(n), Value::Date(d)) => {
+                shift_date(d, n)
+            }
+            (a @ Value::Text(_), b) | (a, b @ Value::Text(_)) => Value::Text(format!(
+                "{}{}",
+                a.to_text().unwrap_or_default(),
+                b.to_text().unwrap_or_default()
+            )),
+            _ => Value::Empty,
+        },
+        BinaryOp::Sub => match (a, b) {
+            (Value::Number(a), Value::Number(b)) => Value::Number(a - b),
+            (Value::Date(a), Value::Date(b)) => Value::Number((a - b) as f64),
+            (Value::Date(d), Value::Number(n)) => shift_date(d, -n),
+            _ => Value::Empty,
+        },
+        BinaryOp::Mul => match (a, b) {
+            (Value::Number(a), Value::Number(b)) => Value::Number(a * b),
+            _ => Value::Empty,
+        },
+        BinaryOp::Div => match (a, b) {
+            (Value::Number(a), Value::Number(b)) if b != 0.0 => Value::Number(a / b),
+            _ => Value::Empty,
+        },
+        BinaryOp::And => Value::Bool(a.is_true() && b.is_true()),
+        BinaryOp::Or => Value::Bool(a.is_true() || b.is_true()),
+    }
+}
@@ -0,0 +1,112 @@
+// crates/kandown/src/gfm.rs
+
+use anyhow::{Result, anyhow};
+use std::fmt::Write;
+
+use crate::{Kanban, KanbanPropertyType, KanbanViewType};
+
+impl Kanban {
+    // Render a view as GitHub-flavored markdown: boards become one checklist
+    // per column, every other layout a table. Honors the view's display
+    // properties, filter and sort. Cards in a board's last Select column are
+    // ticked.
+    pub fn render_view_gfm(&self, view_name: &str) -> Result<String> {
+        let view_rc = self
+            .view_by_name
+            .get(view_name)
+            .ok_or_else(|| anyhow!("View not found: {view_name}"))?;
+
+        let (group_by, shown) = {
+            let view = view_rc.borrow();
+            let group_by = match &view.view_layout {
+                KanbanViewType::Board { group_by } => Some(group_by.clone()),
+                _ => None,
+            };
+            (group_by, self.shown_properties(&view, true))
+        };
+
+        let mut output = String::new();
+
+        match group_by {
+            Some(group_by) => {
+                let done_column =
+                    group_by
+                        .as_ref()
+                        .and_then(|prop_rc| match &prop_rc.borrow().property_type {
+                            KanbanPropertyType::Select { options } => options.last().cloned(),
+                            _ => None,
+                        });
+
+                let columns = if group_by.is_some() {
+                    self.get_ordered_groups(view_name)?
+                } else {
+                    vec![(String::new(), self.get_view_cards(view_name)?)]
+                };
+
+                for (column, cards) in columns {
+                    let title = if column.is_empty() {
+                        "No value"
+                    } else {
+                        column.as_str()
+                    };
+                    let _ = writeln!(output, "## {title}\n");
+
+                    let mark = if done_column.as_ref() == Some(&column) {
+                        'x'
+                    } else {
+                        ' '
+                    };
+                    for card_rc in cards {
+                        let card = card_rc.borrow();
+                        let values: Vec<String> = shown
+                            .iter()
+                            .filter_map(|prop_rc| {
+                                self.card_value(&card, prop_rc)
+                                    .map(|value| format!("{}: {value}", prop_rc.borrow().name))
+                            })
+                            .collect();
+
+                        let _ = write!(output, "- [{mark}] {}", single_line(&card.title));
+                        if !values.is_empty() {
+                            let _ = write!(output, " ({})", single_line(&values.join(", ")));
+                        }
+                        output.push('\n');
+                    }
+
+                    output.push('\n');
+                }
+            }
+            None => {
+                output.push_str("| Title |");
+                for prop_rc in &shown {
+                    let _ = write!(output, " {} |", table_cell(&prop_rc.borrow().name));
+                }
+                output.push_str("\n| --- |");
+                output.push_str(&" --- |".repeat(shown.len()));
+                output.push('\n');
+
+                for card_rc in self.get_view_cards(view_name)? {
+                    let card = card_rc.borrow();
+                    let _ = write!(output, "| {} |", table_cell(&card.title));
+                    for prop_rc in &shown {
+                        let value = self.card_value(&card, prop_rc).unwrap_or_default();
+                        let _ = write!(output, " {} |", table_cell(&value));
+                    }
+                    output.push('\n');
+                }
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+// Collapse line breaks and runs of whitespace into single spaces
+pub(crate) fn single_line(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// Pipes would end the cell early
+fn table_cell(s: &str) -> String {
+    single_line(s).replace('|', "\\|")
+}
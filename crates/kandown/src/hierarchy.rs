@@ -0,0 +1,296 @@
+// crates/kandown/src/hierarchy.rs
+
+use anyhow::{Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use crate::{
+    CardLinks, Diagnostic, DocumentSection, Kanban, KanbanCard, KanbanPropertyType, ParsedDocument,
+};
+
+// Card property naming the card's parent, e.g. the epic a story belongs to
+pub const PARENT_PROPERTY: &str = "Parent";
+
+/// How the children of a card are spread over the board's statuses
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChildRollup {
+    pub total: usize,
+    pub done: usize,
+    pub by_status: Vec<(String, usize)>, // In column order, "" for no status
+}
+
+// Each card's parent as a position in the card list, and parents that name
+// no card. `cards` are titles with each card's parent value.
+fn parent_links(cards: &[(&str, Option<&str>)]) -> (Vec<Option<usize>>, Vec<(usize, String)>) {
+    // Later cards win on duplicate titles, as with `card_by_title`
+    let index: HashMap<&str, usize> = cards
+        .iter()
+        .enumerate()
+        .map(|(idx, (title, _))| (*title, idx))
+        .collect();
+
+    let mut unknown = Vec::new();
+    let parents = cards
+        .iter()
+        .enumerate()
+        .map(|(idx, (_, parent))| {
+            let parent = parent.map(str::trim).filter(|p| !p.is_empty())?;
+            let found = index.get(parent).copied();
+            if found.is_none() {
+                unknown.push((idx, parent.to_string()));
+            }
+            found
+        })
+        .collect();
+
+    (parents, unknown)
+}
+
+// Loops in the parent links, each as positions starting from the first card
+// of the loop in board order
+fn parent_cycles(parents: &[Option<usize>]) -> Vec<Vec<usize>> {
+    let mut state = vec![0u8; parents.len()]; // 0 unvisited, 1 on this path, 2 done
+    let mut cycles = Vec::new();
+
+    for start in 0..parents.len() {
+        let mut path = Vec::new();
+        let mut current = Some(start);
+
+        while let Some(card) = current {
+            match state[card] {
+                0 => {
+                    state[card] = 1;
+                    path.push(card);
+                    current = parents[card];
+                }
+                1 => {
+                    let from = path.iter().position(|&c| c == card).unwrap_or_default();
+                    let mut cycle = path[from..].to_vec();
+                    let first = cycle.iter().enumerate().min_by_key(|(_, c)| **c);
+                    let first = first.map(|(idx, _)| idx).unwrap_or_default();
+                    cycle.rotate_left(first);
+                    cycles.push(cycle);
+                    break;
+                }
+                _ => break,
+            }
+        }
+
+        for card in path {
+            state[card] = 2;
+        }
+    }
+
+    cycles.sort();
+    cycles
+}
+
+fn cycle_message(titles: &[&str]) -> String {
+    match titles {
+        [only] => format!("Card '{only}' is its own parent"),
+        _ => {
+            let chain: Vec<String> = titles
+                .iter()
+                .chain(titles.first())
+                .map(|title| format!("'{title}'"))
+                .collect();
+            format!("Cards form a parent loop: {}", chain.join(" -> "))
+        }
+    }
+}
+
+impl Kanban {
+    pub(crate) fn parent_links(&self) -> (Vec<Option<usize>>, Vec<(usize, String)>) {
+        let cards: Vec<_> = self.cards.iter().map(|card_rc| card_rc.borrow()).collect();
+        let links: Vec<(&str, Option<&str>)> = cards
+            .iter()
+            .map(|card| (card.title.as_str(), card.get_value(PARENT_PROPERTY)))
+            .collect();
+
+        parent_links(&links)
+    }
+
+    pub fn parent(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Option<Rc<RefCell<KanbanCard>>> {
+        self.card_links().parent(card_rc)
+    }
+
+    // Direct children of a card in board order
+    pub fn children(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Vec<Rc<RefCell<KanbanCard>>> {
+        self.card_links().children(card_rc)
+    }
+
+    // Parent, grandparent and so on, stopping before any card repeats
+    pub fn ancestors(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Vec<Rc<RefCell<KanbanCard>>> {
+        let Some(mut idx) = self.position(card_rc) else {
+            return Vec::new();
+        };
+        let (parents, _) = self.parent_links();
+
+        let mut seen = HashSet::from([idx]);
+        let mut ancestors = Vec::new();
+        while let Some(parent) = parents[idx].filter(|parent| seen.insert(*parent)) {
+            ancestors.push(Rc::clone(&self.cards[parent]));
+            idx = parent;
+        }
+        ancestors
+    }
+
+    // Cards without a parent on the board
+    pub fn top_level_cards(&self) -> Vec<Rc<RefCell<KanbanCard>>> {
+        let (parents, _) = self.parent_links();
+
+        self.cards
+            .iter()
+            .zip(parents)
+            .filter(|(_, parent)| parent.is_none())
+            .map(|(card_rc, _)| Rc::clone(card_rc))
+            .collect()
+    }
+
+    // Count a card's children by status, or None if it has no children
+    pub fn child_rollup(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Option<ChildRollup> {
+        self.card_links().child_rollup(card_rc)
+    }
+
+    // Check that `parent` can become the parent of the card titled `title`:
+    // it must exist and must not be the card itself or one of its children,
+    // grandchildren and so on
+    pub(crate) fn check_parent(&self, title: &str, parent: &str) -> Result<()> {
+        let mut current = self
+            .card_by_title
+            .get(parent)
+            .cloned()
+            .ok_or_else(|| anyhow!("Parent card not found: {parent}"))?;
+        let mut seen = HashSet::new();
+
+        loop {
+            let next = {
+                let card = current.borrow();
+                if card.title == title {
+                    bail!(
+                        "Card '{title}' cannot have '{parent}' as parent, it would be its own ancestor"
+                    );
+                }
+                if !seen.insert(card.title.clone()) {
+                    return Ok(());
+                }
+                card.get_value(PARENT_PROPERTY)
+                    .and_then(|p| self.card_by_title.get(p.trim()))
+                    .cloned()
+            };
+
+            match next {
+                Some(next) => current = next,
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+impl CardLinks {
+    pub fn parent(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Option<Rc<RefCell<KanbanCard>>> {
+        let idx = self.position(card_rc)?;
+
+        self.parents[idx].map(|parent| Rc::clone(&self.cards[parent]))
+    }
+
+    // Direct children of a card in board order
+    pub fn children(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Vec<Rc<RefCell<KanbanCard>>> {
+        let Some(idx) = self.position(card_rc) else {
+            return Vec::new();
+        };
+
+        self.cards_at(self.children[idx].iter().copied())
+    }
+
+    // Count a card's children by status, or None if it has no children
+    pub fn child_rollup(&self, card_rc: &Rc<RefCell<KanbanCard>>) -> Option<ChildRollup> {
+        let children = self.children(card_rc);
+        if children.is_empty() {
+            return None;
+        }
+
+        let status = self.status.as_ref();
+        let mut columns: Vec<String> = match status.map(|p| p.borrow()) {
+            Some(prop) => match &prop.property_type {
+                KanbanPropertyType::Select { options } => options.clone(),
+                _ => Vec::new(),
+            },
+            None => Vec::new(),
+        };
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for child in &children {
+            let value = status
+                .and_then(|prop| {
+                    child
+                        .borrow()
+                        .get_value(&prop.borrow().name)
+                        .map(str::to_string)
+                })
+                .unwrap_or_default();
+            if !columns.contains(&value) && !value.is_empty() {
+                columns.push(value.clone());
+            }
+            *counts.entry(value).or_default() += 1;
+        }
+        columns.push(String::new());
+
+        Some(ChildRollup {
+            total: children.len(),
+            done: children.iter().filter(|child| self.is_done(child)).count(),
+            by_status: columns
+                .into_iter()
+                .filter_map(|column| counts.remove(&column).map(|count| (column, count)))
+                .collect(),
+        })
+    }
+}
+
+impl ParsedDocument {
+    // Report parents that name no card and loops of parent links
+    pub(crate) fn lint_hierarchy(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let links: Vec<(&str, Option<&str>)> = self
+            .cards
+            .iter()
+            .map(|card| {
+                let parent = card
+                    .properties
+                    .iter()
+                    .find(|p| p.property_name == PARENT_PROPERTY)
+                    .map(|p| p.value.as_str());
+                (card.title.as_str(), parent)
+            })
+            .collect();
+        let (parents, unknown) = parent_links(&links);
+
+        for (idx, missing) in unknown {
+            let title = &self.cards[idx].title;
+            diagnostics.push(
+                Diagnostic::warning(
+                    "unknown-parent",
+                    format!("Card '{title}' has parent '{missing}', but there is no such card"),
+                )
+                .at(DocumentSection::Cards, idx, title),
+            );
+        }
+
+        for cycle in parent_cycles(&parents) {
+            let titles: Vec<&str> = cycle
+                .iter()
+                .map(|&idx| self.cards[idx].title.as_str())
+                .collect();
+            diagnostics.push(
+                Diagnostic::error("parent-cycle", cycle_message(&titles)).at(
+                    DocumentSection::Cards,
+                    cycle[0],
+                    titles[0],
+                ),
+            );
+        }
+    }
+}
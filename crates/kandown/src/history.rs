@@ -0,0 +1,103 @@
+// crates/kandown/src/history.rs
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BoardChange, BoardMetadata, ParsedDocument};
+
+/// One version of a board file, e.g. from a git commit or a snapshot on disk
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Revision {
+    pub id: String, // Commit hash or snapshot name
+    pub author: Option<String>,
+    pub date: Option<String>,
+    pub markdown: String,
+}
+
+/// A change to a board and the revision that made it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Activity {
+    pub revision: String,
+    pub author: Option<String>,
+    pub date: Option<String>,
+    pub change: BoardChange,
+}
+
+impl std::fmt::Display for Activity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.date, &self.author) {
+            (Some(date), Some(author)) => write!(f, "{date} {author}: {}", self.change)?,
+            (None, Some(author)) => write!(f, "{author}: {}", self.change)?,
+            (Some(date), None) => write!(f, "{date}: {}", self.change)?,
+            (None, None) => write!(f, "{}", self.change)?,
+        }
+
+        write!(f, " [{}]", self.revision)
+    }
+}
+
+impl BoardChange {
+    // Titles of the card a change is about: both titles for a rename, none for
+    // changes to properties or views
+    pub fn cards(&self) -> Vec<&str> {
+        match self {
+            BoardChange::CardAdded { card }
+            | BoardChange::CardRemoved { card }
+            | BoardChange::CardMoved { card, .. }
+            | BoardChange::ValueChanged { card, .. }
+            | BoardChange::DescriptionChanged { card }
+            | BoardChange::SubtasksChanged { card, .. } => vec![card],
+            BoardChange::CardRenamed { from, to } => vec![from, to],
+            _ => Vec::new(),
+        }
+    }
+}
+
+// Activity across revisions given oldest first. The first revision counts as
+// adding everything in it. Revisions that fail to parse are skipped, and the
+// next one is compared with the last that parsed.
+pub fn board_history(revisions: &[Revision]) -> Vec<Activity> {
+    let mut activity = Vec::new();
+    let mut previous = ParsedDocument {
+        metadata: BoardMetadata::default(),
+        properties: Vec::new(),
+        cards: Vec::new(),
+        views: Vec::new(),
+    };
+
+    for revision in revisions {
+        let Ok(doc) = ParsedDocument::parse_complete(&revision.markdown) else {
+            continue;
+        };
+
+        activity.extend(previous.diff(&doc).into_iter().map(|change| Activity {
+            revision: revision.id.clone(),
+            author: revision.author.clone(),
+            date: revision.date.clone(),
+            change,
+        }));
+        previous = doc;
+    }
+
+    activity
+}
+
+// The activity of one card, following it back through renames
+pub fn card_history<'a>(activity: &'a [Activity], title: &str) -> Vec<&'a Activity> {
+    let mut titles = vec![title];
+    let mut history = Vec::new();
+
+    for item in activity.iter().rev() {
+        let cards = item.change.cards();
+        if !cards.iter().any(|card| titles.contains(card)) {
+            continue;
+        }
+
+        if let BoardChange::CardRenamed { from, .. } = &item.change {
+            titles.push(from);
+        }
+        history.push(item);
+    }
+
+    history.reverse();
+    history
+}
@@ -0,0 +1,347 @@
+// crates/kandown/src/html.rs
+
+use anyhow::{Result, anyhow};
+use pulldown_cmark::{CowStr, Event, Parser, Tag, html};
+use std::{borrow::Cow, cell::RefCell, collections::BTreeMap, fmt::Write, rc::Rc};
+
+use crate::{Kanban, KanbanCard, KanbanProperty, KanbanViewType};
+
+const STYLE: &str = r#"
+body { font-family: system-ui, sans-serif; margin: 2rem; color: #1f2328; background: #f6f8fa; }
+h1 { font-size: 1.5rem; }
+.board { display: flex; gap: 1rem; align-items: flex-start; overflow-x: auto; }
+.column { background: #eaeef2; border-radius: 6px; padding: 0.5rem; min-width: 16rem; flex: 0 0 16rem; }
+.column h2 { font-size: 1rem; margin: 0.25rem 0.25rem 0.75rem; }
+.count { color: #656d76; font-weight: normal; }
+.card { background: #fff; border: 1px solid #d0d7de; border-radius: 6px; padding: 0.5rem 0.75rem; margin-bottom: 0.5rem; }
+.card h3 { font-size: 0.95rem; margin: 0.25rem 0; }
+.properties { display: grid; grid-template-columns: auto 1fr; gap: 0 0.5rem; font-size: 0.85rem; margin: 0.25rem 0; }
+.properties dt { color: #656d76; }
+.properties dd { margin: 0; }
+.description { font-size: 0.85rem; }
+table { border-collapse: collapse; background: #fff; margin-bottom: 1.5rem; }
+th, td { border: 1px solid #d0d7de; padding: 0.4rem 0.6rem; text-align: left; vertical-align: top; }
+.calendar td { width: 8rem; height: 5rem; }
+.calendar caption { font-weight: bold; text-align: left; padding: 0.5rem 0; }
+.calendar ul { margin: 0; padding-left: 1rem; font-size: 0.85rem; }
+.day { color: #656d76; font-size: 0.8rem; }
+.outside { background: #f6f8fa; }
+"#;
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+impl Kanban {
+    // Render a view as a self-contained HTML page. Boards are rendered as
+    // columns, tables as a table and calendars/timelines as month grids, using
+    // the same filtering, grouping and sorting as the interactive views.
+    pub fn render_view_html(&self, view_name: &str) -> Result<String> {
+        let view_rc = self
+            .view_by_name
+            .get(view_name)
+            .ok_or_else(|| anyhow!("View not found: {view_name}"))?;
+
+        let mut body = String::new();
+        let (layout, shown) = {
+            let view = view_rc.borrow();
+            let layout = match &view.view_layout {
+                KanbanViewType::Board { group_by } => Layout::Board(group_by.clone()),
+                KanbanViewType::Table { .. } => Layout::Table,
+                KanbanViewType::Calendar { date_property }
+                | KanbanViewType::Timeline { date_property } => {
+                    Layout::Calendar(date_property.clone())
+                }
+            };
+            (layout, self.shown_properties(&view, true))
+        };
+
+        match layout {
+            Layout::Board(group_by) => {
+                let columns = if group_by.is_some() {
+                    self.get_ordered_groups(view_name)?
+                } else {
+                    vec![(String::new(), self.get_view_cards(view_name)?)]
+                };
+
+                body.push_str("<div class=\"board\">\n");
+                for (column, cards) in columns {
+                    let title = if column.is_empty() {
+                        "No value"
+                    } else {
+                        column.as_str()
+                    };
+                    let _ = writeln!(
+                        body,
+                        "<section class=\"column\">\n<h2>{} <span class=\"count\">{}</span></h2>",
+                        escape(title),
+                        cards.len()
+                    );
+                    for card_rc in cards {
+                        render_card(&mut body, self, &card_rc.borrow(), &shown);
+                    }
+                    body.push_str("</section>\n");
+                }
+                body.push_str("</div>\n");
+            }
+            Layout::Table => {
+                let cards = self.get_view_cards(view_name)?;
+
+                body.push_str("<table>\n<thead>\n<tr><th>Title</th>");
+                for prop_rc in &shown {
+                    let _ = write!(body, "<th>{}</th>", escape(&prop_rc.borrow().name));
+                }
+                body.push_str("</tr>\n</thead>\n<tbody>\n");
+                for card_rc in cards {
+                    let card = card_rc.borrow();
+                    let _ = write!(body, "<tr><td>{}</td>", escape(&card.title));
+                    for prop_rc in &shown {
+                        let value = self.card_value(&card, prop_rc).unwrap_or_default();
+                        let _ = write!(body, "<td>{}</td>", escape(&value));
+                    }
+                    body.push_str("</tr>\n");
+                }
+                body.push_str("</tbody>\n</table>\n");
+            }
+            Layout::Calendar(date_property) => {
+                let cards = self.get_view_cards(view_name)?;
+                let mut by_month: BTreeMap<(i32, u32), BTreeMap<u32, Vec<String>>> =
+                    BTreeMap::new();
+                let mut undated = Vec::new();
+
+                for card_rc in cards {
+                    let card = card_rc.borrow();
+                    let date = date_property
+                        .as_ref()
+                        .and_then(|prop_rc| self.card_value(&card, prop_rc))
+                        .and_then(|value| parse_date(&value));
+
+                    match date {
+                        Some((year, month, day)) => by_month
+                            .entry((year, month))
+                            .or_default()
+                            .entry(day)
+                            .or_default()
+                            .push(card.title.clone()),
+                        None => undated.push(card.title.clone()),
+                    }
+                }
+
+                for ((year, month), days) in &by_month {
+                    render_month(&mut body, *year, *month, days);
+                }
+
+                if !undated.is_empty() {
+                    body.push_str("<section class=\"undated\">\n<h2>No date</h2>\n<ul>\n");
+                    for title in undated {
+                        let _ = writeln!(body, "<li>{}</li>", escape(&title));
+                    }
+                    body.push_str("</ul>\n</section>\n");
+                }
+            }
+        }
+
+        let title = escape(view_name);
+        Ok(format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{title}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n\
+             <h1>{title}</h1>\n{body}</body>\n</html>\n"
+        ))
+    }
+}
+
+enum Layout {
+    Board(Option<Rc<RefCell<KanbanProperty>>>),
+    Table,
+    Calendar(Option<Rc<RefCell<KanbanProperty>>>),
+}
+
+fn render_card(
+    out: &mut String,
+    board: &Kanban,
+    card: &KanbanCard,
+    shown: &[Rc<RefCell<KanbanProperty>>],
+) {
+    let _ = writeln!(
+        out,
+        "<article class=\"card\">\n<h3>{}</h3>",
+        escape(&card.title)
+    );
+
+    let values: Vec<(String, Cow<str>)> = shown
+        .iter()
+        .filter_map(|prop_rc| {
+            board
+                .card_value(card, prop_rc)
+                .map(|value| (prop_rc.borrow().name.clone(), value))
+        })
+        .collect();
+    if !values.is_empty() {
+        out.push_str("<dl class=\"properties\">\n");
+        for (name, value) in values {
+            let _ = writeln!(out, "<dt>{}</dt><dd>{}</dd>", escape(&name), escape(&value));
+        }
+        out.push_str("</dl>\n");
+    }
+
+    if !card.description.trim().is_empty() {
+        out.push_str("<div class=\"description\">\n");
+        out.push_str(&markdown_to_html(&card.description));
+        out.push_str("</div>\n");
+    }
+
+    out.push_str("</article>\n");
+}
+
+fn render_month(out: &mut String, year: i32, month: u32, days: &BTreeMap<u32, Vec<String>>) {
+    let _ = writeln!(
+        out,
+        "<table class=\"calendar\">\n<caption>{} {year}</caption>",
+        MONTH_NAMES[month as usize - 1]
+    );
+    out.push_str("<thead>\n<tr>");
+    for weekday in WEEKDAYS {
+        let _ = write!(out, "<th>{weekday}</th>");
+    }
+    out.push_str("</tr>\n</thead>\n<tbody>\n<tr>");
+
+    let offset = weekday(year, month, 1);
+    for _ in 0..offset {
+        out.push_str("<td class=\"outside\"></td>");
+    }
+
+    let last_day = days_in_month(year, month);
+    for day in 1..=last_day {
+        let _ = write!(out, "<td><span class=\"day\">{day}</span>");
+        if let Some(titles) = days.get(&day) {
+            out.push_str("<ul>");
+            for title in titles {
+                let _ = write!(out, "<li>{}</li>", escape(title));
+            }
+            out.push_str("</ul>");
+        }
+        out.push_str("</td>");
+
+        let column = (offset + day) % 7;
+        if column == 0 && day != last_day {
+            out.push_str("</tr>\n<tr>");
+        }
+    }
+
+    let trailing = (7 - (offset + last_day) % 7) % 7;
+    for _ in 0..trailing {
+        out.push_str("<td class=\"outside\"></td>");
+    }
+    out.push_str("</tr>\n</tbody>\n</table>\n");
+}
+
+// Render card markdown, escaping any raw HTML and dropping link targets that
+// could run script, so published pages stay inert
+fn markdown_to_html(markdown: &str) -> String {
+    let parser = Parser::new(markdown).map(|event| match event {
+        Event::Html(html) | Event::InlineHtml(html) => Event::Text(html),
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) if !is_safe_url(&dest_url) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: CowStr::Borrowed(""),
+            title,
+            id,
+        }),
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) if !is_safe_url(&dest_url) => Event::Start(Tag::Image {
+            link_type,
+            dest_url: CowStr::Borrowed(""),
+            title,
+            id,
+        }),
+        event => event,
+    });
+
+    let mut output = String::new();
+    html::push_html(&mut output, parser);
+    output
+}
+
+// Whether a link or image target is http(s), mailto or relative. Browsers
+// ignore whitespace and control characters inside a scheme, so they are
+// skipped before looking for one.
+fn is_safe_url(url: &str) -> bool {
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+
+    match url.find([':', '/', '?', '#']) {
+        Some(idx) if url[idx..].starts_with(':') => {
+            matches!(&url[..idx], "http" | "https" | "mailto")
+        }
+        _ => true,
+    }
+}
+
+pub(crate) fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Parse a `YYYY-MM-DD` date (anything after the day is ignored)
+fn parse_date(s: &str) -> Option<(i32, u32, u32)> {
+    let mut parts = s.get(..10)?.split('-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+
+    ((1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day))
+        .then_some((year, month, day))
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Day of the week with Monday = 0 (Sakamoto's method)
+fn weekday(year: i32, month: u32, day: u32) -> u32 {
+    const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let year = if month < 3 { year - 1 } else { year };
+    let sunday_based =
+        (year + year / 4 - year / 100 + year / 400 + OFFSETS[month as usize - 1] + day as i32)
+            .rem_euclid(7);
+    ((sunday_based + 6) % 7) as u32
+}
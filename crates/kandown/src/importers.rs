@@ -0,0 +1,556 @@
+// crates/kandown/src/importers.rs
+
+use anyhow::{Result, anyhow, bail};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    BoardMetadata, FORMAT_VERSION, KanbanSortType, ParsedCard, ParsedDocument, ParsedProperty,
+    ParsedPropertyType, ParsedPropertyValue, ParsedView, ParsedViewType, PropertyRules,
+    gfm::single_line, parse_subtask,
+};
+
+// Select columns with more distinct values than this are imported as Text
+const MAX_INFERRED_OPTIONS: usize = 20;
+
+// --- Trello ---
+
+#[derive(Deserialize)]
+struct TrelloBoard {
+    name: String,
+    #[serde(default)]
+    lists: Vec<TrelloList>,
+    #[serde(default)]
+    cards: Vec<TrelloCard>,
+    #[serde(default)]
+    members: Vec<TrelloMember>,
+}
+
+#[derive(Deserialize)]
+struct TrelloList {
+    id: String,
+    name: String,
+    #[serde(default)]
+    closed: bool,
+    #[serde(default)]
+    pos: f64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrelloCard {
+    name: String,
+    #[serde(default)]
+    desc: String,
+    id_list: String,
+    #[serde(default)]
+    closed: bool,
+    #[serde(default)]
+    due: Option<String>,
+    #[serde(default)]
+    labels: Vec<TrelloLabel>,
+    #[serde(default)]
+    id_members: Vec<String>,
+    #[serde(default)]
+    pos: f64,
+}
+
+#[derive(Deserialize)]
+struct TrelloLabel {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    color: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrelloMember {
+    id: String,
+    #[serde(default)]
+    full_name: String,
+    #[serde(default)]
+    username: String,
+}
+
+/// Import a Trello board from its JSON export ("Print and export" → JSON).
+///
+/// Open lists become the options of a `List` Select property and a Board view
+/// named after the Trello board groups by it. Labels, members and due dates are
+/// added as properties when any card uses them. Archived lists and cards are
+/// skipped.
+pub fn import_trello(json: &str) -> Result<ParsedDocument> {
+    let board: TrelloBoard =
+        serde_json::from_str(json).map_err(|e| anyhow!("Invalid Trello export: {e}"))?;
+
+    let mut lists: Vec<&TrelloList> = board.lists.iter().filter(|l| !l.closed).collect();
+    lists.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+
+    let mut cards: Vec<&TrelloCard> = board
+        .cards
+        .iter()
+        .filter(|c| !c.closed && lists.iter().any(|l| l.id == c.id_list))
+        .collect();
+    cards.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+
+    let member_name = |id: &String| {
+        board
+            .members
+            .iter()
+            .find(|m| &m.id == id)
+            .map(|m| {
+                if m.full_name.is_empty() {
+                    m.username.clone()
+                } else {
+                    m.full_name.clone()
+                }
+            })
+            .unwrap_or_else(|| id.clone())
+    };
+
+    let mut parsed_cards = Vec::new();
+    for card in cards {
+        let list = lists.iter().find(|l| l.id == card.id_list).unwrap();
+        let mut properties = vec![value("List", &list.name)];
+
+        let labels: Vec<String> = card
+            .labels
+            .iter()
+            .filter_map(|l| {
+                if !l.name.is_empty() {
+                    Some(l.name.clone())
+                } else {
+                    l.color.clone()
+                }
+            })
+            .collect();
+        if !labels.is_empty() {
+            properties.push(value("Labels", &labels.join(", ")));
+        }
+
+        if !card.id_members.is_empty() {
+            let members: Vec<String> = card.id_members.iter().map(member_name).collect();
+            properties.push(value("Members", &members.join(", ")));
+        }
+
+        if let Some(due) = card.due.as_deref().and_then(normalize_date) {
+            properties.push(value("Due", &due));
+        }
+
+        parsed_cards.push(card_from(&card.name, &card.desc, properties));
+    }
+
+    let mut properties = vec![ParsedProperty {
+        name: "List".to_string(),
+        property_type: ParsedPropertyType::Select {
+            options: lists.iter().map(|l| single_line(&l.name)).collect(),
+        },
+        rules: PropertyRules::default(),
+    }];
+    for (name, property_type) in [
+        ("Labels", ParsedPropertyType::Text),
+        ("Members", ParsedPropertyType::Text),
+        ("Due", ParsedPropertyType::Date),
+    ] {
+        if uses_property(&parsed_cards, name) {
+            properties.push(ParsedProperty {
+                name: name.to_string(),
+                property_type,
+                rules: PropertyRules::default(),
+            });
+        }
+    }
+
+    Ok(build_document(&board.name, properties, parsed_cards))
+}
+
+// --- GitHub Projects ---
+
+#[derive(Deserialize)]
+struct GithubItemList {
+    items: Vec<serde_json::Map<String, Value>>,
+}
+
+// Item keys handled explicitly rather than imported as project fields
+const GITHUB_RESERVED_KEYS: [&str; 3] = ["id", "title", "content"];
+
+/// Import GitHub Projects (v2) items as produced by
+/// `gh project item-list <number> --format json`.
+///
+/// Every project field becomes a property: `status` and other single-select
+/// style fields become Select properties, numbers, dates and booleans are typed
+/// accordingly, and list fields such as `assignees` or `labels` are joined into
+/// Text. The issue or pull request body becomes the card description.
+pub fn import_github_project(json: &str, project_name: &str) -> Result<ParsedDocument> {
+    let list: GithubItemList =
+        serde_json::from_str(json).map_err(|e| anyhow!("Invalid GitHub project export: {e}"))?;
+
+    // Collect the union of field names across all items
+    let mut field_keys: Vec<String> = Vec::new();
+    for item in &list.items {
+        for key in item.keys() {
+            if !GITHUB_RESERVED_KEYS.contains(&key.as_str()) && !field_keys.contains(key) {
+                field_keys.push(key.clone());
+            }
+        }
+    }
+
+    let mut rows = Vec::new();
+    for item in &list.items {
+        let content = item.get("content");
+        let title = item
+            .get("title")
+            .or_else(|| content.and_then(|c| c.get("title")))
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let body = content
+            .and_then(|c| c.get("body"))
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+
+        let values: Vec<Option<String>> = field_keys
+            .iter()
+            .map(|key| item.get(key).and_then(github_field_text))
+            .collect();
+
+        rows.push((title.to_string(), body.to_string(), values));
+    }
+
+    let columns: Vec<(String, Vec<Option<String>>)> = field_keys
+        .iter()
+        .enumerate()
+        .map(|(idx, key)| {
+            let values = rows.iter().map(|(_, _, values)| values[idx].clone());
+            (property_name(key), values.collect())
+        })
+        .collect();
+
+    let (properties, typed_columns) = infer_properties(columns);
+
+    let cards = rows
+        .iter()
+        .enumerate()
+        .map(|(row, (title, body, _))| {
+            let values = properties
+                .iter()
+                .zip(&typed_columns)
+                .filter_map(|(prop, column)| column[row].as_ref().map(|v| value(&prop.name, v)))
+                .collect();
+            card_from(title, body, values)
+        })
+        .collect();
+
+    Ok(build_document(project_name, properties, cards))
+}
+
+// Flatten a project field value into text
+fn github_field_text(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) if s.is_empty() => None,
+        Value::String(s) => Some(s.clone()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Array(items) => {
+            let parts: Vec<String> = items.iter().filter_map(github_field_text).collect();
+            (!parts.is_empty()).then(|| parts.join(", "))
+        }
+        // Milestones, iterations and similar objects are identified by title
+        Value::Object(map) => ["title", "name", "login"]
+            .iter()
+            .find_map(|key| map.get(*key))
+            .and_then(github_field_text),
+    }
+}
+
+// --- Notion ---
+
+/// Import a Notion database from its CSV export ("Export" → Markdown & CSV).
+///
+/// The first column is the page title. Other columns are typed by their
+/// contents: Yes/No columns become Checkbox, numeric columns Number, date
+/// columns Date (normalized to `YYYY-MM-DD`) and columns with a small set of
+/// repeated values Select. Everything else is imported as Text.
+pub fn import_notion_csv(csv: &str, database_name: &str) -> Result<ParsedDocument> {
+    let csv = csv.strip_prefix('\u{feff}').unwrap_or(csv);
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(csv.as_bytes());
+
+    let headers: Vec<String> = reader.headers()?.iter().map(|h| h.to_string()).collect();
+    if headers.is_empty() {
+        bail!("Notion export has no columns");
+    }
+
+    let mut titles = Vec::new();
+    let mut columns: Vec<(String, Vec<Option<String>>)> = headers[1..]
+        .iter()
+        .map(|h| (property_name(h), Vec::new()))
+        .collect();
+
+    for record in reader.records() {
+        let record = record?;
+        titles.push(record.get(0).unwrap_or_default().trim().to_string());
+
+        for (idx, (_, values)) in columns.iter_mut().enumerate() {
+            let cell = record.get(idx + 1).unwrap_or_default().trim();
+            values.push((!cell.is_empty()).then(|| cell.to_string()));
+        }
+    }
+
+    let (properties, typed_columns) = infer_properties(columns);
+
+    let cards = titles
+        .iter()
+        .enumerate()
+        .map(|(row, title)| {
+            let values = properties
+                .iter()
+                .zip(&typed_columns)
+                .filter_map(|(prop, column)| column[row].as_ref().map(|v| value(&prop.name, v)))
+                .collect();
+            card_from(title, "", values)
+        })
+        .collect();
+
+    Ok(build_document(database_name, properties, cards))
+}
+
+// --- Shared helpers ---
+
+// Infer a property type for each column and normalize its values to match
+fn infer_properties(
+    columns: Vec<(String, Vec<Option<String>>)>,
+) -> (Vec<ParsedProperty>, Vec<Vec<Option<String>>>) {
+    let mut properties = Vec::new();
+    let mut typed_columns = Vec::new();
+
+    for (name, values) in columns {
+        let present: Vec<&str> = values.iter().flatten().map(|v| v.as_str()).collect();
+        if present.is_empty() {
+            continue;
+        }
+
+        let is_bool = |v: &str| {
+            ["yes", "no", "true", "false"]
+                .iter()
+                .any(|b| v.eq_ignore_ascii_case(b))
+        };
+
+        let (property_type, values) = if present.iter().all(|v| is_bool(v)) {
+            let values = values
+                .into_iter()
+                .map(|v| {
+                    v.map(|v| {
+                        let checked =
+                            v.eq_ignore_ascii_case("yes") || v.eq_ignore_ascii_case("true");
+                        checked.to_string()
+                    })
+                })
+                .collect();
+            (ParsedPropertyType::Checkbox, values)
+        } else if present.iter().all(|v| v.parse::<f64>().is_ok()) {
+            (ParsedPropertyType::Number, values)
+        } else if present.iter().all(|v| normalize_date(v).is_some()) {
+            let values = values
+                .into_iter()
+                .map(|v| v.and_then(|v| normalize_date(&v)))
+                .collect();
+            (ParsedPropertyType::Date, values)
+        } else {
+            let mut options: Vec<String> = Vec::new();
+            for v in &present {
+                let v = single_line(v);
+                if !options.contains(&v) {
+                    options.push(v);
+                }
+            }
+
+            let repeated = options.len() < present.len();
+            let is_status = name.eq_ignore_ascii_case("Status");
+            if (is_status || repeated) && options.len() <= MAX_INFERRED_OPTIONS {
+                (ParsedPropertyType::Select { options }, values)
+            } else {
+                (ParsedPropertyType::Text, values)
+            }
+        };
+
+        properties.push(ParsedProperty {
+            name,
+            property_type,
+            rules: PropertyRules::default(),
+        });
+        typed_columns.push(values);
+    }
+
+    (properties, typed_columns)
+}
+
+// Assemble the document with a view for the imported board. The view groups
+// by `Status` (or the first Select property) and falls back to a table.
+fn build_document(
+    board_name: &str,
+    properties: Vec<ParsedProperty>,
+    mut cards: Vec<ParsedCard>,
+) -> ParsedDocument {
+    for (idx, card) in cards.iter_mut().enumerate() {
+        card.id = idx;
+    }
+
+    let is_select =
+        |p: &&ParsedProperty| matches!(p.property_type, ParsedPropertyType::Select { .. });
+    let group = properties
+        .iter()
+        .filter(is_select)
+        .find(|p| p.name.eq_ignore_ascii_case("Status"))
+        .or_else(|| properties.iter().find(is_select))
+        .map(|p| p.name.clone());
+
+    let display: Vec<String> = properties
+        .iter()
+        .filter(|p| Some(&p.name) != group.as_ref())
+        .map(|p| p.name.clone())
+        .collect();
+
+    let name = single_line(board_name);
+    let view = ParsedView {
+        name: if name.is_empty() {
+            "Imported".to_string()
+        } else {
+            name
+        },
+        layout: if group.is_some() {
+            ParsedViewType::Board
+        } else {
+            ParsedViewType::Table
+        },
+        group,
+        filter: None,
+        sort_by: None,
+        sort_type: KanbanSortType::None,
+        column_sorts: vec![],
+        display: (!display.is_empty()).then(|| display.join(", ")),
+    };
+
+    ParsedDocument {
+        metadata: BoardMetadata {
+            version: Some(FORMAT_VERSION),
+            ..BoardMetadata::default()
+        },
+        properties,
+        cards,
+        views: vec![view],
+    }
+}
+
+// Checklist lines in the description become the card's subtasks
+fn card_from(title: &str, description: &str, properties: Vec<ParsedPropertyValue>) -> ParsedCard {
+    let mut subtasks = Vec::new();
+    let description: Vec<&str> = description
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| match parse_subtask(line) {
+            Some(subtask) => {
+                subtasks.push(subtask);
+                false
+            }
+            None => true,
+        })
+        .collect();
+
+    ParsedCard {
+        id: 0, // Assigned in build_document
+        title: single_line(title),
+        description: description.join("\n").trim().to_string(),
+        properties,
+        subtasks,
+    }
+}
+
+fn value(property_name: &str, value: &str) -> ParsedPropertyValue {
+    ParsedPropertyValue {
+        property_name: property_name.to_string(),
+        value: single_line(value),
+    }
+}
+
+fn uses_property(cards: &[ParsedCard], name: &str) -> bool {
+    cards
+        .iter()
+        .any(|c| c.properties.iter().any(|p| p.property_name == name))
+}
+
+// Turn an external field key into a property name the grammar accepts
+fn property_name(key: &str) -> String {
+    let key = single_line(key).replace(':', " ");
+    let mut chars = key.trim().chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => "Field".to_string(),
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Normalize common export date formats to `YYYY-MM-DD`.
+///
+/// Accepts ISO dates and timestamps, `YYYY/MM/DD`, `MM/DD/YYYY` and Notion's
+/// `Month D, YYYY` (optionally followed by a time or a `→` range end).
+pub fn normalize_date(s: &str) -> Option<String> {
+    let s = s.split('→').next()?.trim();
+    let ymd = |y: &str, m: &str, d: &str| -> Option<String> {
+        let (y, m, d) = (
+            y.parse::<u32>().ok()?,
+            m.parse::<u32>().ok()?,
+            d.parse::<u32>().ok()?,
+        );
+        ((1000..=9999).contains(&y) && (1..=12).contains(&m) && (1..=31).contains(&d))
+            .then(|| format!("{y:04}-{m:02}-{d:02}"))
+    };
+
+    // ISO date or timestamp: 2024-03-05 / 2024-03-05T10:00:00.000Z
+    if let Some(date) = s.get(..10)
+        && let [y, m, d] = date.split('-').collect::<Vec<_>>()[..]
+        && s[10..].chars().next().is_none_or(|c| c == 'T' || c == ' ')
+    {
+        return ymd(y, m, d);
+    }
+
+    let first_word = s.split_whitespace().next()?;
+    if let [a, b, c] = first_word.split('/').collect::<Vec<_>>()[..] {
+        return if a.len() == 4 {
+            ymd(a, b, c)
+        } else {
+            ymd(c, a, b)
+        };
+    }
+
+    // Month D, YYYY
+    let words: Vec<&str> = s.split_whitespace().collect();
+    if let [month, day, year, ..] = words[..] {
+        let month = month.to_ascii_lowercase();
+        let month_idx = MONTHS
+            .iter()
+            .position(|m| *m == month || (month.len() >= 3 && m.starts_with(&month)))?;
+        return ymd(
+            year,
+            &(month_idx + 1).to_string(),
+            day.trim_end_matches(','),
+        );
+    }
+
+    None
+}
@@ -2,14 +2,18 @@
 
 use anyhow::{Result, anyhow, bail};
 use std::{
+    borrow::Cow,
     cell::RefCell,
+    cmp::Ordering,
     collections::HashMap,
     rc::{Rc, Weak},
 };
 
 use crate::{
-    ColumnSort, KanbanSortType, ParsedCard, ParsedDocument, ParsedProperty, ParsedPropertyType,
-    ParsedPropertyValue, ParsedView, ParsedViewType,
+    BLOCKED_BY_PROPERTY, BLOCKS_PROPERTY, BoardMetadata, ColumnSort, Diagnostic, DocumentSection,
+    FORMAT_VERSION, Formula, KanbanSortType, PARENT_PROPERTY, ParsedCard, ParsedDocument,
+    ParsedProperty, ParsedPropertyType, ParsedPropertyValue, ParsedView, ParsedViewType,
+    ParserConfig, PropertyRules, Subtask, ViewFilter, system_today,
 };
 
 pub struct Kanban {
@@ -21,19 +25,27 @@ pub struct Kanban {
     pub card_by_title: HashMap<String, Rc<RefCell<KanbanCard>>>,
     pub card_by_id: HashMap<usize, Rc<RefCell<KanbanCard>>>,
     pub view_by_name: HashMap<String, Rc<RefCell<KanbanView>>>,
+
+    pub metadata: BoardMetadata,
+    pub today: Option<String>, // The date `today()` gives in formulas, as YYYY-MM-DD
 }
 
+// A board column: the group value and its cards in display order
+pub type KanbanColumn = (String, Vec<Rc<RefCell<KanbanCard>>>);
+
 pub enum KanbanPropertyType {
     Text,
     Number,
     Date,
     Checkbox,
     Select { options: Vec<String> },
+    Formula { formula: Formula },
 }
 
 pub struct KanbanProperty {
     pub name: String,
     pub property_type: KanbanPropertyType,
+    pub rules: PropertyRules,
     pub cards: Vec<Weak<RefCell<KanbanCard>>>, // Reference to all cards that use this property
 }
 
@@ -42,6 +54,7 @@ pub struct KanbanCard {
     pub title: String,
     pub description: String,
     pub properties: Vec<(Rc<RefCell<KanbanProperty>>, String)>,
+    pub subtasks: Vec<Subtask>,
     pub views: Vec<Weak<RefCell<KanbanView>>>, // Weak references to avoid cycles
 }
 
@@ -66,13 +79,258 @@ pub struct KanbanView {
     pub filter: Option<String>,
     pub sort_type: KanbanSortType,
     pub sort_by: Option<Rc<RefCell<KanbanProperty>>>,
+    pub sort_by_computed: Option<String>, // A computed value such as `Progress`
     pub column_sorts: HashMap<String, Vec<usize>>, // For manual sorting
     pub display: Vec<Rc<RefCell<KanbanProperty>>>,
     pub cards: Vec<Rc<RefCell<KanbanCard>>>,
 }
 
+impl KanbanProperty {
+    // Check that a value is acceptable for this property's type
+    pub fn validate_value(&self, value: &str) -> Result<()> {
+        let name = &self.name;
+        match &self.property_type {
+            KanbanPropertyType::Select { options } if !options.iter().any(|o| o == value) => {
+                Err(anyhow!("Invalid value for {name}: {value}"))
+            }
+            KanbanPropertyType::Checkbox if value != "true" && value != "false" => {
+                Err(anyhow!("Invalid value for checkbox {name}: {value}"))
+            }
+            KanbanPropertyType::Formula { .. } => {
+                Err(anyhow!("{name} is computed by a formula and cannot be set"))
+            }
+            // More validation could be added for other types
+            _ => self.rules.check(name, value),
+        }
+    }
+
+    // Compare two values of this property, placing missing values last
+    pub fn compare_values(&self, a: Option<&str>, b: Option<&str>) -> Ordering {
+        let (a, b) = match (a, b) {
+            (Some(a), Some(b)) => (a, b),
+            (Some(_), None) => return Ordering::Less,
+            (None, Some(_)) => return Ordering::Greater,
+            (None, None) => return Ordering::Equal,
+        };
+
+        match &self.property_type {
+            // Formulas may give numbers, dates, text or booleans
+            KanbanPropertyType::Number | KanbanPropertyType::Formula { .. } => {
+                match (a.parse::<f64>(), b.parse::<f64>()) {
+                    (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+                    (Ok(_), Err(_)) => Ordering::Less,
+                    (Err(_), Ok(_)) => Ordering::Greater,
+                    (Err(_), Err(_)) => a.cmp(b),
+                }
+            }
+            KanbanPropertyType::Select { options } => {
+                let position = |value: &str| options.iter().position(|o| o == value);
+                match (position(a), position(b)) {
+                    (Some(a), Some(b)) => a.cmp(&b),
+                    _ => a.cmp(b),
+                }
+            }
+            _ => a.cmp(b),
+        }
+    }
+}
+
+// Pseudo-properties computed from a card's checklist: `Subtasks` counts the
+// done items out of all (`3/5`) and `Progress` is the percentage done
+pub const COMPUTED_PROPERTIES: [&str; 2] = ["Subtasks", "Progress"];
+
+impl KanbanCard {
+    // Get the value of a property by name, falling back to the title for `Title`
+    pub fn get_value(&self, property_name: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|(prop, _)| prop.borrow().name == property_name)
+            .map(|(_, value)| value.as_str())
+            .or_else(|| (property_name == "Title").then_some(self.title.as_str()))
+    }
+
+    // Done and total subtasks, or None for cards without a checklist
+    pub fn progress(&self) -> Option<(usize, usize)> {
+        let done = self.subtasks.iter().filter(|s| s.done).count();
+        (!self.subtasks.is_empty()).then_some((done, self.subtasks.len()))
+    }
+
+    // Get the value of one of the `COMPUTED_PROPERTIES`
+    pub fn computed_value(&self, name: &str) -> Option<String> {
+        let (done, total) = self.progress()?;
+        match name {
+            "Subtasks" => Some(format!("{done}/{total}")),
+            "Progress" => Some((done * 100 / total).to_string()),
+            _ => None,
+        }
+    }
+
+    // Get a property value, the title or a computed value, as used by filters
+    pub fn field_value(&self, name: &str) -> Option<Cow<'_, str>> {
+        self.get_value(name)
+            .map(Cow::Borrowed)
+            .or_else(|| self.computed_value(name).map(Cow::Owned))
+    }
+}
+
+impl KanbanView {
+    // Cards in this view that pass the view's filter. `board` evaluates
+    // formula properties. A filter that doesn't parse is ignored, as free-form
+    // filter text was before filters had a syntax; lint reports it as
+    // invalid-filter.
+    pub fn filtered_cards(&self, board: &Kanban) -> Vec<Rc<RefCell<KanbanCard>>> {
+        let Some(filter) = self
+            .filter
+            .as_deref()
+            .and_then(|f| ViewFilter::parse(f).ok())
+        else {
+            return self.cards.clone();
+        };
+
+        self.cards
+            .iter()
+            .filter(|card_rc| {
+                let card = card_rc.borrow();
+                filter.matches(|name| board.field_value(&card, name))
+            })
+            .cloned()
+            .collect()
+    }
+
+    // Sort cards according to the view's sort settings. `group` is the board
+    // column being sorted, used to look up manual orderings.
+    pub fn sort_cards(
+        &self,
+        board: &Kanban,
+        cards: &mut [Rc<RefCell<KanbanCard>>],
+        group: Option<&str>,
+    ) {
+        if self.sort_type == KanbanSortType::Manual {
+            // If manual sorting is defined for this column
+            if let Some(order) = group.and_then(|group| self.column_sorts.get(group)) {
+                // Create a map of card ID to position for quick lookup
+                let position_map: HashMap<usize, usize> = order
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, &card_id)| (card_id, idx))
+                    .collect();
+
+                // Sort cards based on the manual ordering
+                cards.sort_by(|a, b| {
+                    let a_id = a.borrow().id;
+                    let b_id = b.borrow().id;
+
+                    // Get positions from the map, defaulting to max value if not found
+                    let a_pos = position_map.get(&a_id).copied().unwrap_or(usize::MAX);
+                    let b_pos = position_map.get(&b_id).copied().unwrap_or(usize::MAX);
+
+                    // Compare positions
+                    a_pos.cmp(&b_pos)
+                });
+            }
+            return;
+        }
+
+        // Sort by property value when the view has a sort property. Formulas
+        // share the board's links rather than working them out per card.
+        if let Some(prop_rc) = &self.sort_by {
+            let prop = prop_rc.borrow();
+            let links = board.is_formula(&prop.name).then(|| board.card_links());
+            let value_of = |card_rc: &Rc<RefCell<KanbanCard>>| {
+                let card = card_rc.borrow();
+                match &links {
+                    Some(links) => board.formula_value_with(links, &card, &prop.name),
+                    None => card.field_value(&prop.name).map(Cow::into_owned),
+                }
+            };
+
+            sort_by_value(cards, value_of, |a, b| {
+                let ordering = prop.compare_values(a.as_deref(), b.as_deref());
+                if self.sort_type == KanbanSortType::ReverseAlpha {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+            return;
+        }
+
+        if let Some(name) = &self.sort_by_computed {
+            let value_of = |card_rc: &Rc<RefCell<KanbanCard>>| {
+                card_rc
+                    .borrow()
+                    .computed_value(name)
+                    .and_then(|value| value.parse::<f64>().ok())
+            };
+
+            // Cards without a value go last in either direction
+            sort_by_value(cards, value_of, |a, b| match (a, b) {
+                (Some(a), Some(b)) if self.sort_type == KanbanSortType::ReverseAlpha => {
+                    b.total_cmp(a)
+                }
+                (Some(a), Some(b)) => a.total_cmp(b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            });
+            return;
+        }
+
+        match self.sort_type {
+            KanbanSortType::Alpha => {
+                cards.sort_by(|a, b| {
+                    let a_title = &a.borrow().title;
+                    let b_title = &b.borrow().title;
+                    a_title.cmp(b_title)
+                });
+            }
+            KanbanSortType::ReverseAlpha => {
+                cards.sort_by(|a, b| {
+                    let a_title = &a.borrow().title;
+                    let b_title = &b.borrow().title;
+                    b_title.cmp(a_title)
+                });
+            }
+            KanbanSortType::Manual | KanbanSortType::None => {
+                // No sorting, leave as is
+            }
+        }
+    }
+}
+
+// Sort cards by a value worked out once per card before sorting, as values
+// such as formula results are too costly to work out for every comparison
+fn sort_by_value<T>(
+    cards: &mut [Rc<RefCell<KanbanCard>>],
+    value_of: impl Fn(&Rc<RefCell<KanbanCard>>) -> T,
+    compare: impl Fn(&T, &T) -> Ordering,
+) {
+    let mut keyed: Vec<(T, Rc<RefCell<KanbanCard>>)> = cards
+        .iter()
+        .map(|card_rc| (value_of(card_rc), Rc::clone(card_rc)))
+        .collect();
+    keyed.sort_by(|(a, _), (b, _)| compare(a, b));
+
+    for (slot, (_, card_rc)) in cards.iter_mut().zip(keyed) {
+        *slot = card_rc;
+    }
+}
+
 impl Kanban {
     pub fn from_document(doc: ParsedDocument) -> Result<Self> {
+        Kanban::from_document_with_diagnostics(doc).map(|(board, _)| board)
+    }
+
+    // Build a board, returning warnings about references that were skipped
+    // alongside it. Problems that make the board unusable are still errors.
+    // Boards in older formats are upgraded, with each change as a warning.
+    pub fn from_document_with_diagnostics(
+        mut doc: ParsedDocument,
+    ) -> Result<(Self, Vec<Diagnostic>)> {
+        // Boards are written with their locale's keywords, so it must be known
+        doc.metadata.parser_config(&ParserConfig::default())?;
+        let mut diagnostics = doc.migrate()?.changes;
+
         // First pass: create all the properties
         let mut properties = Vec::new();
         let mut property_by_name = HashMap::new();
@@ -86,11 +344,23 @@ impl Kanban {
                 ParsedPropertyType::Select { options } => KanbanPropertyType::Select {
                     options: options.clone(),
                 },
+                ParsedPropertyType::Formula { expression } => KanbanPropertyType::Formula {
+                    formula: Formula::parse(&expression).map_err(|e| {
+                        anyhow!("Property '{}' has an invalid formula: {e}", prop.name)
+                    })?,
+                },
             };
 
+            if prop.rules.pattern.is_some() {
+                prop.rules
+                    .pattern_regex()
+                    .map_err(|e| anyhow!("Property '{}' has an invalid pattern: {e}", prop.name))?;
+            }
+
             let prop_rc = Rc::new(RefCell::new(KanbanProperty {
                 name: prop.name.clone(),
                 property_type,
+                rules: prop.rules.clone(),
                 cards: Vec::new(),
             }));
 
@@ -103,7 +373,7 @@ impl Kanban {
         let mut card_by_title = HashMap::new();
         let mut card_by_id = HashMap::new();
 
-        for parsed_card in doc.cards {
+        for (card_idx, parsed_card) in doc.cards.into_iter().enumerate() {
             let mut card_properties = Vec::new();
 
             // Link card to its properties
@@ -113,6 +383,28 @@ impl Kanban {
                     .get(prop_name)
                     .ok_or_else(|| anyhow!("Card references unknown property: {prop_name}"))?;
 
+                // Formulas compute their value, so a written one is dropped
+                if matches!(
+                    prop_rc.borrow().property_type,
+                    KanbanPropertyType::Formula { .. }
+                ) {
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            "formula-value",
+                            format!(
+                                "Card '{}' sets '{prop_name}', which is computed by a formula",
+                                parsed_card.title
+                            ),
+                        )
+                        .at(
+                            DocumentSection::Cards,
+                            card_idx,
+                            &parsed_card.title,
+                        ),
+                    );
+                    continue;
+                }
+
                 card_properties.push((Rc::clone(prop_rc), prop_value.value.clone()));
             }
 
@@ -121,6 +413,7 @@ impl Kanban {
                 title: parsed_card.title.clone(),
                 description: parsed_card.description,
                 properties: card_properties,
+                subtasks: parsed_card.subtasks,
                 views: Vec::new(),
             }));
 
@@ -143,16 +436,19 @@ impl Kanban {
         let mut views = Vec::new();
         let mut view_by_name = HashMap::new();
 
-        for ParsedView {
-            name,
-            layout: view_type,
-            sort_by,
-            group,
-            filter,
-            sort_type,
-            column_sorts,
-            display,
-        } in doc.views
+        for (
+            view_idx,
+            ParsedView {
+                name,
+                layout: view_type,
+                sort_by,
+                group,
+                filter,
+                sort_type,
+                column_sorts,
+                display,
+            },
+        ) in doc.views.into_iter().enumerate()
         {
             let mut view_properties = Vec::new();
             let mut column_sorts_map = HashMap::new();
@@ -164,12 +460,19 @@ impl Kanban {
 
             // Link sort_by property if present
             let mut sort_by_prop = None;
+            let mut sort_by_computed = None;
             if let Some(sort_by_name) = &sort_by {
                 if let Some(prop_rc) = property_by_name.get(sort_by_name) {
                     sort_by_prop = Some(Rc::clone(prop_rc));
+                } else if COMPUTED_PROPERTIES.contains(&sort_by_name.as_str()) {
+                    sort_by_computed = Some(sort_by_name.clone());
                 } else {
-                    println!(
-                        "Warning: View '{name}' references unknown sort_by property: {sort_by_name}"
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            "unknown-view-property",
+                            format!("View '{name}' sorts by unknown property '{sort_by_name}'"),
+                        )
+                        .at(DocumentSection::Views, view_idx, &name),
                     );
                 }
             }
@@ -199,7 +502,7 @@ impl Kanban {
                     if let Some(sort_by_name) = &sort_by {
                         if let Some(prop_rc) = property_by_name.get(sort_by_name) {
                             sort_by_prop = Some(Rc::clone(prop_rc));
-                        } else {
+                        } else if !COMPUTED_PROPERTIES.contains(&sort_by_name.as_str()) {
                             bail!("View '{name}' references unknown sort_by: {sort_by_name}")
                         }
                     }
@@ -239,24 +542,32 @@ impl Kanban {
             };
 
             // Link display properties
-            if let Some(display_str) = &display {
-                if !display_str.trim().is_empty() {
-                    // Split by commas if multiple properties are specified
-                    let display_props: Vec<&str> = display_str
-                        .split(',')
-                        .map(|s| s.trim())
-                        .filter(|s| !s.is_empty())
-                        .collect();
-
-                    for prop_name in display_props {
-                        if let Some(prop_rc) = property_by_name.get(prop_name) {
-                            view_properties.push(Rc::clone(prop_rc));
-                        } else {
-                            // Skip unknown display properties instead of failing
-                            println!(
-                                "Warning: View '{name}' references unknown display property: '{prop_name}'",
-                            );
-                        }
+            if let Some(display_str) = &display
+                && !display_str.trim().is_empty()
+            {
+                // Split by commas if multiple properties are specified
+                let display_props: Vec<&str> = display_str
+                    .split(',')
+                    .map(|s| s.trim())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+
+                for prop_name in display_props {
+                    if let Some(prop_rc) = property_by_name.get(prop_name) {
+                        view_properties.push(Rc::clone(prop_rc));
+                    } else {
+                        // Skip unknown display properties instead of failing
+                        diagnostics.push(
+                            Diagnostic::warning(
+                                "unknown-display-property",
+                                format!("View '{name}' displays unknown property '{prop_name}'"),
+                            )
+                            .at(
+                                DocumentSection::Views,
+                                view_idx,
+                                &name,
+                            ),
+                        );
                     }
                 }
             }
@@ -268,6 +579,7 @@ impl Kanban {
                 filter,
                 sort_type,
                 sort_by: sort_by_prop,
+                sort_by_computed,
                 column_sorts: column_sorts_map,
                 display: view_properties,
                 cards: cards.clone(),
@@ -287,7 +599,7 @@ impl Kanban {
             }
         }
 
-        Ok(Kanban {
+        let board = Kanban {
             properties,
             cards,
             views,
@@ -295,7 +607,11 @@ impl Kanban {
             card_by_title,
             card_by_id,
             view_by_name,
-        })
+            metadata: doc.metadata,
+            today: system_today(),
+        };
+
+        Ok((board, diagnostics))
     }
 
     // Get cards grouped by a property (for board view)
@@ -329,72 +645,131 @@ impl Kanban {
         }
 
         // Group cards by property value
-        for card_rc in &view.cards {
-            let card = card_rc.borrow();
-
-            // Find the property value
-            let group_value = card
-                .properties
-                .iter()
-                .find(|(prop, _)| Rc::ptr_eq(prop, group_by_prop))
-                .map(|(_, value)| value.clone())
-                .unwrap_or_else(|| "".to_string());
+        for card_rc in view.filtered_cards(self) {
+            let group_value = self
+                .field_value(&card_rc.borrow(), &prop.name)
+                .map(Cow::into_owned)
+                .unwrap_or_default();
 
             // Add card to the appropriate group
             result
                 .entry(group_value)
                 .or_insert_with(Vec::new)
-                .push(Rc::clone(card_rc));
+                .push(card_rc);
         }
 
         // Apply sorting if specified
         for (group_name, cards) in result.iter_mut() {
-            match view.sort_type {
-                KanbanSortType::Alpha => {
-                    cards.sort_by(|a, b| {
-                        let a_title = &a.borrow().title;
-                        let b_title = &b.borrow().title;
-                        a_title.cmp(b_title)
-                    });
-                }
-                KanbanSortType::ReverseAlpha => {
-                    cards.sort_by(|a, b| {
-                        let a_title = &a.borrow().title;
-                        let b_title = &b.borrow().title;
-                        b_title.cmp(a_title)
-                    });
-                }
-                KanbanSortType::Manual => {
-                    // If manual sorting is defined for this column
-                    if let Some(order) = view.column_sorts.get(group_name) {
-                        // Create a map of card ID to position for quick lookup
-                        let position_map: HashMap<usize, usize> = order
-                            .iter()
-                            .enumerate()
-                            .map(|(idx, &card_id)| (card_id, idx))
-                            .collect();
-
-                        // Sort cards based on the manual ordering
-                        cards.sort_by(|a, b| {
-                            let a_id = a.borrow().id;
-                            let b_id = b.borrow().id;
-
-                            // Get positions from the map, defaulting to max value if not found
-                            let a_pos = position_map.get(&a_id).copied().unwrap_or(usize::MAX);
-                            let b_pos = position_map.get(&b_id).copied().unwrap_or(usize::MAX);
-
-                            // Compare positions
-                            a_pos.cmp(&b_pos)
-                        });
-                    }
-                }
-                KanbanSortType::None => {
-                    // No sorting, leave as is
+            view.sort_cards(self, cards, Some(group_name));
+        }
+
+        Ok(result)
+    }
+
+    // Get grouped cards as an ordered list of columns: Select options in
+    // declaration order, then any other values, with the ungrouped column last
+    pub fn get_ordered_groups(&self, view_name: &str) -> Result<Vec<KanbanColumn>> {
+        let mut groups = self.get_cards_by_group(view_name)?;
+
+        let mut column_order = Vec::new();
+        if let Some(view_rc) = self.view_by_name.get(view_name)
+            && let KanbanViewType::Board {
+                group_by: Some(group_by),
+            } = &view_rc.borrow().view_layout
+            && let KanbanPropertyType::Select { options } = &group_by.borrow().property_type
+        {
+            column_order.extend(options.iter().cloned());
+        }
+
+        let mut others: Vec<String> = groups
+            .keys()
+            .filter(|key| !key.is_empty() && !column_order.contains(key))
+            .cloned()
+            .collect();
+        others.sort();
+        column_order.extend(others);
+
+        if groups.contains_key("") {
+            column_order.push(String::new());
+        }
+
+        Ok(column_order
+            .into_iter()
+            .filter_map(|column| groups.remove(&column).map(|cards| (column, cards)))
+            .collect())
+    }
+
+    // Get the cards of a view with its filter and sorting applied
+    pub fn get_view_cards(&self, view_name: &str) -> Result<Vec<Rc<RefCell<KanbanCard>>>> {
+        let view_rc = self
+            .view_by_name
+            .get(view_name)
+            .ok_or_else(|| anyhow!("View not found: {view_name}"))?;
+
+        let view = view_rc.borrow();
+        let mut cards = view.filtered_cards(self);
+        view.sort_cards(self, &mut cards, None);
+
+        Ok(cards)
+    }
+
+    // Properties rendered for each card of a view: its display properties, or
+    // every property when it lists none. Boards already show the grouping as
+    // columns, so `hide_grouping` leaves it out there.
+    pub(crate) fn shown_properties(
+        &self,
+        view: &KanbanView,
+        hide_grouping: bool,
+    ) -> Vec<Rc<RefCell<KanbanProperty>>> {
+        if !view.display.is_empty() {
+            return view.display.clone();
+        }
+
+        let group_by = match &view.view_layout {
+            KanbanViewType::Board { group_by } if hide_grouping => group_by.as_ref(),
+            _ => None,
+        };
+        self.properties
+            .iter()
+            .filter(|p| !group_by.is_some_and(|g| Rc::ptr_eq(p, g)))
+            .cloned()
+            .collect()
+    }
+
+    // A card's value for a property as rendered in exports, including
+    // formula results. Empty values count as missing.
+    pub(crate) fn card_value<'a>(
+        &self,
+        card: &'a KanbanCard,
+        prop_rc: &Rc<RefCell<KanbanProperty>>,
+    ) -> Option<Cow<'a, str>> {
+        self.field_value(card, &prop_rc.borrow().name)
+            .filter(|value| !value.is_empty())
+    }
+
+    // Values of the defaults for properties a new card leaves out. Fails when
+    // a required property is left out and has no default.
+    pub(crate) fn default_values(
+        &self,
+        property_values: &HashMap<String, String>,
+    ) -> Result<Vec<(Rc<RefCell<KanbanProperty>>, String)>> {
+        let mut defaults = Vec::new();
+
+        for prop_rc in &self.properties {
+            let prop = prop_rc.borrow();
+            match property_values.get(&prop.name) {
+                Some(value) if !value.is_empty() => {}
+                None if prop.rules.default.is_some() => {
+                    let default = prop.rules.default.clone().unwrap_or_default();
+                    prop.validate_value(&default)?;
+                    defaults.push((Rc::clone(prop_rc), default));
                 }
+                _ if prop.rules.required => bail!("Missing required property: {}", prop.name),
+                _ => {}
             }
         }
 
-        Ok(result)
+        Ok(defaults)
     }
 
     // Add a new card
@@ -414,29 +789,27 @@ impl Kanban {
                 .ok_or_else(|| anyhow!("Unknown property: {}", prop_name))?;
 
             // Validate property value
-            let prop = prop_rc.borrow();
-            match &prop.property_type {
-                KanbanPropertyType::Select { options } => {
-                    if !options.contains(value) {
-                        return Err(anyhow!("Invalid value for {}: {}", prop_name, value));
-                    }
-                }
-                KanbanPropertyType::Checkbox => {
-                    if value != "true" && value != "false" {
-                        return Err(anyhow!(
-                            "Invalid value for checkbox {}: {}",
-                            prop_name,
-                            value
-                        ));
-                    }
-                }
-                // More validation could be added for other types
-                _ => {}
-            }
+            prop_rc.borrow().validate_value(value)?;
 
             card_properties.push((Rc::clone(prop_rc), value.clone()));
         }
 
+        card_properties.extend(self.default_values(&property_values)?);
+
+        if let Some(parent) = property_values.get(PARENT_PROPERTY)
+            && !parent.trim().is_empty()
+        {
+            self.check_parent(&title, parent.trim())?;
+        }
+
+        // Keep values in declaration order
+        card_properties.sort_by_key(|(prop_rc, _)| {
+            self.properties
+                .iter()
+                .position(|p| Rc::ptr_eq(p, prop_rc))
+                .unwrap_or(usize::MAX)
+        });
+
         // Generate a new ID for the card (next available ID)
         let new_id = self.cards.iter().map(|c| c.borrow().id).max().unwrap_or(0) + 1;
 
@@ -446,6 +819,7 @@ impl Kanban {
             title: title.clone(),
             description,
             properties: card_properties,
+            subtasks: Vec::new(),
             views: Vec::new(),
         }));
 
@@ -471,6 +845,171 @@ impl Kanban {
         Ok(card_rc)
     }
 
+    // Remove a card from the board, its views and its properties
+    pub fn remove_card(&mut self, card_title: &str) -> Result<Rc<RefCell<KanbanCard>>> {
+        let card_rc = self
+            .card_by_title
+            .remove(card_title)
+            .ok_or_else(|| anyhow!("Card not found: {card_title}"))?;
+
+        let card_id = card_rc.borrow().id;
+        self.cards.retain(|c| !Rc::ptr_eq(c, &card_rc));
+        self.card_by_id.remove(&card_id);
+
+        for view_rc in &self.views {
+            let mut view = view_rc.borrow_mut();
+            view.cards.retain(|c| !Rc::ptr_eq(c, &card_rc));
+            for order in view.column_sorts.values_mut() {
+                order.retain(|&id| id != card_id);
+            }
+        }
+
+        for prop_rc in &self.properties {
+            prop_rc
+                .borrow_mut()
+                .cards
+                .retain(|c| c.upgrade().is_some_and(|c| !Rc::ptr_eq(&c, &card_rc)));
+        }
+
+        // Other cards stop linking to the removed one
+        self.rewrite_links(card_title, None);
+
+        Ok(card_rc)
+    }
+
+    // Point every Parent, Blocks and Blocked by link naming `old_title` at
+    // `new_title`, or drop the link when there is none. Properties left
+    // without any link are removed from the card.
+    fn rewrite_links(&self, old_title: &str, new_title: Option<&str>) {
+        for card_rc in &self.cards {
+            let mut card = card_rc.borrow_mut();
+            let mut emptied = Vec::new();
+
+            for (prop_rc, value) in &mut card.properties {
+                let name = prop_rc.borrow().name.clone();
+                if ![PARENT_PROPERTY, BLOCKS_PROPERTY, BLOCKED_BY_PROPERTY].contains(&name.as_str())
+                {
+                    continue;
+                }
+
+                let titles: Vec<&str> = value.split(',').map(str::trim).collect();
+                if !titles.contains(&old_title) {
+                    continue;
+                }
+                let rewritten: Vec<&str> = titles
+                    .into_iter()
+                    .filter_map(|title| {
+                        if title == old_title {
+                            new_title
+                        } else {
+                            Some(title)
+                        }
+                    })
+                    .filter(|title| !title.is_empty())
+                    .collect();
+
+                *value = rewritten.join(", ");
+                if value.is_empty() {
+                    emptied.push(Rc::clone(prop_rc));
+                }
+            }
+
+            for prop_rc in emptied {
+                card.properties.retain(|(p, _)| !Rc::ptr_eq(p, &prop_rc));
+                prop_rc
+                    .borrow_mut()
+                    .cards
+                    .retain(|c| c.upgrade().is_some_and(|c| !Rc::ptr_eq(&c, card_rc)));
+            }
+        }
+    }
+
+    // Update a card's title, description and property values. An empty value
+    // removes the property from the card.
+    pub fn edit_card(
+        &mut self,
+        card_title: &str,
+        new_title: Option<String>,
+        description: Option<String>,
+        property_values: HashMap<String, String>,
+    ) -> Result<()> {
+        let card_rc = self
+            .card_by_title
+            .get(card_title)
+            .cloned()
+            .ok_or_else(|| anyhow!("Card not found: {card_title}"))?;
+
+        if let Some(new_title) = &new_title
+            && new_title != card_title
+            && self.card_by_title.contains_key(new_title)
+        {
+            bail!("A card named '{new_title}' already exists");
+        }
+
+        // Validate everything before changing anything
+        let mut updates = Vec::new();
+        for (prop_name, value) in property_values {
+            let prop_rc = self
+                .property_by_name
+                .get(&prop_name)
+                .ok_or_else(|| anyhow!("Unknown property: {prop_name}"))?;
+
+            if !value.is_empty() {
+                prop_rc.borrow().validate_value(&value)?;
+            } else if prop_rc.borrow().rules.required {
+                bail!("{prop_name} is required and cannot be removed");
+            }
+            if prop_name == PARENT_PROPERTY && !value.trim().is_empty() {
+                self.check_parent(card_title, value.trim())?;
+            }
+            updates.push((Rc::clone(prop_rc), value));
+        }
+
+        // Parent and dependency links follow the card to its new title
+        if let Some(new_title) = &new_title
+            && new_title != card_title
+        {
+            self.rewrite_links(card_title, Some(new_title));
+        }
+
+        let mut card = card_rc.borrow_mut();
+
+        for (prop_rc, value) in updates {
+            let existing = card
+                .properties
+                .iter()
+                .position(|(p, _)| Rc::ptr_eq(p, &prop_rc));
+
+            match (existing, value.is_empty()) {
+                (Some(idx), true) => {
+                    card.properties.remove(idx);
+                    prop_rc
+                        .borrow_mut()
+                        .cards
+                        .retain(|c| c.upgrade().is_some_and(|c| !Rc::ptr_eq(&c, &card_rc)));
+                }
+                (Some(idx), false) => card.properties[idx].1 = value,
+                (None, true) => {}
+                (None, false) => {
+                    prop_rc.borrow_mut().cards.push(Rc::downgrade(&card_rc));
+                    card.properties.push((prop_rc, value));
+                }
+            }
+        }
+
+        if let Some(description) = description {
+            card.description = description;
+        }
+
+        if let Some(new_title) = new_title {
+            self.card_by_title.remove(card_title);
+            card.title = new_title.clone();
+            self.card_by_title.insert(new_title, Rc::clone(&card_rc));
+        }
+
+        Ok(())
+    }
+
     // Move a card to a different group (column in board view)
     pub fn move_card(
         &self,
@@ -500,10 +1039,13 @@ impl Kanban {
 
         // Validate the new group value
         let prop = group_by_prop.borrow();
-        if let KanbanPropertyType::Select { options } = &prop.property_type {
-            if !options.contains(&new_group_value.to_string()) {
-                return Err(anyhow!("Invalid group value: {new_group_value}"));
-            }
+        if let KanbanPropertyType::Select { options } = &prop.property_type
+            && !options.contains(&new_group_value.to_string())
+        {
+            return Err(anyhow!("Invalid group value: {new_group_value}"));
+        }
+        if let KanbanPropertyType::Formula { .. } = &prop.property_type {
+            bail!("Cannot move cards in view '{view_name}', its groups come from a formula");
         }
 
         // Update the card's property value
@@ -530,6 +1072,58 @@ impl Kanban {
         Ok(())
     }
 
+    // Add an unchecked item to the end of a card's checklist
+    pub fn add_subtask(&self, card_title: &str, text: &str) -> Result<()> {
+        let card_rc = self
+            .card_by_title
+            .get(card_title)
+            .ok_or_else(|| anyhow!("Card not found: {card_title}"))?;
+
+        let text = text.trim();
+        if text.is_empty() || text.contains(['\n', '\r']) {
+            bail!("Subtask text must be a single non-empty line");
+        }
+
+        card_rc.borrow_mut().subtasks.push(Subtask {
+            text: text.to_string(),
+            done: false,
+        });
+        Ok(())
+    }
+
+    // Check or uncheck a card's subtask by position, returning whether it is
+    // now done
+    pub fn toggle_subtask(&self, card_title: &str, index: usize) -> Result<bool> {
+        let card_rc = self
+            .card_by_title
+            .get(card_title)
+            .ok_or_else(|| anyhow!("Card not found: {card_title}"))?;
+
+        let mut card = card_rc.borrow_mut();
+        let subtask = card
+            .subtasks
+            .get_mut(index)
+            .ok_or_else(|| anyhow!("Card '{card_title}' has no subtask {index}"))?;
+        subtask.done = !subtask.done;
+
+        Ok(subtask.done)
+    }
+
+    // Remove a card's subtask by position
+    pub fn remove_subtask(&self, card_title: &str, index: usize) -> Result<Subtask> {
+        let card_rc = self
+            .card_by_title
+            .get(card_title)
+            .ok_or_else(|| anyhow!("Card not found: {card_title}"))?;
+
+        let mut card = card_rc.borrow_mut();
+        if index >= card.subtasks.len() {
+            bail!("Card '{card_title}' has no subtask {index}");
+        }
+
+        Ok(card.subtasks.remove(index))
+    }
+
     // Move a card using ID instead of title
     pub fn move_card_by_id(
         &self,
@@ -551,6 +1145,16 @@ impl Kanban {
         self.views.clone()
     }
 
+    // The view to open first: the one named in the metadata, else the first
+    pub fn default_view(&self) -> Option<Rc<RefCell<KanbanView>>> {
+        self.metadata
+            .default_view
+            .as_ref()
+            .and_then(|name| self.view_by_name.get(name))
+            .or_else(|| self.views.first())
+            .cloned()
+    }
+
     // Get all properties
     pub fn get_properties(&self) -> Vec<Rc<RefCell<KanbanProperty>>> {
         self.properties.clone()
@@ -572,17 +1176,30 @@ impl Kanban {
                 KanbanPropertyType::Select { options } => ParsedPropertyType::Select {
                     options: options.clone(),
                 },
+                KanbanPropertyType::Formula { formula } => ParsedPropertyType::Formula {
+                    expression: formula.source().to_string(),
+                },
             };
 
             parsed_properties.push(ParsedProperty {
                 name: prop.name.clone(),
                 property_type,
+                rules: prop.rules.clone(),
             });
         }
 
+        // Card IDs are positions in the document, so renumber them and remap
+        // manual orderings to match
+        let new_ids: HashMap<usize, usize> = self
+            .cards
+            .iter()
+            .enumerate()
+            .map(|(idx, card_rc)| (card_rc.borrow().id, idx))
+            .collect();
+
         let mut parsed_cards = Vec::new();
 
-        for card_rc in &self.cards {
+        for (idx, card_rc) in self.cards.iter().enumerate() {
             let card = card_rc.borrow();
 
             let mut property_values = Vec::new();
@@ -595,10 +1212,11 @@ impl Kanban {
             }
 
             parsed_cards.push(ParsedCard {
-                id: card.id,
+                id: idx,
                 title: card.title.clone(),
                 description: card.description.clone(),
                 properties: property_values,
+                subtasks: card.subtasks.clone(),
             });
         }
 
@@ -631,35 +1249,59 @@ impl Kanban {
                 ),
             };
 
-            // Convert column_sorts from HashMap to Vec<ColumnSort>
-            let column_sorts = view
+            // Convert column_sorts from HashMap to Vec<ColumnSort>, in column
+            // order so the output is stable
+            let options = match &view.view_layout {
+                KanbanViewType::Board {
+                    group_by: Some(prop_rc),
+                } => match &prop_rc.borrow().property_type {
+                    KanbanPropertyType::Select { options } => options.clone(),
+                    _ => Vec::new(),
+                },
+                _ => Vec::new(),
+            };
+            let mut column_sorts: Vec<ColumnSort> = view
                 .column_sorts
                 .iter()
                 .map(|(column, order)| ColumnSort {
                     column: column.clone(),
-                    order: order.clone(),
+                    order: order
+                        .iter()
+                        .filter_map(|id| new_ids.get(id).copied())
+                        .collect(),
                 })
                 .collect();
+            column_sorts.sort_by_key(|sort| {
+                let position = options.iter().position(|o| *o == sort.column);
+                (position.unwrap_or(usize::MAX), sort.column.clone())
+            });
 
             parsed_views.push(ParsedView {
                 name: view.name.clone(),
                 layout: view_type,
                 group,
                 filter: view.filter.clone(),
-                sort_by: sort_by.or_else(|| view.sort_by.as_ref().map(|p| p.borrow().name.clone())),
+                sort_by: sort_by
+                    .or_else(|| view.sort_by.as_ref().map(|p| p.borrow().name.clone()))
+                    .or_else(|| view.sort_by_computed.clone()),
                 sort_type: view.sort_type.clone(),
                 column_sorts,
-                display: Some(
+                display: (!view.display.is_empty()).then(|| {
                     view.display
                         .iter()
                         .map(|p| p.borrow().name.clone())
                         .collect::<Vec<_>>()
-                        .join(", "),
-                ),
+                        .join(", ")
+                }),
             });
         }
 
         ParsedDocument {
+            // Boards are always written in the current format
+            metadata: BoardMetadata {
+                version: Some(FORMAT_VERSION),
+                ..self.metadata.clone()
+            },
             properties: parsed_properties,
             cards: parsed_cards,
             views: parsed_views,
@@ -0,0 +1,105 @@
+// crates/kandown/src/keywords.rs
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+use crate::DocumentSection;
+
+/// The words the markdown format is written with: section headings, view
+/// attributes and property settings. Boards pick a built-in locale with
+/// `locale` in their front matter; other sets of words can be passed to
+/// [`ParsedDocument::parse_complete_with`](crate::ParsedDocument::parse_complete_with)
+/// and [`ParsedDocument::to_markdown_with`](crate::ParsedDocument::to_markdown_with).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParserConfig {
+    pub properties: String,
+    pub views: String,
+    pub cards: String,
+
+    pub layout: String,
+    pub group: String,
+    pub filter: String,
+    pub sort: String,
+    pub sort_type: String,
+    pub display: String,
+
+    pub default: String,
+    pub required: String,
+    pub min: String,
+    pub max: String,
+    pub pattern: String,
+}
+
+// Locale codes with built-in keywords
+pub const LOCALES: &[&str] = &["en", "de"];
+
+impl ParserConfig {
+    pub fn english() -> Self {
+        ParserConfig {
+            properties: "Properties".to_string(),
+            views: "Views".to_string(),
+            cards: "Cards".to_string(),
+            layout: "Layout".to_string(),
+            group: "Group".to_string(),
+            filter: "Filter".to_string(),
+            sort: "Sort".to_string(),
+            sort_type: "Sort Type".to_string(),
+            display: "Display".to_string(),
+            default: "Default".to_string(),
+            required: "Required".to_string(),
+            min: "Min".to_string(),
+            max: "Max".to_string(),
+            pattern: "Pattern".to_string(),
+        }
+    }
+
+    pub fn german() -> Self {
+        ParserConfig {
+            properties: "Eigenschaften".to_string(),
+            views: "Ansichten".to_string(),
+            cards: "Karten".to_string(),
+            layout: "Layout".to_string(),
+            group: "Gruppe".to_string(),
+            filter: "Filter".to_string(),
+            sort: "Sortierung".to_string(),
+            sort_type: "Sortierart".to_string(),
+            display: "Anzeige".to_string(),
+            default: "Standard".to_string(),
+            required: "Pflicht".to_string(),
+            min: "Min".to_string(),
+            max: "Max".to_string(),
+            pattern: "Muster".to_string(),
+        }
+    }
+
+    // The built-in keywords for a locale code such as "de"
+    pub fn for_locale(locale: &str) -> Result<Self> {
+        match locale {
+            "en" => Ok(ParserConfig::english()),
+            "de" => Ok(ParserConfig::german()),
+            _ => Err(anyhow!(
+                "Unknown locale: {locale} (expected one of: {})",
+                LOCALES.join(", ")
+            )),
+        }
+    }
+
+    // The section a heading names with these keywords
+    pub(crate) fn section_for_heading(&self, heading: &str) -> Option<DocumentSection> {
+        if heading == self.properties {
+            Some(DocumentSection::Properties)
+        } else if heading == self.views {
+            Some(DocumentSection::Views)
+        } else if heading == self.cards {
+            Some(DocumentSection::Cards)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for ParserConfig {
+    fn default() -> Self {
+        ParserConfig::english()
+    }
+}
@@ -6,6 +6,42 @@ mod kanban;
 pub use kanban::*;
 mod parsed_document;
 pub use parsed_document::*;
+mod exchange;
+pub use exchange::*;
+mod filter;
+pub use filter::*;
+mod formula;
+pub use formula::*;
+mod csv_io;
+pub use csv_io::*;
+mod importers;
+pub use importers::*;
+mod diagnostic;
+mod gfm;
+mod html;
+mod keywords;
+pub use diagnostic::*;
+pub use keywords::*;
+mod lint;
+pub use lint::*;
+mod file;
+pub use file::*;
+mod history;
+pub use history::*;
+mod diff;
+pub use diff::*;
+mod merge;
+pub use merge::*;
+mod migrate;
+pub use migrate::*;
+mod sync;
+pub use sync::*;
+mod dependencies;
+pub use dependencies::*;
+mod hierarchy;
+pub use hierarchy::*;
+mod workspace;
+pub use workspace::*;
 
 #[cfg(test)]
 mod test;
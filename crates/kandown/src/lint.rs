@@ -0,0 +1,352 @@
+// crates/kandown/src/lint.rs
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    COMPUTED_PROPERTIES, Diagnostic, DocumentSection, Formula, KanbanSortType, ParsedDocument,
+    ParsedProperty, ParsedPropertyType, ParsedViewType, ParserConfig, PropertyRules, ViewFilter,
+    locate_diagnostics_with, normalize_date,
+};
+
+impl ParsedDocument {
+    // Check a parsed board for problems that parsing alone does not catch.
+    // Boards in older formats are checked as they load, after the upgrade,
+    // with each change the upgrade makes as a warning.
+    pub fn lint(&self) -> Vec<Diagnostic> {
+        let mut upgraded = self.clone();
+        let mut diagnostics = match upgraded.migrate() {
+            Ok(report) => report.changes,
+            Err(e) => vec![Diagnostic::error("unsupported-version", e.to_string())],
+        };
+
+        upgraded.lint_upgraded(&mut diagnostics);
+        diagnostics
+    }
+
+    fn lint_upgraded(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let properties: HashMap<&str, &ParsedPropertyType> = self
+            .properties
+            .iter()
+            .map(|p| (p.name.as_str(), &p.property_type))
+            .collect();
+
+        let mut seen = HashSet::new();
+        for (idx, prop) in self.properties.iter().enumerate() {
+            if !seen.insert(prop.name.as_str()) {
+                diagnostics.push(
+                    Diagnostic::error(
+                        "duplicate-property",
+                        format!("Property '{}' is declared more than once", prop.name),
+                    )
+                    .at(DocumentSection::Properties, idx, &prop.name),
+                );
+            }
+
+            if let ParsedPropertyType::Formula { expression } = &prop.property_type
+                && let Err(e) = Formula::parse(expression)
+            {
+                diagnostics.push(
+                    Diagnostic::error(
+                        "invalid-formula",
+                        format!("Property '{}' has an invalid formula: {e}", prop.name),
+                    )
+                    .at(DocumentSection::Properties, idx, &prop.name),
+                );
+            }
+
+            if let Some(problem) = lint_rules(prop) {
+                diagnostics.push(problem.at(DocumentSection::Properties, idx, &prop.name));
+            }
+        }
+
+        if let Some(view) = &self.metadata.default_view
+            && !self.views.iter().any(|v| &v.name == view)
+        {
+            diagnostics.push(Diagnostic::warning(
+                "unknown-default-view",
+                format!("The default view '{view}' does not exist"),
+            ));
+        }
+
+        self.lint_views(&properties, diagnostics);
+        self.lint_cards(&properties, diagnostics);
+        self.lint_dependencies(diagnostics);
+        self.lint_hierarchy(diagnostics);
+    }
+
+    fn lint_views(
+        &self,
+        properties: &HashMap<&str, &ParsedPropertyType>,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let mut seen = HashSet::new();
+
+        for (idx, view) in self.views.iter().enumerate() {
+            let mut report = |diagnostic: Diagnostic| {
+                diagnostics.push(diagnostic.at(DocumentSection::Views, idx, &view.name));
+            };
+            let name = &view.name;
+
+            if !seen.insert(name.as_str()) {
+                report(Diagnostic::error(
+                    "duplicate-view",
+                    format!("View '{name}' is declared more than once"),
+                ));
+            }
+
+            if let Some(group) = &view.group
+                && !properties.contains_key(group.as_str())
+            {
+                report(Diagnostic::error(
+                    "unknown-view-property",
+                    format!("View '{name}' groups by unknown property '{group}'"),
+                ));
+            }
+
+            if let Some(sort_by) = &view.sort_by
+                && !properties.contains_key(sort_by.as_str())
+                && !COMPUTED_PROPERTIES.contains(&sort_by.as_str())
+            {
+                let severity = if view.layout == ParsedViewType::Table {
+                    Diagnostic::error
+                } else {
+                    Diagnostic::warning
+                };
+                report(severity(
+                    "unknown-view-property",
+                    format!("View '{name}' sorts by unknown property '{sort_by}'"),
+                ));
+            }
+
+            for prop_name in display_names(view.display.as_deref()) {
+                if !properties.contains_key(prop_name) {
+                    report(Diagnostic::warning(
+                        "unknown-display-property",
+                        format!("View '{name}' displays unknown property '{prop_name}'"),
+                    ));
+                }
+            }
+
+            if let Some(filter) = &view.filter
+                && let Err(e) = ViewFilter::parse(filter)
+            {
+                report(Diagnostic::error(
+                    "invalid-filter",
+                    format!("View '{name}' has an invalid filter: {e}"),
+                ));
+            }
+
+            if view.sort_type != KanbanSortType::Manual {
+                continue;
+            }
+
+            // Manual orderings must name columns of the grouping and real cards
+            let columns = match view.group.as_deref().and_then(|g| properties.get(g)) {
+                Some(ParsedPropertyType::Select { options }) => Some(options),
+                _ => None,
+            };
+            for sort in &view.column_sorts {
+                if let Some(options) = columns
+                    && !options.contains(&sort.column)
+                {
+                    report(Diagnostic::warning(
+                        "unknown-sort-column",
+                        format!(
+                            "View '{name}' orders column '{}', which is not an option of '{}'",
+                            sort.column,
+                            view.group.as_deref().unwrap_or_default()
+                        ),
+                    ));
+                }
+
+                for id in &sort.order {
+                    if *id >= self.cards.len() {
+                        report(Diagnostic::warning(
+                            "unknown-sort-card",
+                            format!(
+                                "View '{name}' orders card {id} in column '{}', but there is no such card",
+                                sort.column
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    fn lint_cards(
+        &self,
+        properties: &HashMap<&str, &ParsedPropertyType>,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let mut seen = HashSet::new();
+        // Properties with a broken pattern are reported once, not on every card
+        let rules: HashMap<&str, &PropertyRules> = self
+            .properties
+            .iter()
+            .filter(|p| p.rules.pattern.is_none() || p.rules.pattern_regex().is_ok())
+            .map(|p| (p.name.as_str(), &p.rules))
+            .collect();
+
+        for (idx, card) in self.cards.iter().enumerate() {
+            let mut report = |diagnostic: Diagnostic| {
+                diagnostics.push(diagnostic.at(DocumentSection::Cards, idx, &card.title));
+            };
+            let title = &card.title;
+
+            // Later cards replace earlier ones when looked up by title
+            if !seen.insert(title.as_str()) {
+                report(Diagnostic::error(
+                    "duplicate-card-title",
+                    format!("Card title '{title}' is used more than once"),
+                ));
+            }
+
+            for value in &card.properties {
+                let prop_name = &value.property_name;
+                let value = value.value.as_str();
+
+                let Some(property_type) = properties.get(prop_name.as_str()) else {
+                    report(Diagnostic::error(
+                        "unknown-property",
+                        format!("Card '{title}' uses undeclared property '{prop_name}'"),
+                    ));
+                    continue;
+                };
+
+                if value.is_empty() {
+                    report(Diagnostic::warning(
+                        "empty-value",
+                        format!("Card '{title}' has an empty value for '{prop_name}'"),
+                    ));
+                    continue;
+                }
+
+                let problem = match property_type {
+                    ParsedPropertyType::Formula { .. } => Some(Diagnostic::warning(
+                        "formula-value",
+                        format!(
+                            "Card '{title}' sets '{prop_name}', which is computed by a formula"
+                        ),
+                    )),
+                    ParsedPropertyType::Select { options }
+                        if !options.iter().any(|o| o == value) =>
+                    {
+                        Some(Diagnostic::error(
+                            "invalid-value",
+                            format!(
+                                "Card '{title}' has '{value}' for '{prop_name}', expected one of: {}",
+                                options.join(", ")
+                            ),
+                        ))
+                    }
+                    ParsedPropertyType::Checkbox if value != "true" && value != "false" => {
+                        Some(Diagnostic::error(
+                            "invalid-value",
+                            format!(
+                                "Card '{title}' has '{value}' for checkbox '{prop_name}', expected true or false"
+                            ),
+                        ))
+                    }
+                    ParsedPropertyType::Number if value.parse::<f64>().is_err() => {
+                        Some(Diagnostic::warning(
+                            "invalid-value",
+                            format!("Card '{title}' has non-numeric '{value}' for '{prop_name}'"),
+                        ))
+                    }
+                    ParsedPropertyType::Date if normalize_date(value).as_deref() != Some(value) => {
+                        Some(Diagnostic::warning(
+                            "invalid-value",
+                            format!(
+                                "Card '{title}' has '{value}' for date '{prop_name}', expected YYYY-MM-DD"
+                            ),
+                        ))
+                    }
+                    _ => rules.get(prop_name.as_str()).and_then(|rules| {
+                        let e = rules.check(prop_name, value).err()?;
+                        Some(Diagnostic::error(
+                            "invalid-value",
+                            format!("Card '{title}' has an invalid value: {e}"),
+                        ))
+                    }),
+                };
+
+                if let Some(problem) = problem {
+                    report(problem);
+                }
+            }
+
+            for prop in self.properties.iter().filter(|p| p.rules.required) {
+                let name = &prop.name;
+                if !card
+                    .properties
+                    .iter()
+                    .any(|v| &v.property_name == name && !v.value.is_empty())
+                {
+                    report(Diagnostic::error(
+                        "missing-required",
+                        format!("Card '{title}' is missing required property '{name}'"),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+// Check that a property's pattern compiles and its default is a valid value
+fn lint_rules(prop: &ParsedProperty) -> Option<Diagnostic> {
+    let name = &prop.name;
+    if prop.rules.pattern.is_some()
+        && let Err(e) = prop.rules.pattern_regex()
+    {
+        return Some(Diagnostic::error(
+            "invalid-pattern",
+            format!("Property '{name}' has an invalid pattern: {e}"),
+        ));
+    }
+
+    let default = prop.rules.default.as_deref()?;
+    let problem = match &prop.property_type {
+        ParsedPropertyType::Formula { .. } => Some("formulas cannot have a default".to_string()),
+        ParsedPropertyType::Select { options } if !options.iter().any(|o| o == default) => {
+            Some(format!("expected one of: {}", options.join(", ")))
+        }
+        ParsedPropertyType::Checkbox if default != "true" && default != "false" => {
+            Some("expected true or false".to_string())
+        }
+        _ => prop.rules.check(name, default).err().map(|e| e.to_string()),
+    };
+
+    problem.map(|problem| {
+        Diagnostic::error(
+            "invalid-default",
+            format!("Property '{name}' has an invalid default '{default}': {problem}"),
+        )
+    })
+}
+
+// Lint markdown source, reporting parse failures as diagnostics and filling in
+// source lines
+pub fn lint_markdown(source: &str) -> Vec<Diagnostic> {
+    lint_markdown_with(source, &ParserConfig::default())
+}
+
+// Lint markdown source written with the given keywords
+pub fn lint_markdown_with(source: &str, config: &ParserConfig) -> Vec<Diagnostic> {
+    let doc = match ParsedDocument::parse_complete_with(source, config) {
+        Ok(doc) => doc,
+        Err(e) => return vec![Diagnostic::error("parse-error", e.to_string())],
+    };
+
+    let mut diagnostics = doc.lint();
+    locate_diagnostics_with(source, config, &mut diagnostics);
+    diagnostics
+}
+
+fn display_names(display: Option<&str>) -> impl Iterator<Item = &str> {
+    display
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
@@ -0,0 +1,517 @@
+// crates/kandown/src/merge.rs
+
+use anyhow::{Context, Result, bail};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::{
+    ColumnSort, Diagnostic, DocumentSection, ParsedCard, ParsedDocument, ParsedPropertyValue,
+    ParsedView, Subtask, locate_diagnostics,
+};
+
+/// Outcome of a three-way merge. Where both sides changed the same thing
+/// differently, the document keeps our side and a conflict is reported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeResult {
+    pub document: ParsedDocument,
+    pub conflicts: Vec<Diagnostic>,
+}
+
+// What a card holds, independent of its position in the file
+#[derive(Debug, Clone, PartialEq)]
+struct CardContent {
+    description: String,
+    properties: BTreeMap<String, String>,
+    subtasks: Vec<Subtask>,
+}
+
+// Manual column orders of a view, by card title
+type ViewOrders = BTreeMap<String, Vec<String>>;
+
+// The three versions of one item
+struct Versions<'a, T> {
+    base: Option<&'a T>,
+    ours: Option<&'a T>,
+    theirs: Option<&'a T>,
+}
+
+impl<T: PartialEq + Clone> Versions<'_, T> {
+    // The merged item (None if deleted), or Err if both sides changed it
+    // differently
+    fn merge(&self) -> Result<Option<T>, ()> {
+        if self.ours == self.theirs || self.theirs == self.base {
+            Ok(self.ours.cloned())
+        } else if self.ours == self.base {
+            Ok(self.theirs.cloned())
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl ParsedDocument {
+    /// Merge the changes `ours` and `theirs` made to `base`. Properties and
+    /// views are matched by name and merged whole; cards are matched by title
+    /// and merged field by field; manual column orders are merged per column.
+    pub fn merge(
+        base: &ParsedDocument,
+        ours: &ParsedDocument,
+        theirs: &ParsedDocument,
+    ) -> Result<MergeResult> {
+        for (side, doc) in [("base", base), ("ours", ours), ("theirs", theirs)] {
+            let mut seen = HashSet::new();
+            if let Some(card) = doc.cards.iter().find(|c| !seen.insert(&c.title)) {
+                bail!(
+                    "Cannot merge: card title '{}' is used twice in {side}",
+                    card.title
+                );
+            }
+        }
+
+        let mut conflicts = Vec::new();
+
+        // Board metadata is merged whole, like a property
+        let versions = Versions {
+            base: Some(&base.metadata),
+            ours: Some(&ours.metadata),
+            theirs: Some(&theirs.metadata),
+        };
+        let (metadata, metadata_conflict) = match versions.merge() {
+            Ok(metadata) => (metadata.unwrap_or_default(), false),
+            Err(()) => (ours.metadata.clone(), true),
+        };
+
+        let properties = merge_items(
+            [base, ours, theirs].map(|doc| {
+                doc.properties
+                    .iter()
+                    .map(|p| (p.name.clone(), p.clone()))
+                    .collect()
+            }),
+            |name, _| {
+                conflicts.push((
+                    DocumentSection::Properties,
+                    name.to_string(),
+                    format!("Property '{name}' was changed differently on both sides"),
+                ));
+                None
+            },
+        );
+
+        let cards = merge_items(
+            [base, ours, theirs].map(|doc| {
+                doc.cards
+                    .iter()
+                    .map(|c| (c.title.clone(), card_content(c)))
+                    .collect()
+            }),
+            |title, versions| {
+                let (content, messages) = merge_card(title, versions);
+                for message in messages {
+                    conflicts.push((DocumentSection::Cards, title.to_string(), message));
+                }
+                Some(content)
+            },
+        );
+
+        let views = merge_items(
+            [base, ours, theirs].map(|doc| {
+                doc.views
+                    .iter()
+                    .map(|v| (v.name.clone(), view_content(v, doc)))
+                    .collect()
+            }),
+            |name, versions| match (versions.ours, versions.theirs) {
+                (Some(ours), Some(theirs)) => {
+                    let (view, messages) = merge_view(name, versions.base, ours, theirs);
+                    for message in messages {
+                        conflicts.push((DocumentSection::Views, name.to_string(), message));
+                    }
+                    Some(view)
+                }
+                _ => {
+                    conflicts.push((
+                        DocumentSection::Views,
+                        name.to_string(),
+                        format!("View '{name}' was deleted on one side and changed on the other"),
+                    ));
+                    versions.ours.or(versions.theirs).cloned()
+                }
+            },
+        );
+
+        let mut document = ParsedDocument {
+            metadata,
+            properties: properties.into_iter().map(|(_, p)| p).collect(),
+            cards: cards
+                .into_iter()
+                .enumerate()
+                .map(|(id, (title, content))| ParsedCard {
+                    id,
+                    title,
+                    description: content.description,
+                    subtasks: content.subtasks,
+                    properties: content
+                        .properties
+                        .into_iter()
+                        .map(|(property_name, value)| ParsedPropertyValue {
+                            property_name,
+                            value,
+                        })
+                        .collect(),
+                })
+                .collect(),
+            views: Vec::new(),
+        };
+
+        let positions: HashMap<&str, usize> = document
+            .cards
+            .iter()
+            .map(|card| (card.title.as_str(), card.id))
+            .collect();
+        let views = views
+            .into_iter()
+            .map(|(_, (mut view, orders))| {
+                view.column_sorts = orders
+                    .into_iter()
+                    .map(|(column, titles)| ColumnSort {
+                        column,
+                        order: titles
+                            .iter()
+                            .filter_map(|title| positions.get(title.as_str()).copied())
+                            .collect(),
+                    })
+                    .filter(|sort| !sort.order.is_empty())
+                    .collect();
+                view
+            })
+            .collect();
+        document.views = views;
+        document.normalize();
+
+        let mut conflicts: Vec<Diagnostic> = conflicts
+            .into_iter()
+            .map(|(section, name, message)| {
+                let index = match section {
+                    DocumentSection::Properties => {
+                        document.properties.iter().position(|p| p.name == name)
+                    }
+                    DocumentSection::Views => document.views.iter().position(|v| v.name == name),
+                    DocumentSection::Cards => document.cards.iter().position(|c| c.title == name),
+                };
+                let diagnostic = Diagnostic::error("merge-conflict", message);
+                match index {
+                    Some(index) => diagnostic.at(section, index, &name),
+                    None => diagnostic,
+                }
+            })
+            .collect();
+        if metadata_conflict {
+            conflicts.insert(
+                0,
+                Diagnostic::error(
+                    "merge-conflict",
+                    "Board metadata was changed differently on both sides",
+                ),
+            );
+        }
+
+        Ok(MergeResult {
+            document,
+            conflicts,
+        })
+    }
+}
+
+// Merge markdown boards, returning the merged markdown and conflicts with
+// their lines in it
+pub fn merge_markdown(base: &str, ours: &str, theirs: &str) -> Result<(String, Vec<Diagnostic>)> {
+    let base = ParsedDocument::parse_complete(base).context("Failed to parse base")?;
+    let ours = ParsedDocument::parse_complete(ours).context("Failed to parse ours")?;
+    let theirs = ParsedDocument::parse_complete(theirs).context("Failed to parse theirs")?;
+
+    let MergeResult {
+        document,
+        mut conflicts,
+    } = ParsedDocument::merge(&base, &ours, &theirs)?;
+    let markdown = document.to_string();
+    locate_diagnostics(&markdown, &mut conflicts);
+
+    Ok((markdown, conflicts))
+}
+
+// Three-way merge of named items, given as (name, content) for base, ours
+// and theirs. Items keep our order, with items added on their side placed
+// after the item they follow there. `resolve` decides items both sides
+// changed; returning None keeps our version.
+fn merge_items<C: PartialEq + Clone>(
+    [base, ours, theirs]: [Vec<(String, C)>; 3],
+    mut resolve: impl FnMut(&str, Versions<C>) -> Option<C>,
+) -> Vec<(String, C)> {
+    let our_names: Vec<String> = ours.iter().map(|(name, _)| name.clone()).collect();
+    let their_names: Vec<String> = theirs.iter().map(|(name, _)| name.clone()).collect();
+    let (base, ours, theirs): (HashMap<_, _>, HashMap<_, _>, HashMap<_, _>) = (
+        base.into_iter().collect(),
+        ours.into_iter().collect(),
+        theirs.into_iter().collect(),
+    );
+
+    let mut merged = HashMap::new();
+    for name in our_names.iter().chain(&their_names) {
+        if merged.contains_key(name) {
+            continue;
+        }
+
+        let versions = Versions {
+            base: base.get(name),
+            ours: ours.get(name),
+            theirs: theirs.get(name),
+        };
+        let item = match versions.merge() {
+            Ok(item) => item,
+            Err(()) => {
+                let fallback = versions.ours.cloned();
+                resolve(name, versions).or(fallback)
+            }
+        };
+        merged.insert(name.clone(), item);
+    }
+
+    merge_order(&our_names, &their_names, |name| {
+        merged.get(name).is_some_and(Option::is_some)
+    })
+    .into_iter()
+    .map(|name| {
+        let item = merged
+            .remove(&name)
+            .flatten()
+            .expect("kept items are merged");
+        (name, item)
+    })
+    .collect()
+}
+
+fn merge_order(ours: &[String], theirs: &[String], keep: impl Fn(&str) -> bool) -> Vec<String> {
+    let mut result: Vec<String> = ours.iter().filter(|n| keep(n)).cloned().collect();
+    let mut insert_at = 0;
+
+    for name in theirs {
+        if let Some(pos) = result.iter().position(|n| n == name) {
+            insert_at = pos + 1;
+        } else if keep(name) {
+            result.insert(insert_at, name.clone());
+            insert_at += 1;
+        }
+    }
+
+    result
+}
+
+fn card_content(card: &ParsedCard) -> CardContent {
+    CardContent {
+        description: card.description.clone(),
+        properties: card
+            .properties
+            .iter()
+            .map(|p| (p.property_name.clone(), p.value.clone()))
+            .collect(),
+        subtasks: card.subtasks.clone(),
+    }
+}
+
+// Merge a card both sides changed, field by field
+fn merge_card(title: &str, versions: Versions<CardContent>) -> (CardContent, Vec<String>) {
+    let (Some(ours), Some(theirs)) = (versions.ours, versions.theirs) else {
+        // Keep the changed card rather than lose the change
+        let side = if versions.ours.is_some() {
+            "theirs"
+        } else {
+            "ours"
+        };
+        let card = versions
+            .ours
+            .or(versions.theirs)
+            .cloned()
+            .expect("a conflicting card exists on one side");
+        return (
+            card,
+            vec![format!(
+                "Card '{title}' was deleted in {side} but changed on the other side"
+            )],
+        );
+    };
+
+    let mut messages = Vec::new();
+    let description = Versions {
+        base: versions.base.map(|c| &c.description),
+        ours: Some(&ours.description),
+        theirs: Some(&theirs.description),
+    }
+    .merge()
+    .unwrap_or_else(|()| {
+        messages.push(format!("Card '{title}' has conflicting descriptions"));
+        Some(ours.description.clone())
+    })
+    .unwrap_or_default();
+
+    let mut names: Vec<&String> = ours
+        .properties
+        .keys()
+        .chain(theirs.properties.keys())
+        .collect();
+    names.sort();
+    names.dedup();
+
+    let mut properties = BTreeMap::new();
+    for prop in names {
+        let versions = Versions {
+            base: versions.base.and_then(|c| c.properties.get(prop)),
+            ours: ours.properties.get(prop),
+            theirs: theirs.properties.get(prop),
+        };
+        let value = versions.merge().unwrap_or_else(|()| {
+            messages.push(format!(
+                "Card '{title}' has conflicting values for '{prop}': '{}' and '{}'",
+                versions.ours.map_or("", String::as_str),
+                versions.theirs.map_or("", String::as_str)
+            ));
+            versions.ours.cloned()
+        });
+        if let Some(value) = value {
+            properties.insert(prop.clone(), value);
+        }
+    }
+
+    let subtasks = merge_subtasks(
+        title,
+        versions.base.map(|c| c.subtasks.as_slice()),
+        &ours.subtasks,
+        &theirs.subtasks,
+        &mut messages,
+    );
+
+    (
+        CardContent {
+            description,
+            properties,
+            subtasks,
+        },
+        messages,
+    )
+}
+
+// Merge checklists item by item, matched by text, so checking off different
+// items on each side does not conflict. Lists with repeated items are merged
+// whole.
+fn merge_subtasks(
+    title: &str,
+    base: Option<&[Subtask]>,
+    ours: &[Subtask],
+    theirs: &[Subtask],
+    messages: &mut Vec<String>,
+) -> Vec<Subtask> {
+    let base = base.unwrap_or_default();
+    let has_repeats = |subtasks: &[Subtask]| {
+        let mut seen = HashSet::new();
+        subtasks.iter().any(|s| !seen.insert(&s.text))
+    };
+
+    if [base, ours, theirs].into_iter().any(has_repeats) {
+        let [base, ours, theirs] = [base, ours, theirs].map(<[Subtask]>::to_vec);
+        return Versions {
+            base: Some(&base),
+            ours: Some(&ours),
+            theirs: Some(&theirs),
+        }
+        .merge()
+        .unwrap_or_else(|()| {
+            messages.push(format!("Card '{title}' has conflicting checklists"));
+            Some(ours.clone())
+        })
+        .unwrap_or_default();
+    }
+
+    merge_items(
+        [base, ours, theirs]
+            .map(|subtasks| subtasks.iter().map(|s| (s.text.clone(), s.done)).collect()),
+        |text, versions| {
+            messages.push(format!(
+                "Subtask '{text}' of card '{title}' was changed differently on both sides"
+            ));
+            versions.ours.or(versions.theirs).copied()
+        },
+    )
+    .into_iter()
+    .map(|(text, done)| Subtask { text, done })
+    .collect()
+}
+
+// A view's settings with manual orders as card titles, so cards moving
+// position in the file does not look like a change
+fn view_content(view: &ParsedView, doc: &ParsedDocument) -> (ParsedView, ViewOrders) {
+    // Column sorts refer to card positions in the view's own document
+    let orders = view
+        .column_sorts
+        .iter()
+        .map(|sort| {
+            let titles = sort
+                .order
+                .iter()
+                .filter_map(|id| doc.cards.iter().find(|c| c.id == *id))
+                .map(|card| card.title.clone())
+                .collect();
+            (sort.column.clone(), titles)
+        })
+        .collect();
+
+    let settings = ParsedView {
+        column_sorts: Vec::new(),
+        ..view.clone()
+    };
+    (settings, orders)
+}
+
+// Merge a view both sides changed: settings whole, orders per column
+fn merge_view(
+    name: &str,
+    base: Option<&(ParsedView, ViewOrders)>,
+    ours: &(ParsedView, ViewOrders),
+    theirs: &(ParsedView, ViewOrders),
+) -> ((ParsedView, ViewOrders), Vec<String>) {
+    let mut messages = Vec::new();
+
+    let settings = Versions {
+        base: base.map(|(view, _)| view),
+        ours: Some(&ours.0),
+        theirs: Some(&theirs.0),
+    }
+    .merge()
+    .unwrap_or_else(|()| {
+        messages.push(format!("View '{name}' has conflicting settings"));
+        Some(ours.0.clone())
+    })
+    .unwrap_or_else(|| ours.0.clone());
+
+    let mut columns: Vec<&String> = ours.1.keys().chain(theirs.1.keys()).collect();
+    columns.sort();
+    columns.dedup();
+
+    let mut orders = ViewOrders::new();
+    for column in columns {
+        let versions = Versions {
+            base: base.and_then(|(_, orders)| orders.get(column)),
+            ours: ours.1.get(column),
+            theirs: theirs.1.get(column),
+        };
+        let order = versions.merge().unwrap_or_else(|()| {
+            messages.push(format!(
+                "View '{name}' has conflicting orders for column '{column}'"
+            ));
+            versions.ours.cloned()
+        });
+        if let Some(order) = order {
+            orders.insert(column.clone(), order);
+        }
+    }
+
+    ((settings, orders), messages)
+}
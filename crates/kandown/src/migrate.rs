@@ -0,0 +1,114 @@
+// crates/kandown/src/migrate.rs
+
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::{Diagnostic, DocumentSection, KanbanSortType, ParsedDocument, locate_diagnostics};
+
+/// Version of the markdown format this build reads and writes, recorded as
+/// `version` in a board's front matter. Boards without one are version 1.
+pub const FORMAT_VERSION: u32 = 2;
+
+/// What upgrading a board to [`FORMAT_VERSION`] changed. Each change is a
+/// warning pointing at the item that was rewritten.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MigrationReport {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub changes: Vec<Diagnostic>,
+}
+
+impl MigrationReport {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+// An upgrade of documents written in format version `from` to the next
+struct Migration {
+    from: u32,
+    apply: fn(&mut ParsedDocument, &mut Vec<Diagnostic>),
+}
+
+// Applied in order; add a step here and bump FORMAT_VERSION when the grammar
+// changes in a way older boards need rewriting for
+const MIGRATIONS: &[Migration] = &[Migration {
+    from: 1,
+    apply: sort_type_from_sort,
+}];
+
+impl ParsedDocument {
+    // The format version the document was written in
+    pub fn format_version(&self) -> u32 {
+        self.metadata.version.unwrap_or(1)
+    }
+
+    // Upgrade the document to the current format and mark it with the current
+    // version, so later migrations know which steps it has been through
+    pub fn migrate(&mut self) -> Result<MigrationReport> {
+        let from_version = self.format_version();
+        if from_version == 0 || from_version > FORMAT_VERSION {
+            bail!(
+                "Unsupported board format version: {from_version} (expected at most {FORMAT_VERSION})"
+            );
+        }
+
+        let mut changes = Vec::new();
+        for migration in MIGRATIONS.iter().filter(|m| m.from >= from_version) {
+            (migration.apply)(self, &mut changes);
+        }
+
+        self.metadata.version = Some(FORMAT_VERSION);
+
+        Ok(MigrationReport {
+            from_version,
+            to_version: FORMAT_VERSION,
+            changes,
+        })
+    }
+
+    // Parse a board written in any supported format version and upgrade it,
+    // with the report's changes located in the source
+    pub fn parse_and_migrate(source: &str) -> Result<(ParsedDocument, MigrationReport)> {
+        let mut doc = ParsedDocument::parse_complete(source)?;
+        let mut report = doc.migrate()?;
+        locate_diagnostics(source, &mut report.changes);
+
+        Ok((doc, report))
+    }
+}
+
+// Version 1 set the sort type with `Sort:` itself, as in `Sort: Manual`, which
+// now names the property to sort by
+fn sort_type_from_sort(doc: &mut ParsedDocument, changes: &mut Vec<Diagnostic>) {
+    let properties = &doc.properties;
+
+    for (idx, view) in doc.views.iter_mut().enumerate() {
+        let sort_type = match view.sort_by.as_deref() {
+            Some("Alpha") => KanbanSortType::Alpha,
+            Some("ReverseAlpha") => KanbanSortType::ReverseAlpha,
+            Some("Manual") => KanbanSortType::Manual,
+            _ => continue,
+        };
+        if view.sort_type != KanbanSortType::None
+            || properties
+                .iter()
+                .any(|p| view.sort_by.as_ref() == Some(&p.name))
+        {
+            continue;
+        }
+
+        changes.push(
+            Diagnostic::warning(
+                "migrated",
+                format!(
+                    "View '{}': 'Sort: {sort_type:?}' is now written 'Sort Type: {sort_type:?}'",
+                    view.name
+                ),
+            )
+            .at(DocumentSection::Views, idx, &view.name),
+        );
+        view.sort_by = None;
+        view.sort_type = sort_type;
+    }
+}
@@ -1,7 +1,10 @@
 // crates/kandown/src/parsed_document.rs
 
-use anyhow::anyhow;
+use anyhow::{anyhow, bail};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use crate::ParserConfig;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ParsedViewType {
@@ -17,12 +20,75 @@ pub enum ParsedPropertyType {
     Number,
     Date,
     Checkbox,
+    Formula { expression: String },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ParsedProperty {
     pub name: String,
     pub property_type: ParsedPropertyType,
+    #[serde(default, skip_serializing_if = "PropertyRules::is_empty")]
+    pub rules: PropertyRules,
+}
+
+/// Settings written as indented `Key: value` lines under a property:
+/// `Default`, `Required`, `Min` and `Max` for numbers and a `Pattern` regex
+/// that values must match.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PropertyRules {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+}
+
+impl PropertyRules {
+    pub fn is_empty(&self) -> bool {
+        *self == PropertyRules::default()
+    }
+
+    // Check a non-empty value of the property `name` against the bounds and
+    // pattern
+    pub fn check(&self, name: &str, value: &str) -> anyhow::Result<()> {
+        if self.min.is_some() || self.max.is_some() {
+            let number: f64 = value
+                .parse()
+                .map_err(|_| anyhow!("{name} must be a number, got '{value}'"))?;
+            if let Some(min) = self.min
+                && number < min
+            {
+                bail!("{name} must be at least {min}, got {value}");
+            }
+            if let Some(max) = self.max
+                && number > max
+            {
+                bail!("{name} must be at most {max}, got {value}");
+            }
+        }
+
+        if let Some(pattern) = &self.pattern
+            && !self
+                .pattern_regex()
+                .map_err(|e| anyhow!("{name} has an invalid pattern: {e}"))?
+                .is_match(value)
+        {
+            bail!("{name} must match the pattern {pattern}, got '{value}'");
+        }
+
+        Ok(())
+    }
+
+    // The pattern anchored to the whole value
+    pub(crate) fn pattern_regex(&self) -> Result<regex_lite::Regex, regex_lite::Error> {
+        let pattern = self.pattern.as_deref().unwrap_or_default();
+        regex_lite::Regex::new(&format!("^(?:{pattern})$"))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -31,12 +97,21 @@ pub struct ParsedPropertyValue {
     pub value: String,
 }
 
+// A checklist item inside a card, written as `- [ ] text` or `- [x] text`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Subtask {
+    pub text: String,
+    pub done: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ParsedCard {
     pub id: usize, // Added ID based on position
     pub title: String,
     pub description: String,
     pub properties: Vec<ParsedPropertyValue>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subtasks: Vec<Subtask>,
 }
 
 impl std::fmt::Display for ParsedViewType {
@@ -77,8 +152,48 @@ pub struct ParsedView {
     pub display: Option<String>,
 }
 
+/// Board-level settings, written as YAML front matter at the top of the file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct BoardMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>, // Version of the markdown format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_view: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>, // Language of the headings and keywords, e.g. "de"
+}
+
+impl BoardMetadata {
+    pub fn is_empty(&self) -> bool {
+        *self == BoardMetadata::default()
+    }
+
+    // The keywords a board is written with: those of its locale if it names
+    // one, else `config`. Fails on a locale there are no keywords for.
+    pub fn parser_config<'a>(
+        &self,
+        config: &'a ParserConfig,
+    ) -> anyhow::Result<Cow<'a, ParserConfig>> {
+        match &self.locale {
+            Some(locale) => Ok(Cow::Owned(ParserConfig::for_locale(locale)?)),
+            None => Ok(Cow::Borrowed(config)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ParsedDocument {
+    #[serde(default, skip_serializing_if = "BoardMetadata::is_empty")]
+    pub metadata: BoardMetadata,
     pub properties: Vec<ParsedProperty>,
     pub cards: Vec<ParsedCard>,
     pub views: Vec<ParsedView>,
@@ -88,13 +203,88 @@ impl std::convert::TryFrom<&str> for ParsedDocument {
     type Error = anyhow::Error;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        ParsedDocument::try_parse(value).map(|(_, doc)| doc)
+        ParsedDocument::parse_complete(value)
     }
 }
 
 impl ParsedDocument {
     pub fn try_parse(s: &str) -> anyhow::Result<(&str, ParsedDocument)> {
-        super::parse_document(s).map_err(|e| anyhow!("{e}"))
+        super::parse_document(s).map_err(super::parse_error)
+    }
+
+    // Parse with the given keywords, unless the front matter names a locale
+    pub fn try_parse_with<'a>(
+        s: &'a str,
+        config: &ParserConfig,
+    ) -> anyhow::Result<(&'a str, ParsedDocument)> {
+        super::parse_document_with(config, s).map_err(super::parse_error)
+    }
+
+    // Put each card's property values in the order the properties are declared
+    pub fn normalize(&mut self) {
+        let position = |name: &str| {
+            self.properties
+                .iter()
+                .position(|p| p.name == name)
+                .unwrap_or(usize::MAX)
+        };
+
+        for card in &mut self.cards {
+            card.properties
+                .sort_by_key(|value| position(&value.property_name));
+        }
+    }
+
+    // Parse a whole document, failing instead of ignoring trailing content the
+    // parser could not read
+    pub fn parse_complete(s: &str) -> anyhow::Result<ParsedDocument> {
+        ParsedDocument::parse_complete_with(s, &ParserConfig::default())
+    }
+
+    pub fn parse_complete_with(s: &str, config: &ParserConfig) -> anyhow::Result<ParsedDocument> {
+        let (rest, doc) = ParsedDocument::try_parse_with(s, config)?;
+
+        if !rest.trim().is_empty() {
+            let rest = rest.trim_start();
+            let line = s[..s.len() - rest.len()].lines().count() + 1;
+            let text = rest.lines().next().unwrap_or_default();
+            if text.starts_with('#') {
+                // Headings the parser stopped at are sections it doesn't know
+                let config = doc.metadata.parser_config(config)?;
+                return Err(anyhow!(
+                    "Unknown section at line {line}: {text} (expected one of: # {}, # {}, # {})",
+                    config.properties,
+                    config.views,
+                    config.cards
+                ));
+            }
+            return Err(anyhow!("Unrecognized content at line {line}: {text}"));
+        }
+
+        Ok(doc)
+    }
+
+    // Rewrite a markdown board in canonical form, upgraded to the current
+    // format version. Fails instead of dropping anything the parser could not
+    // read or that would not be written back.
+    pub fn format_markdown(s: &str) -> anyhow::Result<String> {
+        let mut doc = ParsedDocument::parse_complete(s)?;
+        doc.migrate()?;
+        doc.normalize();
+
+        // Column orderings are only written for manually sorted views
+        if let Some(view) = doc
+            .views
+            .iter()
+            .find(|v| v.sort_type != KanbanSortType::Manual && !v.column_sorts.is_empty())
+        {
+            bail!(
+                "View '{}' lists card orderings but is not sorted manually (add 'Sort Type: Manual' to keep them)",
+                view.name
+            );
+        }
+
+        Ok(doc.to_string())
     }
 }
 
@@ -106,17 +296,38 @@ impl std::str::FromStr for ParsedDocument {
     }
 }
 
+// Fails for a locale there are no keywords for; use `to_markdown_with` to
+// get the error itself
 impl std::fmt::Display for ParsedDocument {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let markdown = self
+            .to_markdown_with(&ParserConfig::default())
+            .map_err(|_| std::fmt::Error)?;
+        f.write_str(&markdown)
+    }
+}
+
+impl ParsedDocument {
+    // Write the document with the given keywords, unless its front matter
+    // names a locale
+    pub fn to_markdown_with(&self, config: &ParserConfig) -> anyhow::Result<String> {
+        let config = self.metadata.parser_config(config)?;
         let mut output = String::new();
 
+        // Front matter
+        if !self.metadata.is_empty() {
+            let yaml = serde_norway::to_string(&self.metadata).unwrap_or_default();
+            output.push_str(&format!("---\n{yaml}---\n\n"));
+        }
+
         // Properties section
         if !self.properties.is_empty() {
-            output.push_str("# Properties\n");
+            output.push_str(&format!("# {}\n", config.properties));
 
             for ParsedProperty {
                 name,
                 property_type,
+                rules,
             } in &self.properties
             {
                 match &property_type {
@@ -132,6 +343,9 @@ impl std::fmt::Display for ParsedDocument {
                     ParsedPropertyType::Checkbox => {
                         output.push_str(&format!("- {name}: Checkbox\n"));
                     }
+                    ParsedPropertyType::Formula { expression } => {
+                        output.push_str(&format!("- {name}: Formula({expression})\n"));
+                    }
                     ParsedPropertyType::Select { options } => {
                         output.push_str(&format!("- {name}: Select\n"));
                         for option in options {
@@ -140,13 +354,29 @@ impl std::fmt::Display for ParsedDocument {
                     }
                 }
 
+                if let Some(default) = &rules.default {
+                    output.push_str(&format!("\t{}: {default}\n", config.default));
+                }
+                if rules.required {
+                    output.push_str(&format!("\t{}: true\n", config.required));
+                }
+                if let Some(min) = rules.min {
+                    output.push_str(&format!("\t{}: {min}\n", config.min));
+                }
+                if let Some(max) = rules.max {
+                    output.push_str(&format!("\t{}: {max}\n", config.max));
+                }
+                if let Some(pattern) = &rules.pattern {
+                    output.push_str(&format!("\t{}: {pattern}\n", config.pattern));
+                }
+
                 output.push('\n');
             }
         }
 
         // Views section
         if !self.views.is_empty() {
-            output.push_str("# Views\n");
+            output.push_str(&format!("# {}\n", config.views));
 
             for ParsedView {
                 name,
@@ -161,19 +391,19 @@ impl std::fmt::Display for ParsedDocument {
             {
                 output.push_str(&format!("- {name}\n"));
 
-                output.push_str(&format!("  Layout: {layout}\n"));
+                output.push_str(&format!("  {}: {layout}\n", config.layout));
 
                 if let Some(group_by) = &group {
-                    output.push_str(&format!("  Group: {group_by}\n"));
+                    output.push_str(&format!("  {}: {group_by}\n", config.group));
                 }
 
                 if let Some(sort_by) = &sort_by {
-                    output.push_str(&format!("  Sort: {sort_by}\n"));
+                    output.push_str(&format!("  {}: {sort_by}\n", config.sort));
                 }
 
                 // Output sort type if it's not None
                 if sort_type != &KanbanSortType::None {
-                    output.push_str(&format!("  Sort Type: {sort_type:?}\n"));
+                    output.push_str(&format!("  {}: {sort_type:?}\n", config.sort_type));
                 }
 
                 // Output manual sorting configuration if present
@@ -187,11 +417,11 @@ impl std::fmt::Display for ParsedDocument {
                 }
 
                 if let Some(filter) = &filter {
-                    output.push_str(&format!("  Filter: {filter}\n"));
+                    output.push_str(&format!("  {}: {filter}\n", config.filter));
                 }
 
                 if let Some(display) = &display {
-                    output.push_str(&format!("  Display: {display}\n"));
+                    output.push_str(&format!("  {}: {display}\n", config.display));
                 }
 
                 output.push('\n');
@@ -200,12 +430,13 @@ impl std::fmt::Display for ParsedDocument {
 
         // Cards section
         if !self.cards.is_empty() {
-            output.push_str("# Cards\n");
+            output.push_str(&format!("# {}\n", config.cards));
 
             for ParsedCard {
                 title,
                 description,
                 properties,
+                subtasks,
                 ..
             } in &self.cards
             {
@@ -219,14 +450,30 @@ impl std::fmt::Display for ParsedDocument {
                     output.push_str(&format!("  {property_name}: {value}\n"));
                 }
 
-                if !description.is_empty() {
-                    output.push_str(&format!("  {description}\n"));
+                // A first line with a colon would be read back as a property,
+                // so it is kept apart from them by an empty indented line
+                if description.lines().next().is_some_and(|l| l.contains(':')) {
+                    output.push_str("  \n");
+                }
+                for line in description.lines() {
+                    output.push_str(&format!("  {line}\n"));
+                }
+
+                for Subtask { text, done } in subtasks {
+                    let mark = if *done { 'x' } else { ' ' };
+                    output.push_str(&format!("  - [{mark}] {text}\n"));
                 }
 
                 output.push('\n');
             }
         }
 
-        write!(f, "{output}")
+        // Sections are separated by blank lines, but the file ends with one newline
+        let output = output.trim_end();
+        if output.is_empty() {
+            Ok(String::new())
+        } else {
+            Ok(format!("{output}\n"))
+        }
     }
 }
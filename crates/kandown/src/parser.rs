@@ -5,15 +5,16 @@ use nom::{
     branch::alt,
     bytes::complete::{tag, take_while1},
     character::complete::{char, line_ending, multispace0, not_line_ending, space0, space1},
-    combinator::{map, opt, verify},
+    combinator::{eof, map, opt},
     error::{ErrorKind, ParseError},
-    multi::{many0, many1},
+    multi::many0,
     sequence::{preceded, terminated},
 };
 
 use crate::{
-    ColumnSort, KanbanSortType, ParsedCard, ParsedDocument, ParsedProperty, ParsedPropertyType,
-    ParsedPropertyValue, ParsedView, ParsedViewType,
+    BoardMetadata, ColumnSort, DocumentSection, KanbanSortType, ParsedCard, ParsedDocument,
+    ParsedProperty, ParsedPropertyType, ParsedPropertyValue, ParsedView, ParsedViewType,
+    ParserConfig, PropertyRules, Subtask,
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -34,6 +35,28 @@ impl<I> ParseError<I> for MarkdownError<I> {
     }
 }
 
+impl<I> std::fmt::Display for MarkdownError<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MarkdownError::NomError(_, kind) => {
+                write!(f, "Unexpected content ({})", kind.description())
+            }
+            MarkdownError::MissingSection(section) => write!(f, "Missing # {section} section"),
+            MarkdownError::InvalidPropertyType(message) | MarkdownError::InvalidFormat(message) => {
+                write!(f, "{message}")
+            }
+        }
+    }
+}
+
+// Turn a parser error into the message users see, without nom's wrapping
+pub(crate) fn parse_error(e: nom::Err<MarkdownError<&str>>) -> anyhow::Error {
+    match e {
+        nom::Err::Error(e) | nom::Err::Failure(e) => anyhow::anyhow!("{e}"),
+        nom::Err::Incomplete(_) => anyhow::anyhow!("Unexpected end of input"),
+    }
+}
+
 type ParserError<'a> = MarkdownError<&'a str>;
 type ParserResult<'a, R> = IResult<&'a str, R, ParserError<'a>>;
 
@@ -42,13 +65,16 @@ mod parse_md {
 
     pub fn heading<'a>(content: &str, i: &'a str) -> ParserResult<'a, ()> {
         map(
-            terminated((char('#'), space0, tag(content)), opt(line_ending)),
+            terminated(
+                (char('#'), space0, tag(content), space0),
+                alt((line_ending, eof)),
+            ),
             |_| (),
         )
         .parse(i)
     }
 
-    pub fn list_item(i: &str) -> ParserResult<&str> {
+    pub fn list_item(i: &str) -> ParserResult<'_, &str> {
         preceded(
             (
                 space0,
@@ -71,45 +97,158 @@ mod parse_md {
 }
 
 // Document parser
-pub(crate) fn parse_document(i: &str) -> ParserResult<ParsedDocument> {
-    let (i, _) = multispace0.parse(i)?;
+pub(crate) fn parse_document(i: &str) -> ParserResult<'_, ParsedDocument> {
+    parse_document_with(&ParserConfig::default(), i)
+}
 
-    let (i, properties) = match parse_properties_section(i) {
+// Parse a document written with the given keywords, unless its front matter
+// names a locale
+pub(crate) fn parse_document_with<'a>(
+    config: &ParserConfig,
+    input: &'a str,
+) -> ParserResult<'a, ParsedDocument> {
+    let (i, metadata) = match parse_front_matter(input) {
         Ok(result) => result,
-        Err(nom::Err::Error(_)) => (i, vec![]), // Return empty vec on error
-        Err(e) => return Err(e),                // Propagate other errors
+        Err(nom::Err::Error(_)) => (input, BoardMetadata::default()),
+        Err(e) => return Err(e),
     };
+    let (i, _) = multispace0.parse(i)?;
 
-    let (i, views) = match parse_views_section(i) {
-        Ok(result) => result,
-        Err(nom::Err::Error(_)) => (i, vec![]), // Return empty vec on error
-        Err(e) => return Err(e),                // Propagate other errors
+    let locale_config;
+    let config = match &metadata.locale {
+        Some(locale) => {
+            locale_config = ParserConfig::for_locale(locale)
+                .map_err(|e| nom::Err::Failure(MarkdownError::InvalidFormat(e.to_string())))?;
+            &locale_config
+        }
+        None => config,
     };
 
-    // Parse cards and assign IDs based on position
-    let (i, cards) = match parse_cards_section(i) {
-        Ok(result) => result,
-        Err(nom::Err::Error(_)) => (i, vec![]), // Return empty vec on error
-        Err(e) => return Err(e),                // Propagate other errors
-    };
+    // Sections may come in any order and cards may be split across several
+    // sections. Parsing stops at the first line that starts no section.
+    let mut properties = None;
+    let mut views = None;
+    let mut cards = Vec::new();
+    let mut i = i;
+
+    while let Ok((_, section)) = parse_section_heading(config, i) {
+        let line = input[..input.len() - i.len()].lines().count() + 1;
+        let heading = i.lines().next().unwrap_or_default().trim();
+        let section_failure = |e| match e {
+            nom::Err::Error(MarkdownError::InvalidFormat(message)) => nom::Err::Failure(
+                MarkdownError::InvalidFormat(format!("{message} (in {heading} at line {line})")),
+            ),
+            nom::Err::Error(_) => nom::Err::Failure(MarkdownError::InvalidFormat(format!(
+                "Could not parse {heading} at line {line}"
+            ))),
+            e => e,
+        };
+        let duplicate = || {
+            nom::Err::Failure(MarkdownError::InvalidFormat(format!(
+                "Duplicate {heading} section at line {line}"
+            )))
+        };
+
+        i = match section {
+            DocumentSection::Properties => {
+                if properties.is_some() {
+                    return Err(duplicate());
+                }
+                let (rest, section) = parse_properties_section(config, metadata.version, i)
+                    .map_err(section_failure)?;
+                properties = Some(section);
+                rest
+            }
+            DocumentSection::Views => {
+                if views.is_some() {
+                    return Err(duplicate());
+                }
+                let (rest, section) = parse_views_section(config, i).map_err(section_failure)?;
+                views = Some(section);
+                rest
+            }
+            DocumentSection::Cards => {
+                let (rest, mut section) =
+                    parse_cards_section(config, i).map_err(section_failure)?;
+                cards.append(&mut section);
+                rest
+            }
+        };
+        (i, _) = multispace0.parse(i)?;
+    }
+
+    // Card IDs are positions across all card sections
+    for (idx, card) in cards.iter_mut().enumerate() {
+        card.id = idx;
+    }
 
     Ok((
         i,
         ParsedDocument {
-            properties,
+            metadata,
+            properties: properties.unwrap_or_default(),
             cards,
-            views,
+            views: views.unwrap_or_default(),
         },
     ))
 }
 
-pub(crate) fn parse_card(i: &str) -> ParserResult<ParsedCard> {
+// The section a `# Heading` line starts
+pub(crate) fn parse_section_heading<'a>(
+    config: &ParserConfig,
+    i: &'a str,
+) -> ParserResult<'a, DocumentSection> {
+    alt((
+        map(
+            |i| parse_md::heading(&config.properties, i),
+            |_| DocumentSection::Properties,
+        ),
+        map(
+            |i| parse_md::heading(&config.views, i),
+            |_| DocumentSection::Views,
+        ),
+        map(
+            |i| parse_md::heading(&config.cards, i),
+            |_| DocumentSection::Cards,
+        ),
+    ))
+    .parse(i)
+}
+
+// YAML front matter between `---` lines at the very start of the file
+pub(crate) fn parse_front_matter(i: &str) -> ParserResult<'_, BoardMetadata> {
+    let (i, _) = (tag("---"), space0, line_ending).parse(i)?;
+
+    let end = if i.starts_with("---") {
+        0
+    } else {
+        i.find("\n---").map(|pos| pos + 1).ok_or_else(|| {
+            nom::Err::Failure(MarkdownError::InvalidFormat(
+                "Front matter is missing its closing ---".to_string(),
+            ))
+        })?
+    };
+    let (yaml, i) = i.split_at(end);
+    let (i, _) = (tag("---"), space0, opt(line_ending)).parse(i)?;
+
+    if yaml.trim().is_empty() {
+        return Ok((i, BoardMetadata::default()));
+    }
+    let metadata = serde_norway::from_str(yaml).map_err(|e| {
+        nom::Err::Failure(MarkdownError::InvalidFormat(format!(
+            "Invalid front matter: {e}"
+        )))
+    })?;
+
+    Ok((i, metadata))
+}
+
+pub(crate) fn parse_card(i: &str) -> ParserResult<'_, ParsedCard> {
     let (i, title) = parse_md::list_item(i)?;
     let (i, _) = opt(line_ending).parse(i)?;
 
     // Parse property values
     let mut property_values = Vec::new();
-    let mut description = String::new();
     let mut current_input = i;
 
     // First, collect all property values
@@ -143,22 +282,31 @@ pub(crate) fn parse_card(i: &str) -> ParserResult<ParsedCard> {
         }
     }
 
-    // Now try to parse description (any indented text that's not a property)
-    let mut description_parser = many0(preceded(
+    // The remaining indented lines are checklist items and the description.
+    // Only the first description line has to avoid colons, after that every
+    // indented line that is not a checklist item belongs to the description.
+    // An empty line before the description separates it from the properties.
+    let mut desc_lines = Vec::new();
+    let mut subtasks = Vec::new();
+    let mut separated = false;
+    let mut indented_line = preceded(
         space1::<_, ParserError>,
-        map(
-            terminated(
-                verify(not_line_ending, |s: &str| !s.contains(':')),
-                opt(line_ending),
-            ),
-            |s: &str| s.to_string(),
-        ),
-    ));
-
-    if let Ok((new_input, desc_lines)) = description_parser.parse(current_input) {
-        description = desc_lines.join("\n");
+        terminated(not_line_ending, opt(line_ending)),
+    );
+
+    while let Ok((new_input, line)) = indented_line.parse(current_input) {
+        if let Some(subtask) = parse_subtask(line) {
+            subtasks.push(subtask);
+        } else if desc_lines.is_empty() && !separated && line.trim().is_empty() {
+            separated = true;
+        } else if desc_lines.is_empty() && !separated && line.contains(':') {
+            break;
+        } else {
+            desc_lines.push(line);
+        }
         current_input = new_input;
     }
+    let description = desc_lines.join("\n");
 
     // Consume any trailing newlines
     let (current_input, _) = many0(line_ending).parse(current_input)?;
@@ -170,11 +318,38 @@ pub(crate) fn parse_card(i: &str) -> ParserResult<ParsedCard> {
             title: title.trim().to_string(),
             description,
             properties: property_values,
+            subtasks,
         },
     ))
 }
 
-pub(crate) fn parse_card_property(i: &str) -> ParserResult<ParsedPropertyValue> {
+// Parse a checklist item such as `- [x] Write tests`, without its indentation
+pub(crate) fn parse_subtask(line: &str) -> Option<Subtask> {
+    let rest = line.trim().strip_prefix(['-', '*', '+'])?;
+    let rest = rest.strip_prefix([' ', '\t'])?.trim_start();
+
+    let (done, text) = if let Some(text) = rest.strip_prefix("[ ]") {
+        (false, text)
+    } else if let Some(text) = rest
+        .strip_prefix("[x]")
+        .or_else(|| rest.strip_prefix("[X]"))
+    {
+        (true, text)
+    } else {
+        return None;
+    };
+
+    if !text.is_empty() && !text.starts_with([' ', '\t']) {
+        return None;
+    }
+
+    Some(Subtask {
+        text: text.trim().to_string(),
+        done,
+    })
+}
+
+pub(crate) fn parse_card_property(i: &str) -> ParserResult<'_, ParsedPropertyValue> {
     let (i, _) = space1.parse(i)?; // indentation
 
     // Parse name: value format
@@ -202,8 +377,11 @@ pub(crate) fn parse_card_property(i: &str) -> ParserResult<ParsedPropertyValue>
     ))
 }
 
-pub(crate) fn parse_cards_section(i: &str) -> ParserResult<Vec<ParsedCard>> {
-    let (i, _) = parse_md::heading("Cards", i)?;
+pub(crate) fn parse_cards_section<'a>(
+    config: &ParserConfig,
+    i: &'a str,
+) -> ParserResult<'a, Vec<ParsedCard>> {
+    let (i, _) = parse_md::heading(&config.cards, i)?;
     let (i, _) = many0(line_ending).parse(i)?; // Handle extra newlines
 
     // Parse cards first without assigning IDs
@@ -228,30 +406,44 @@ pub(crate) fn parse_cards_section(i: &str) -> ParserResult<Vec<ParsedCard>> {
     Ok((i, cards))
 }
 
-// Properties section parser
-pub(crate) fn parse_properties_section(i: &str) -> ParserResult<Vec<ParsedProperty>> {
-    let (i, _) = parse_md::heading("Properties", i)?;
-    let (i, props) = many1(parse_property).parse(i)?;
+// Properties section parser. `version` is the document's format version, as
+// written in its front matter.
+pub(crate) fn parse_properties_section<'a>(
+    config: &ParserConfig,
+    version: Option<u32>,
+    i: &'a str,
+) -> ParserResult<'a, Vec<ParsedProperty>> {
+    // Version 1 boards may have Select options without indentation
+    let legacy = version.unwrap_or(1) < 2;
+
+    let (i, _) = parse_md::heading(&config.properties, i)?;
+    let (i, props) = many0(|i| parse_property(config, legacy, i)).parse(i)?;
     let (i, _) = many0(line_ending).parse(i)?;
 
     Ok((i, props))
 }
 
-// Property parser for the new format
-pub(crate) fn parse_property(i: &str) -> ParserResult<ParsedProperty> {
+// Property parser. `legacy` accepts version 1 Select options.
+pub(crate) fn parse_property<'a>(
+    config: &ParserConfig,
+    legacy: bool,
+    i: &'a str,
+) -> ParserResult<'a, ParsedProperty> {
     let (i, name_and_type) = parse_md::list_item(i)?;
     let (i, _) = opt(line_ending).parse(i)?;
 
-    // Split the name and type by the colon
-    let parts: Vec<&str> = name_and_type.split(':').collect();
-    if parts.len() != 2 {
+    // Split the name and type by the colon; only formulas may contain another
+    let parts = name_and_type
+        .split_once(':')
+        .filter(|(_, type_str)| !type_str.contains(':') || type_str.trim().starts_with("Formula("));
+    let Some((name, type_str)) = parts else {
         return Err(nom::Err::Error(MarkdownError::InvalidFormat(format!(
             "Invalid property format: {name_and_type}",
         ))));
-    }
+    };
 
-    let name = parts[0].trim();
-    let type_str = parts[1].trim();
+    let name = name.trim();
+    let type_str = type_str.trim();
 
     // Initialize property type based on the type string
     let (i, property_type) = match type_str {
@@ -261,7 +453,11 @@ pub(crate) fn parse_property(i: &str) -> ParserResult<ParsedProperty> {
         "Checkbox" => (i, ParsedPropertyType::Checkbox),
         "Select" => {
             // For Select, we need to parse options
-            let (i, options) = many0(parse_property_option).parse(i)?;
+            let (i, options) = if legacy {
+                many0(parse_legacy_property_option).parse(i)?
+            } else {
+                many0(parse_property_option).parse(i)?
+            };
             (
                 i,
                 ParsedPropertyType::Select {
@@ -269,6 +465,15 @@ pub(crate) fn parse_property(i: &str) -> ParserResult<ParsedProperty> {
                 },
             )
         }
+        _ if type_str.starts_with("Formula(") && type_str.ends_with(')') => {
+            let expression = &type_str["Formula(".len()..type_str.len() - 1];
+            (
+                i,
+                ParsedPropertyType::Formula {
+                    expression: expression.trim().to_string(),
+                },
+            )
+        }
         _ => {
             return Err(nom::Err::Error(MarkdownError::InvalidPropertyType(
                 format!("Unknown property type: {type_str}"),
@@ -276,6 +481,28 @@ pub(crate) fn parse_property(i: &str) -> ParserResult<ParsedProperty> {
         }
     };
 
+    let (i, settings) = many0(parse_property_setting).parse(i)?;
+    let mut rules = PropertyRules::default();
+    for (key, value) in settings {
+        let invalid = || {
+            nom::Err::Failure(MarkdownError::InvalidFormat(format!(
+                "Invalid {key} for property {name}: {value}"
+            )))
+        };
+        match key {
+            _ if key == config.default => rules.default = Some(value.to_string()),
+            _ if key == config.required => rules.required = value.parse().map_err(|_| invalid())?,
+            _ if key == config.min => rules.min = Some(value.parse().map_err(|_| invalid())?),
+            _ if key == config.max => rules.max = Some(value.parse().map_err(|_| invalid())?),
+            _ if key == config.pattern => rules.pattern = Some(value.to_string()),
+            _ => {
+                return Err(nom::Err::Failure(MarkdownError::InvalidFormat(format!(
+                    "Unknown setting for property {name}: {key}"
+                ))));
+            }
+        }
+    }
+
     let (i, _) = many0(line_ending).parse(i)?;
 
     Ok((
@@ -283,30 +510,69 @@ pub(crate) fn parse_property(i: &str) -> ParserResult<ParsedProperty> {
         ParsedProperty {
             name: name.to_string(),
             property_type,
+            rules,
         },
     ))
 }
 
+// Parse an indented `Key: value` setting under a property, e.g. `Default: Todo`
+pub(crate) fn parse_property_setting(i: &str) -> ParserResult<'_, (&str, &str)> {
+    let (i, _) = space1.parse(i)?;
+    if parse_md::list_item(i).is_ok() {
+        return Err(nom::Err::Error(MarkdownError::NomError(i, ErrorKind::Tag)));
+    }
+
+    let (i, line) = take_while1(|c: char| c != '\n' && c != '\r').parse(i)?;
+    let (i, _) = opt(line_ending).parse(i)?;
+
+    let Some((key, value)) = line.split_once(':') else {
+        return Err(nom::Err::Error(MarkdownError::InvalidFormat(format!(
+            "Invalid property setting: {line}"
+        ))));
+    };
+
+    Ok((i, (key.trim(), value.trim())))
+}
+
 // Parse options for Select properties
-pub(crate) fn parse_property_option(i: &str) -> ParserResult<&str> {
-    let (i, _) = space0.parse(i)?; // indentation
+pub(crate) fn parse_property_option(i: &str) -> ParserResult<'_, &str> {
+    let (i, _) = space1.parse(i)?; // options must be indented
     let (i, option) = parse_md::list_item(i)?; // option name
     let (i, _) = opt(line_ending).parse(i)?;
 
     Ok((i, option.trim()))
 }
 
+// Options in version 1 boards could also be unindented. An unindented item
+// with a colon is the next property rather than an option.
+fn parse_legacy_property_option(i: &str) -> ParserResult<'_, &str> {
+    if let Ok(result) = parse_property_option(i) {
+        return Ok(result);
+    }
+
+    let (rest, option) = parse_md::list_item(i)?;
+    if option.contains(':') {
+        return Err(nom::Err::Error(MarkdownError::NomError(i, ErrorKind::Tag)));
+    }
+    let (rest, _) = opt(line_ending).parse(rest)?;
+
+    Ok((rest, option.trim()))
+}
+
 // Views section parser
-pub(crate) fn parse_views_section(i: &str) -> ParserResult<Vec<ParsedView>> {
-    let (i, _) = parse_md::heading("Views", i)?;
-    let (i, views) = many1(parse_view).parse(i)?;
+pub(crate) fn parse_views_section<'a>(
+    config: &ParserConfig,
+    i: &'a str,
+) -> ParserResult<'a, Vec<ParsedView>> {
+    let (i, _) = parse_md::heading(&config.views, i)?;
+    let (i, views) = many0(|i| parse_view(config, i)).parse(i)?;
     let (i, _) = many0(line_ending).parse(i)?;
 
     Ok((i, views))
 }
 
 // Parse a view in the new format
-pub(crate) fn parse_view(i: &str) -> ParserResult<ParsedView> {
+pub(crate) fn parse_view<'a>(config: &ParserConfig, i: &'a str) -> ParserResult<'a, ParsedView> {
     let (i, name) = parse_md::list_item(i)?;
     let (i, _) = opt(line_ending).parse(i)?;
 
@@ -326,18 +592,18 @@ pub(crate) fn parse_view(i: &str) -> ParserResult<ParsedView> {
         // TODO: Make this more robust instead of trying to match "      " directly
 
         // Check if this is a deeper indented line (for manual sorting)
-        if current_input.starts_with("      ") {
-            if let Some(current_sort) = &mut current_column_sort {
-                // This is a card ID under a column - must be a number
-                match parse_number_option(current_input) {
-                    Ok((new_input, id)) => {
-                        current_sort.order.push(id);
-                        let (after_line, _) = opt(line_ending).parse(new_input)?;
-                        current_input = after_line;
-                        continue;
-                    }
-                    Err(e) => return Err(e), // Return error if not a number
+        if current_input.starts_with("      ")
+            && let Some(current_sort) = &mut current_column_sort
+        {
+            // This is a card ID under a column - must be a number
+            match parse_number_option(current_input) {
+                Ok((new_input, id)) => {
+                    current_sort.order.push(id);
+                    let (after_line, _) = opt(line_ending).parse(new_input)?;
+                    current_input = after_line;
+                    continue;
                 }
+                Err(e) => return Err(e), // Return error if not a number
             }
         }
 
@@ -378,7 +644,7 @@ pub(crate) fn parse_view(i: &str) -> ParserResult<ParsedView> {
                 let attr_value = parts[1].trim();
 
                 match attr_name {
-                    "Layout" => {
+                    _ if attr_name == config.layout => {
                         view_type = Some(match attr_value {
                             "Board" => ParsedViewType::Board,
                             "Table" => ParsedViewType::Table,
@@ -387,10 +653,10 @@ pub(crate) fn parse_view(i: &str) -> ParserResult<ParsedView> {
                             _ => ParsedViewType::Board, // Default to Board for unknown types
                         });
                     }
-                    "Group" => group_by = Some(attr_value.to_string()),
-                    "Filter" => filter = Some(attr_value.to_string()),
-                    "Sort" => sort_by = Some(attr_value.to_string()),
-                    "Sort Type" => {
+                    _ if attr_name == config.group => group_by = Some(attr_value.to_string()),
+                    _ if attr_name == config.filter => filter = Some(attr_value.to_string()),
+                    _ if attr_name == config.sort => sort_by = Some(attr_value.to_string()),
+                    _ if attr_name == config.sort_type => {
                         sort_type = match attr_value {
                             "Alpha" => KanbanSortType::Alpha,
                             "ReverseAlpha" => KanbanSortType::ReverseAlpha,
@@ -398,7 +664,7 @@ pub(crate) fn parse_view(i: &str) -> ParserResult<ParsedView> {
                             _ => KanbanSortType::None,
                         };
                     }
-                    "Display" => display = Some(attr_value.to_string()),
+                    _ if attr_name == config.display => display = Some(attr_value.to_string()),
                     _ => {} // Ignore unknown attributes
                 }
             }
@@ -436,7 +702,7 @@ pub(crate) fn parse_view(i: &str) -> ParserResult<ParsedView> {
         },
     ))
 }
-pub(crate) fn parse_number_option(i: &str) -> ParserResult<usize> {
+pub(crate) fn parse_number_option(i: &str) -> ParserResult<'_, usize> {
     let (i, option) = parse_property_option(i)?;
 
     match option.trim().parse::<usize>() {
@@ -0,0 +1,744 @@
+// crates/kandown/src/sync.rs
+
+use anyhow::{Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::{
+    ColumnSort, Kanban, ParsedCard, ParsedDocument, ParsedPropertyType, ParsedPropertyValue,
+    ParsedViewType, Subtask, parse_subtask,
+};
+
+/// Lamport timestamp identifying an operation. Comparing the counter, then the
+/// replica, gives every replica the same total order for resolving conflicts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Timestamp {
+    pub counter: u64,
+    pub replica: u64,
+}
+
+// Cards are identified by the timestamp of the operation that inserted them.
+// Cards of the base document use replica 0 and their position + 1.
+pub type CardId = Timestamp;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardField {
+    Title,
+    Description,
+    Property(String),
+    // A checklist item, identified by the operation that added it. The value
+    // is `[ ] text` or `[x] text`; empty removes the item.
+    Subtask(Timestamp),
+}
+
+/// A board edit that can be applied on any replica, in any order, any number
+/// of times, with the same result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Operation {
+    InsertCard {
+        id: Timestamp,
+        title: String,
+        description: String,
+        properties: BTreeMap<String, String>,
+    },
+    // Deletes win over concurrent edits
+    DeleteCard {
+        id: Timestamp,
+        card: CardId,
+    },
+    // Last writer wins per field. An empty property value removes it.
+    SetField {
+        id: Timestamp,
+        card: CardId,
+        field: CardField,
+        value: String,
+    },
+    // Last writer wins for the whole order of a column in a manual view
+    ReorderColumn {
+        id: Timestamp,
+        view: String,
+        column: String,
+        order: Vec<CardId>,
+    },
+}
+
+impl Operation {
+    pub fn id(&self) -> Timestamp {
+        match self {
+            Operation::InsertCard { id, .. }
+            | Operation::DeleteCard { id, .. }
+            | Operation::SetField { id, .. }
+            | Operation::ReorderColumn { id, .. } => *id,
+        }
+    }
+}
+
+/// Messages of the sync protocol, sent as JSON text frames over a WebSocket.
+/// A client connects, receives `Welcome`, then both sides exchange
+/// `Operations`. Clients apply their own operations locally before sending
+/// them, so the server only echoes other clients' operations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SyncMessage {
+    // Server to client: the replica id to use, the board the session started
+    // from and every operation since
+    Welcome {
+        replica: u64,
+        markdown: String,
+        operations: Vec<Operation>,
+    },
+    Operations {
+        operations: Vec<Operation>,
+    },
+    // Server to client: an operation that failed validation and was dropped
+    Rejected {
+        id: Timestamp,
+        reason: String,
+    },
+    // Server to client: the session is over, e.g. because the board changed
+    // outside it. Rejoin to continue from the saved board.
+    Ended {
+        reason: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Register<T> {
+    value: T,
+    stamp: Timestamp,
+}
+
+impl<T> Register<T> {
+    fn set(&mut self, value: T, stamp: Timestamp) {
+        if stamp > self.stamp {
+            self.value = value;
+            self.stamp = stamp;
+        }
+    }
+}
+
+// Fields may arrive before the insert that creates the card, so a card only
+// becomes visible once it has been inserted
+#[derive(Debug, Clone, Default)]
+struct CardState {
+    inserted: bool,
+    deleted: bool,
+    fields: BTreeMap<CardField, Register<String>>,
+}
+
+impl CardState {
+    fn set(&mut self, field: CardField, value: String, stamp: Timestamp) {
+        match self.fields.get_mut(&field) {
+            Some(register) => register.set(value, stamp),
+            None => {
+                self.fields.insert(field, Register { value, stamp });
+            }
+        }
+    }
+
+    fn get(&self, field: &CardField) -> &str {
+        self.fields
+            .get(field)
+            .map(|register| register.value.as_str())
+            .unwrap_or_default()
+    }
+}
+
+/// One copy of a board being edited collaboratively. The properties and views
+/// come from the base document and are not edited; cards and manual column
+/// orders are merged from operations.
+#[derive(Debug, Clone)]
+pub struct BoardReplica {
+    replica: u64,
+    clock: u64,
+    schema: ParsedDocument, // The base document without its cards
+    cards: BTreeMap<CardId, CardState>,
+    column_orders: BTreeMap<(String, String), Register<Vec<CardId>>>,
+    applied: HashSet<Timestamp>,
+}
+
+impl BoardReplica {
+    pub fn new(replica: u64, mut doc: ParsedDocument) -> Self {
+        let base = Timestamp {
+            counter: 0,
+            replica: 0,
+        };
+        let card_ids: HashMap<usize, CardId> = doc
+            .cards
+            .iter()
+            .enumerate()
+            .map(|(idx, card)| (card.id, base_card_id(idx)))
+            .collect();
+
+        let mut cards = BTreeMap::new();
+        let mut subtask_count = 0;
+        for (idx, card) in doc.cards.drain(..).enumerate() {
+            let id = base_card_id(idx);
+            let mut state = CardState {
+                inserted: true,
+                ..CardState::default()
+            };
+            state.set(CardField::Title, card.title, id);
+            state.set(CardField::Description, card.description, id);
+            for value in card.properties {
+                state.set(CardField::Property(value.property_name), value.value, id);
+            }
+            for (idx, subtask) in card.subtasks.iter().enumerate() {
+                state.set(
+                    CardField::Subtask(base_card_id(idx)),
+                    subtask_value(&subtask.text, subtask.done),
+                    id,
+                );
+            }
+            subtask_count = subtask_count.max(card.subtasks.len());
+            cards.insert(id, state);
+        }
+
+        let mut column_orders = BTreeMap::new();
+        for view in &mut doc.views {
+            for sort in view.column_sorts.drain(..) {
+                let order = sort
+                    .order
+                    .iter()
+                    .filter_map(|id| card_ids.get(id).copied())
+                    .collect();
+                column_orders.insert(
+                    (view.name.clone(), sort.column),
+                    Register {
+                        value: order,
+                        stamp: base,
+                    },
+                );
+            }
+        }
+
+        BoardReplica {
+            replica,
+            // Later ids sort after every base card and subtask
+            clock: cards.len().max(subtask_count) as u64,
+            schema: doc,
+            cards,
+            column_orders,
+            applied: HashSet::new(),
+        }
+    }
+
+    pub fn from_markdown(replica: u64, markdown: &str) -> Result<Self> {
+        let doc = ParsedDocument::parse_complete(markdown)?;
+        Ok(BoardReplica::new(replica, doc))
+    }
+
+    // Join a session from the server's welcome message
+    pub fn from_welcome(message: &SyncMessage) -> Result<Self> {
+        let SyncMessage::Welcome {
+            replica,
+            markdown,
+            operations,
+        } = message
+        else {
+            bail!("Expected a welcome message");
+        };
+
+        let mut board = BoardReplica::from_markdown(*replica, markdown)?;
+        for op in operations {
+            board.apply(op)?;
+        }
+        Ok(board)
+    }
+
+    pub fn replica(&self) -> u64 {
+        self.replica
+    }
+
+    // Apply an operation from any replica. Returns false if it was already
+    // applied. Invalid operations are refused the same way on every replica,
+    // so refusing them does not break convergence.
+    pub fn apply(&mut self, op: &Operation) -> Result<bool> {
+        if self.applied.contains(&op.id()) {
+            return Ok(false);
+        }
+        self.check(op)?;
+
+        self.applied.insert(op.id());
+        self.clock = self.clock.max(op.id().counter);
+
+        match op.clone() {
+            Operation::InsertCard {
+                id,
+                title,
+                description,
+                properties,
+            } => {
+                let properties = self.insert_values(&properties)?;
+                let card = self.cards.entry(id).or_default();
+                card.inserted = true;
+                card.set(CardField::Title, title, id);
+                card.set(CardField::Description, description, id);
+                for (name, value) in properties {
+                    card.set(CardField::Property(name), value, id);
+                }
+            }
+            Operation::DeleteCard { card, .. } => {
+                self.cards.entry(card).or_default().deleted = true;
+            }
+            Operation::SetField {
+                id,
+                card,
+                field,
+                value,
+            } => self.cards.entry(card).or_default().set(field, value, id),
+            Operation::ReorderColumn {
+                id,
+                view,
+                column,
+                order,
+            } => match self.column_orders.get_mut(&(view.clone(), column.clone())) {
+                Some(register) => register.set(order, id),
+                None => {
+                    self.column_orders.insert(
+                        (view, column),
+                        Register {
+                            value: order,
+                            stamp: id,
+                        },
+                    );
+                }
+            },
+        }
+
+        Ok(true)
+    }
+
+    // Apply the operations of a sync message, returning whether anything
+    // changed
+    pub fn apply_message(&mut self, message: &SyncMessage) -> Result<bool> {
+        match message {
+            SyncMessage::Operations { operations } => {
+                let mut changed = false;
+                for op in operations {
+                    changed |= self.apply(op)?;
+                }
+                Ok(changed)
+            }
+            SyncMessage::Rejected { id, reason } => Err(anyhow!(
+                "Operation {}@{} was rejected: {reason}",
+                id.counter,
+                id.replica
+            )),
+            SyncMessage::Ended { reason } => bail!("The sync session ended: {reason}"),
+            SyncMessage::Welcome { .. } => bail!("Already joined the session"),
+        }
+    }
+
+    fn check(&self, op: &Operation) -> Result<()> {
+        match op {
+            Operation::InsertCard {
+                title, properties, ..
+            } => {
+                if title.trim().is_empty() {
+                    bail!("Card title cannot be empty");
+                }
+                for (name, value) in &self.insert_values(properties)? {
+                    self.check_value(name, value)?;
+                }
+            }
+            Operation::SetField { field, value, .. } => match field {
+                CardField::Title if value.trim().is_empty() => bail!("Card title cannot be empty"),
+                CardField::Property(name) => self.check_value(name, value)?,
+                CardField::Subtask(_)
+                    if !value.is_empty() && parse_subtask_value(value).is_none() =>
+                {
+                    bail!("Invalid subtask: {value}")
+                }
+                _ => {}
+            },
+            Operation::ReorderColumn { view, .. } => {
+                if !self.schema.views.iter().any(|v| v.name == *view) {
+                    bail!("View not found: {view}");
+                }
+            }
+            Operation::DeleteCard { .. } => {}
+        }
+
+        Ok(())
+    }
+
+    // The values a new card starts with: the given ones plus the defaults of
+    // omitted properties, as Kanban::add_card fills them in. Fails when a
+    // required property is left without a value.
+    fn insert_values(
+        &self,
+        properties: &BTreeMap<String, String>,
+    ) -> Result<BTreeMap<String, String>> {
+        let mut values = properties.clone();
+
+        for prop in &self.schema.properties {
+            if let Some(default) = &prop.rules.default
+                && !values.contains_key(&prop.name)
+            {
+                values.insert(prop.name.clone(), default.clone());
+            }
+            if prop.rules.required && values.get(&prop.name).is_none_or(|v| v.is_empty()) {
+                bail!("Missing required property: {}", prop.name);
+            }
+        }
+
+        Ok(values)
+    }
+
+    fn check_value(&self, property: &str, value: &str) -> Result<()> {
+        let prop = self
+            .schema
+            .properties
+            .iter()
+            .find(|p| p.name == property)
+            .ok_or_else(|| anyhow!("Unknown property: {property}"))?;
+
+        if value.is_empty() {
+            if prop.rules.required {
+                bail!("{property} is required and cannot be removed");
+            }
+            return Ok(());
+        }
+
+        match &prop.property_type {
+            ParsedPropertyType::Select { options } if !options.iter().any(|o| o == value) => {
+                Err(anyhow!("Invalid value for {property}: {value}"))
+            }
+            ParsedPropertyType::Checkbox if value != "true" && value != "false" => {
+                Err(anyhow!("Invalid value for checkbox {property}: {value}"))
+            }
+            ParsedPropertyType::Formula { .. } => Err(anyhow!(
+                "{property} is computed by a formula and cannot be set"
+            )),
+            _ => prop.rules.check(property, value),
+        }
+    }
+
+    fn next_id(&mut self) -> Timestamp {
+        self.clock += 1;
+        Timestamp {
+            counter: self.clock,
+            replica: self.replica,
+        }
+    }
+
+    // Apply a locally made operation and return it for sending
+    fn local(&mut self, make: impl FnOnce(Timestamp) -> Operation) -> Result<Operation> {
+        let op = make(self.next_id());
+        self.apply(&op)?;
+        Ok(op)
+    }
+
+    pub fn insert_card(
+        &mut self,
+        title: &str,
+        description: &str,
+        properties: HashMap<String, String>,
+    ) -> Result<Operation> {
+        if self.card_id(title).is_some() {
+            bail!("A card named '{title}' already exists");
+        }
+
+        self.local(|id| Operation::InsertCard {
+            id,
+            title: title.to_string(),
+            description: description.to_string(),
+            properties: properties.into_iter().collect(),
+        })
+    }
+
+    pub fn delete_card(&mut self, card: CardId) -> Result<Operation> {
+        self.visible_card(card)?;
+        self.local(|id| Operation::DeleteCard { id, card })
+    }
+
+    pub fn set_field(&mut self, card: CardId, field: CardField, value: &str) -> Result<Operation> {
+        self.visible_card(card)?;
+        if field == CardField::Title && self.card_id(value).is_some_and(|other| other != card) {
+            bail!("A card named '{value}' already exists");
+        }
+
+        self.local(|id| Operation::SetField {
+            id,
+            card,
+            field,
+            value: value.to_string(),
+        })
+    }
+
+    // Move a card to a column of a board view by setting its group property
+    pub fn move_card(&mut self, card: CardId, view_name: &str, column: &str) -> Result<Operation> {
+        let view = self
+            .schema
+            .views
+            .iter()
+            .find(|v| v.name == view_name)
+            .ok_or_else(|| anyhow!("View not found: {view_name}"))?;
+        let group = match (&view.layout, &view.group) {
+            (ParsedViewType::Board, Some(group)) => group.clone(),
+            (ParsedViewType::Board, None) => bail!("Board view doesn't have a group_by property"),
+            _ => bail!("View is not a Board view"),
+        };
+
+        self.set_field(card, CardField::Property(group), column)
+    }
+
+    // Add an unchecked item to a card's checklist
+    pub fn add_subtask(&mut self, card: CardId, text: &str) -> Result<Operation> {
+        self.visible_card(card)?;
+        let text = text.trim();
+        if text.is_empty() || text.contains(['\n', '\r']) {
+            bail!("Subtask text must be a single non-empty line");
+        }
+
+        self.local(|id| Operation::SetField {
+            id,
+            card,
+            field: CardField::Subtask(id),
+            value: subtask_value(text, false),
+        })
+    }
+
+    // Check or uncheck a subtask, given its position in the card's checklist
+    pub fn toggle_subtask(&mut self, card: CardId, index: usize) -> Result<Operation> {
+        let (subtask, text, done) = self.subtask(card, index)?;
+        self.set_field(
+            card,
+            CardField::Subtask(subtask),
+            &subtask_value(&text, !done),
+        )
+    }
+
+    pub fn remove_subtask(&mut self, card: CardId, index: usize) -> Result<Operation> {
+        let (subtask, _, _) = self.subtask(card, index)?;
+        self.set_field(card, CardField::Subtask(subtask), "")
+    }
+
+    // A card's subtasks in checklist order, with the ids that address them
+    fn subtasks(state: &CardState) -> Vec<(Timestamp, String, bool)> {
+        state
+            .fields
+            .iter()
+            .filter_map(|(field, register)| match field {
+                CardField::Subtask(id) => parse_subtask_value(&register.value)
+                    .map(|subtask| (*id, subtask.text, subtask.done)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn subtask(&self, card: CardId, index: usize) -> Result<(Timestamp, String, bool)> {
+        let state = self.visible_card(card)?;
+        BoardReplica::subtasks(state)
+            .into_iter()
+            .nth(index)
+            .ok_or_else(|| anyhow!("Card has no subtask {index}"))
+    }
+
+    pub fn reorder_column(
+        &mut self,
+        view: &str,
+        column: &str,
+        order: Vec<CardId>,
+    ) -> Result<Operation> {
+        for card in &order {
+            self.visible_card(*card)?;
+        }
+
+        self.local(|id| Operation::ReorderColumn {
+            id,
+            view: view.to_string(),
+            column: column.to_string(),
+            order,
+        })
+    }
+
+    fn visible_card(&self, card: CardId) -> Result<&CardState> {
+        self.cards
+            .get(&card)
+            .filter(|state| state.inserted && !state.deleted)
+            .ok_or_else(|| anyhow!("Card not found: {}@{}", card.counter, card.replica))
+    }
+
+    // Visible cards in document order with the titles they are shown under.
+    // Concurrent inserts can produce the same title; later cards get a
+    // numbered suffix so titles stay unique.
+    pub fn titles(&self) -> Vec<(CardId, String)> {
+        let mut seen = HashSet::new();
+
+        self.cards
+            .iter()
+            .filter(|(_, state)| state.inserted && !state.deleted)
+            .map(|(id, state)| {
+                let title = state.get(&CardField::Title).to_string();
+                let mut unique = title.clone();
+                let mut n = 2;
+                while !seen.insert(unique.clone()) {
+                    unique = format!("{title} ({n})");
+                    n += 1;
+                }
+                (*id, unique)
+            })
+            .collect()
+    }
+
+    pub fn card_id(&self, title: &str) -> Option<CardId> {
+        self.titles()
+            .into_iter()
+            .find(|(_, t)| t == title)
+            .map(|(id, _)| id)
+    }
+
+    pub fn to_document(&self) -> ParsedDocument {
+        let titles = self.titles();
+        let positions: HashMap<CardId, usize> = titles
+            .iter()
+            .enumerate()
+            .map(|(position, (id, _))| (*id, position))
+            .collect();
+
+        let cards = titles
+            .into_iter()
+            .enumerate()
+            .map(|(position, (id, title))| {
+                let state = &self.cards[&id];
+                let properties = state
+                    .fields
+                    .iter()
+                    .filter_map(|(field, register)| match field {
+                        CardField::Property(name) if !register.value.is_empty() => {
+                            Some(ParsedPropertyValue {
+                                property_name: name.clone(),
+                                value: register.value.clone(),
+                            })
+                        }
+                        _ => None,
+                    })
+                    .collect();
+
+                ParsedCard {
+                    id: position,
+                    title,
+                    description: state.get(&CardField::Description).to_string(),
+                    properties,
+                    subtasks: BoardReplica::subtasks(state)
+                        .into_iter()
+                        .map(|(_, text, done)| Subtask { text, done })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        let mut doc = self.schema.clone();
+        doc.cards = cards;
+        for view in &mut doc.views {
+            view.column_sorts = self
+                .column_orders
+                .iter()
+                .filter(|((view_name, _), _)| *view_name == view.name)
+                .map(|((_, column), register)| ColumnSort {
+                    column: column.clone(),
+                    order: register
+                        .value
+                        .iter()
+                        .filter_map(|id| positions.get(id).copied())
+                        .collect(),
+                })
+                .filter(|sort| !sort.order.is_empty())
+                .collect();
+        }
+        doc.normalize();
+
+        doc
+    }
+
+    pub fn to_kanban(&self) -> Result<Kanban> {
+        Kanban::from_document(self.to_document())
+    }
+
+    pub fn to_markdown(&self) -> Result<String> {
+        Ok(self.to_kanban()?.to_parsed_document().to_string())
+    }
+}
+
+fn subtask_value(text: &str, done: bool) -> String {
+    let mark = if done { 'x' } else { ' ' };
+    format!("[{mark}] {text}")
+}
+
+fn parse_subtask_value(value: &str) -> Option<Subtask> {
+    parse_subtask(&format!("- {value}"))
+}
+
+fn base_card_id(idx: usize) -> CardId {
+    Timestamp {
+        counter: idx as u64 + 1,
+        replica: 0,
+    }
+}
+
+/// Relay for one board's sync session. Keeps the board the session started
+/// from and every accepted operation, so clients joining late can catch up.
+/// Transport is left to the caller.
+#[derive(Debug, Clone)]
+pub struct SyncHub {
+    base: String,
+    board: BoardReplica, // Replica 0, used to validate operations
+    log: Vec<Operation>,
+    next_replica: u64,
+}
+
+impl SyncHub {
+    pub fn new(markdown: &str) -> Result<Self> {
+        Ok(SyncHub {
+            base: markdown.to_string(),
+            board: BoardReplica::from_markdown(0, markdown)?,
+            log: Vec::new(),
+            next_replica: 1,
+        })
+    }
+
+    // Register a new client, returning its replica id and the welcome message
+    // to send it
+    pub fn join(&mut self) -> (u64, SyncMessage) {
+        let replica = self.next_replica;
+        self.next_replica += 1;
+
+        let welcome = SyncMessage::Welcome {
+            replica,
+            markdown: self.base.clone(),
+            operations: self.log.clone(),
+        };
+        (replica, welcome)
+    }
+
+    // Apply operations received from a client. Returns the new operations to
+    // broadcast to the other clients, and rejections to send back.
+    pub fn receive(&mut self, operations: Vec<Operation>) -> (Vec<Operation>, Vec<SyncMessage>) {
+        let mut accepted = Vec::new();
+        let mut rejected = Vec::new();
+
+        for op in operations {
+            match self.board.apply(&op) {
+                Ok(true) => {
+                    self.log.push(op.clone());
+                    accepted.push(op);
+                }
+                Ok(false) => {}
+                Err(e) => rejected.push(SyncMessage::Rejected {
+                    id: op.id(),
+                    reason: e.to_string(),
+                }),
+            }
+        }
+
+        (accepted, rejected)
+    }
+
+    pub fn board(&self) -> &BoardReplica {
+        &self.board
+    }
+}
@@ -1,3 +1,23 @@
 // crates/kandown/src/test/mod.rs
+mod test_csv;
+mod test_dependencies;
+mod test_diff;
+mod test_exchange;
+mod test_formula;
+mod test_gfm;
+mod test_hierarchy;
+mod test_history;
+mod test_html;
+mod test_importers;
 mod test_kanban;
+mod test_keywords;
+mod test_lint;
+mod test_merge;
+mod test_metadata;
+mod test_migrate;
 mod test_parser;
+mod test_rules;
+mod test_sections;
+mod test_subtasks;
+mod test_sync;
+mod test_workspace;
@@ -0,0 +1,252 @@
+// crates/kandown/src/test/test_csv.rs
+use crate::*;
+
+const BOARD: &str = r#"# Properties
+- Status: Select
+	- Backlog
+	- In Progress
+	- Done
+
+- Owner: Text
+
+- Points: Number
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+  Sort Type: Alpha
+  Display: Owner, Points
+
+- Open Work
+  Layout: Table
+  Sort: Points
+  Filter: Status != Done
+  Display: Status, Points
+
+# Cards
+- Write spec
+  Status: In Progress
+  Owner: alice
+  Points: 5
+
+- Ship it
+  Status: Done
+  Owner: bob
+  Points: 3
+
+- Add tests
+  Status: Backlog
+  Owner: carol
+  Points: 10
+
+- Benchmark, then tune
+  Status: Backlog
+  Owner: dave
+  Points: 2
+"#;
+
+fn board() -> Kanban {
+    Kanban::from_document(ParsedDocument::try_from(BOARD).unwrap()).unwrap()
+}
+
+#[test]
+fn test_export_board_view_csv() {
+    let csv = board().export_view_csv("Board").unwrap();
+
+    assert_eq!(
+        csv,
+        "Title,Owner,Points\n\
+         Add tests,carol,10\n\
+         \"Benchmark, then tune\",dave,2\n\
+         Write spec,alice,5\n\
+         Ship it,bob,3\n"
+    );
+}
+
+#[test]
+fn test_export_table_view_csv_is_filtered_and_sorted() {
+    let csv = board().export_view_csv("Open Work").unwrap();
+
+    assert_eq!(
+        csv,
+        "Title,Status,Points\n\
+         \"Benchmark, then tune\",Backlog,2\n\
+         Write spec,In Progress,5\n\
+         Add tests,Backlog,10\n"
+    );
+}
+
+#[test]
+fn test_import_csv_maps_headers() {
+    let mut board = board();
+    let input = "title,STATUS,Points,Notes,Description\n\
+                 Fix login,Backlog,1,ignored,Users cannot log in\n\
+                 ,Backlog,2,,\n";
+
+    let report = board
+        .import_csv(input, &CsvImportOptions::default())
+        .unwrap();
+
+    assert_eq!(report.columns[0].target, CsvColumnTarget::Title);
+    assert_eq!(
+        report.columns[1].target,
+        CsvColumnTarget::Property("Status".to_string())
+    );
+    assert_eq!(report.columns[3].target, CsvColumnTarget::Ignored);
+    assert_eq!(report.columns[4].target, CsvColumnTarget::Description);
+
+    assert_eq!(report.added_cards, vec!["Fix login".to_string()]);
+    assert_eq!(report.errors.len(), 1);
+    assert_eq!(report.errors[0].line, 3);
+    assert_eq!(report.errors[0].message, "Missing title");
+
+    let card = board.card_by_title.get("Fix login").unwrap().borrow();
+    assert_eq!(card.description, "Users cannot log in");
+    assert_eq!(card.get_value("Status"), Some("Backlog"));
+    assert_eq!(card.get_value("Points"), Some("1"));
+}
+
+#[test]
+fn test_import_csv_select_policies() {
+    let input = "Title,Status\nTriage bugs,Blocked\nReview,Blocked\n";
+
+    // Rejecting unknown options skips the rows
+    let mut rejecting = board();
+    let report = rejecting
+        .import_csv(input, &CsvImportOptions::default())
+        .unwrap();
+    assert!(report.added_cards.is_empty());
+    assert_eq!(report.errors.len(), 2);
+    assert!(report.errors[0].message.contains("Blocked"));
+    assert_eq!(rejecting.cards.len(), 4);
+
+    // Creating options adds the value once and imports both rows
+    let mut creating = board();
+    let report = creating
+        .import_csv(
+            input,
+            &CsvImportOptions {
+                select_policy: CsvSelectPolicy::CreateOption,
+                dry_run: false,
+            },
+        )
+        .unwrap();
+    assert_eq!(report.added_cards.len(), 2);
+    assert_eq!(report.created_options.len(), 1);
+    assert_eq!(creating.cards.len(), 6);
+
+    let groups = creating.get_cards_by_group("Board").unwrap();
+    assert_eq!(groups["Blocked"].len(), 2);
+}
+
+#[test]
+fn test_import_csv_dry_run() {
+    let mut board = board();
+    let input = "Title,Status\nTriage bugs,Blocked\n";
+
+    let report = board
+        .import_csv(
+            input,
+            &CsvImportOptions {
+                select_policy: CsvSelectPolicy::CreateOption,
+                dry_run: true,
+            },
+        )
+        .unwrap();
+
+    assert!(report.dry_run);
+    assert_eq!(report.added_cards, vec!["Triage bugs".to_string()]);
+    assert_eq!(report.created_options[0].option, "Blocked");
+    assert!(report.to_string().contains("Would add 1 card(s)"));
+
+    // Nothing changed on the board
+    assert_eq!(board.cards.len(), 4);
+    let status = board.property_by_name.get("Status").unwrap().borrow();
+    match &status.property_type {
+        KanbanPropertyType::Select { options } => assert_eq!(options.len(), 3),
+        _ => panic!("Status should be a Select property"),
+    }
+}
+
+#[test]
+fn test_import_csv_requires_title_column() {
+    let mut board = board();
+    let result = board.import_csv("Status\nBacklog\n", &CsvImportOptions::default());
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_descriptions_with_colons_roundtrip() {
+    let mut board = board();
+    let input = "Title,Status,Description\nCall bob,Backlog,Note: call bob at 5\n";
+    board
+        .import_csv(input, &CsvImportOptions::default())
+        .unwrap();
+    board
+        .add_card(
+            "Read docs".to_string(),
+            "http://example.com".to_string(),
+            Default::default(),
+        )
+        .unwrap();
+
+    let markdown = board.to_parsed_document().to_string();
+    assert!(markdown.contains("- Call bob\n  Status: Backlog\n  \n  Note: call bob at 5\n"));
+
+    let reloaded =
+        Kanban::from_document(ParsedDocument::try_from(markdown.as_str()).unwrap()).unwrap();
+    let description = |title: &str| reloaded.card_by_title[title].borrow().description.clone();
+    assert_eq!(description("Call bob"), "Note: call bob at 5");
+    assert_eq!(description("Read docs"), "http://example.com");
+    assert_eq!(reloaded.to_parsed_document().to_string(), markdown);
+}
+
+#[test]
+fn test_import_csv_checks_required_properties() {
+    let markdown = BOARD.replace("- Owner: Text\n", "- Owner: Text\n\tRequired: true\n");
+    let input = "Title,Status,Owner\n\
+                 Fix login,Backlog,alice\n\
+                 Triage bugs,Blocked,\n\
+                 Write docs,Blocked,bob\n\
+                 Review,Done,\n";
+    let options = |dry_run| CsvImportOptions {
+        select_policy: CsvSelectPolicy::CreateOption,
+        dry_run,
+    };
+
+    let mut board =
+        Kanban::from_document(ParsedDocument::try_from(markdown.as_str()).unwrap()).unwrap();
+    let dry_run = board.import_csv(input, &options(true)).unwrap();
+    let report = board.import_csv(input, &options(false)).unwrap();
+
+    // A dry run reports exactly what the import then does
+    assert_eq!(report.added_cards, ["Fix login", "Write docs"]);
+    assert_eq!(dry_run.added_cards, report.added_cards);
+    assert_eq!(dry_run.created_options, report.created_options);
+    assert_eq!(dry_run.errors, report.errors);
+
+    let errors: Vec<(u64, &str)> = report
+        .errors
+        .iter()
+        .map(|e| (e.line, e.message.as_str()))
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            (3, "Missing required property: Owner"),
+            (5, "Missing required property: Owner")
+        ]
+    );
+
+    // The option is created once, by the row that was imported
+    assert_eq!(report.created_options.len(), 1);
+    assert_eq!(board.cards.len(), 6);
+    let status = board.property_by_name["Status"].borrow();
+    match &status.property_type {
+        KanbanPropertyType::Select { options } => {
+            assert_eq!(options, &["Backlog", "In Progress", "Done", "Blocked"])
+        }
+        _ => panic!("Status should be a Select property"),
+    }
+}
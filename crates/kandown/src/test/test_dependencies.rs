@@ -0,0 +1,214 @@
+// crates/kandown/src/test/test_dependencies.rs
+use crate::*;
+
+const BOARD: &str = r#"# Properties
+- Status: Select
+	- Todo
+	- Doing
+	- Done
+- Blocks: Text
+- Blocked by: Text
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+
+# Cards
+- Deploy
+  Status: Todo
+  Blocked by: Write code, Review
+
+- Write code
+  Status: Done
+  Blocks: Review
+
+- Review
+  Status: Doing
+
+- Docs
+  Status: Todo
+  Blocked by: web#release-notes
+"#;
+
+fn board(markdown: &str) -> Kanban {
+    Kanban::from_document(ParsedDocument::try_from(markdown).unwrap()).unwrap()
+}
+
+fn titles(cards: &[std::rc::Rc<std::cell::RefCell<KanbanCard>>]) -> Vec<String> {
+    cards.iter().map(|c| c.borrow().title.clone()).collect()
+}
+
+#[test]
+fn test_blockers_and_ready_cards() {
+    let board = board(BOARD);
+    let card = |title: &str| board.card_by_title[title].clone();
+
+    // Links count from either side
+    assert_eq!(
+        titles(&board.blockers(&card("Deploy"))),
+        ["Write code", "Review"]
+    );
+    assert_eq!(titles(&board.blockers(&card("Review"))), ["Write code"]);
+    assert_eq!(
+        titles(&board.blocked_cards(&card("Write code"))),
+        ["Deploy", "Review"]
+    );
+
+    assert!(board.is_done(&card("Write code")));
+    assert_eq!(titles(&board.open_blockers(&card("Deploy"))), ["Review"]);
+    assert!(board.is_blocked(&card("Deploy")));
+    assert!(!board.is_blocked(&card("Review")));
+
+    // Cross-board links are left to workspaces
+    assert_eq!(titles(&board.ready_cards()), ["Review", "Docs"]);
+
+    assert_eq!(
+        titles(&board.topological_order().unwrap()),
+        ["Write code", "Review", "Deploy", "Docs"]
+    );
+    assert!(board.dependency_cycles().is_empty());
+    assert!(ParsedDocument::try_from(BOARD).unwrap().lint().is_empty());
+}
+
+#[test]
+fn test_dependency_cycles_and_missing_cards() {
+    let markdown = BOARD
+        .replace(
+            "- Review\n  Status: Doing",
+            "- Review\n  Status: Doing\n  Blocks: Write code",
+        )
+        .replace(
+            "Blocked by: Write code, Review",
+            "Blocked by: Write code, Reveiw",
+        );
+    let board = board(&markdown);
+
+    let cycles = board.dependency_cycles();
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(titles(&cycles[0]), ["Write code", "Review"]);
+
+    let Err(error) = board.topological_order() else {
+        panic!("Expected a cycle error");
+    };
+    assert_eq!(
+        error.to_string(),
+        "Cannot order cards: Cards 'Write code' and 'Review' block each other"
+    );
+
+    let diagnostics = lint_markdown(&markdown);
+    let codes: Vec<(&str, Option<usize>)> = diagnostics
+        .iter()
+        .map(|d| (d.code.as_str(), d.location.as_ref().and_then(|l| l.line)))
+        .collect();
+    assert_eq!(
+        codes,
+        [
+            ("unknown-dependency", Some(15)),
+            ("dependency-cycle", Some(19))
+        ]
+    );
+    assert_eq!(
+        diagnostics[0].message,
+        "Card 'Deploy' depends on 'Reveiw', but there is no such card"
+    );
+}
+
+#[test]
+fn test_links_follow_renamed_cards() {
+    let mut board = board(BOARD);
+    board
+        .edit_card(
+            "Review",
+            Some("Code review".to_string()),
+            None,
+            Default::default(),
+        )
+        .unwrap();
+    let card = |title: &str| board.card_by_title[title].clone();
+
+    assert_eq!(
+        card("Deploy").borrow().get_value(BLOCKED_BY_PROPERTY),
+        Some("Write code, Code review")
+    );
+    assert_eq!(
+        card("Write code").borrow().get_value(BLOCKS_PROPERTY),
+        Some("Code review")
+    );
+    assert_eq!(
+        titles(&board.open_blockers(&card("Deploy"))),
+        ["Code review"]
+    );
+
+    let markdown = board.to_parsed_document().to_string();
+    assert!(lint_markdown(&markdown).is_empty());
+}
+
+#[test]
+fn test_links_to_removed_cards_are_dropped() {
+    let mut board = board(BOARD);
+    let deploy = board.card_by_title["Deploy"].clone();
+    let review = board.card_by_title["Review"].clone();
+    board.remove_card("Write code").unwrap();
+
+    assert_eq!(
+        deploy.borrow().get_value(BLOCKED_BY_PROPERTY),
+        Some("Review")
+    );
+    assert!(board.blockers(&review).is_empty());
+
+    // A card whose only blocker is gone loses the property
+    board.remove_card("Review").unwrap();
+    assert_eq!(deploy.borrow().get_value(BLOCKED_BY_PROPERTY), None);
+    assert!(!board.is_blocked(&deploy));
+    let linked: Vec<String> = board.property_by_name[BLOCKED_BY_PROPERTY]
+        .borrow()
+        .cards
+        .iter()
+        .filter_map(|c| c.upgrade().map(|c| c.borrow().title.clone()))
+        .collect();
+    assert_eq!(linked, ["Docs"]);
+
+    let markdown = board.to_parsed_document().to_string();
+    assert!(lint_markdown(&markdown).is_empty());
+}
+
+#[test]
+fn test_long_blocking_chain() {
+    // Each card blocks the one before it, and the first may block the last
+    let count = 50_000;
+    let chain = |closed: bool| {
+        let mut markdown = "# Properties\n- Blocks: Text\n\n# Cards\n".to_string();
+        for idx in 0..count {
+            markdown.push_str(&format!("- Card {idx}\n"));
+            if idx > 0 {
+                markdown.push_str(&format!("  Blocks: Card {}\n", idx - 1));
+            } else if closed {
+                markdown.push_str(&format!("  Blocks: Card {}\n", count - 1));
+            }
+        }
+        board(&markdown)
+    };
+
+    let cycles = chain(true).dependency_cycles();
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(cycles[0].len(), count);
+
+    let board = chain(false);
+    assert!(board.dependency_cycles().is_empty());
+    let order = board.topological_order().unwrap();
+    assert_eq!(order.len(), count);
+    assert_eq!(order[0].borrow().title, format!("Card {}", count - 1));
+    assert_eq!(order[count - 1].borrow().title, "Card 0");
+
+    // Links worked out once answer the same as the board
+    let links = board.card_links();
+    let card = board.card_by_title["Card 5"].clone();
+    assert_eq!(
+        titles(&links.blockers(&card)),
+        titles(&board.blockers(&card))
+    );
+    assert_eq!(titles(&links.blockers(&card)), ["Card 6"]);
+    assert_eq!(titles(&links.blocked_cards(&card)), ["Card 4"]);
+    assert!(links.is_blocked(&card));
+}
@@ -0,0 +1,124 @@
+// crates/kandown/src/test/test_diff.rs
+use crate::*;
+
+const OLD: &str = r#"# Properties
+- Status: Select
+	- Backlog
+	- Done
+
+- Owner: Text
+
+- Points: Number
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+
+- All
+  Layout: Table
+
+# Cards
+- Write spec
+  Status: Backlog
+  Owner: alice
+
+- Add tests
+  Status: Backlog
+  Points: 3
+
+- Old idea
+"#;
+
+const NEW: &str = r#"# Properties
+- Status: Select
+	- Backlog
+	- Review
+	- Done
+
+- Owner: Text
+
+- Points: Text
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+  Filter: Owner is alice
+
+- Calendar
+  Layout: Calendar
+  Date: Due
+
+# Cards
+- Triage
+  Status: Backlog
+
+- Write the spec
+  Status: Backlog
+  Owner: alice
+
+- Add tests
+  Status: Done
+  Owner: bob
+  Cover the parser
+"#;
+
+#[test]
+fn test_diff() {
+    let old = ParsedDocument::try_from(OLD).unwrap();
+    let new = ParsedDocument::try_from(NEW).unwrap();
+
+    let changes: Vec<String> = old.diff(&new).iter().map(ToString::to_string).collect();
+    assert_eq!(
+        changes,
+        [
+            "Added option 'Review' to 'Status'",
+            "Changed property 'Points' from Number to Text",
+            "Changed filter of view 'Board' from (none) to 'Owner is alice'",
+            "Removed view 'All'",
+            "Added view 'Calendar'",
+            "Removed card 'Old idea'",
+            "Added card 'Triage'",
+            "Renamed card 'Write spec' to 'Write the spec'",
+            "Moved card 'Add tests' from 'Backlog' to 'Done' (Status)",
+            "Changed 'Owner' of card 'Add tests' from (none) to 'bob'",
+            "Changed 'Points' of card 'Add tests' from '3' to (none)",
+            "Changed description of card 'Add tests'",
+        ]
+    );
+
+    assert!(old.diff(&old).is_empty());
+}
+
+#[test]
+fn test_diff_ignores_card_positions() {
+    let old = ParsedDocument::try_from(
+        "# Properties\n- Status: Select\n\t- Backlog\n\n# Views\n- Board\n  Layout: Board\n  Group: Status\n  Sort Type: Manual\n    - Backlog\n      - 1\n      - 0\n\n# Cards\n- A\n  Status: Backlog\n\n- B\n  Status: Backlog\n",
+    )
+    .unwrap();
+    let mut new = old.clone();
+    new.cards.reverse();
+    for (id, card) in new.cards.iter_mut().enumerate() {
+        card.id = id;
+    }
+    new.views[0].column_sorts[0].order = vec![0, 1];
+
+    assert!(old.diff(&new).is_empty());
+
+    new.views[0].column_sorts[0].order = vec![1, 0];
+    let changes = old.diff(&new);
+    assert_eq!(
+        changes,
+        [BoardChange::ViewChanged {
+            view: "Board".to_string(),
+            setting: "order of 'Backlog'".to_string(),
+            from: Some("B, A".to_string()),
+            to: Some("A, B".to_string()),
+        }]
+    );
+    assert_eq!(
+        serde_json::to_value(&changes[0]).unwrap()["kind"],
+        "view_changed"
+    );
+}
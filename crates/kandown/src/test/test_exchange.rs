@@ -0,0 +1,170 @@
+// crates/kandown/src/test/test_exchange.rs
+use crate::*;
+
+const BOARD: &str = r#"# Properties
+- Owner: Text
+
+- Status: Select
+	- Backlog
+	- In Progress
+	- Done
+
+- Points: Number
+
+- Due Date: Date
+
+- Blocked: Checkbox
+
+# Views
+- Task Board
+  Layout: Board
+  Group: Status
+  Sort Type: Manual
+    - Backlog
+      - 1
+      - 0
+    - In Progress
+      - 2
+  Display: Owner, Points
+
+- Deadlines
+  Layout: Calendar
+  Group: Due Date
+
+- All Tasks
+  Layout: Table
+  Sort: Points
+  Sort Type: Alpha
+  Filter: Status != Done
+
+# Cards
+- Task 1
+  Owner: bob
+  Status: Backlog
+  Points: 3
+  Blocked: false
+  This is a description
+  spanning two lines
+
+- Task 2
+  Owner: alice
+  Status: Backlog
+  Points: 2.50
+  Due Date: 2024-03-01
+
+- Task 3
+  Status: In Progress
+  Points: lots
+  Blocked: true
+"#;
+
+#[test]
+fn test_json_roundtrip_is_identity() {
+    let doc = ParsedDocument::try_from(BOARD).unwrap();
+    assert_eq!(doc.to_string(), BOARD);
+
+    let json = doc.to_json().unwrap();
+    let from_json = ParsedDocument::from_json(&json).unwrap();
+
+    assert_eq!(from_json, doc);
+    assert_eq!(from_json.to_string(), BOARD);
+}
+
+#[test]
+fn test_yaml_roundtrip_is_identity() {
+    let doc = ParsedDocument::try_from(BOARD).unwrap();
+
+    let yaml = doc.to_yaml().unwrap();
+    let from_yaml = ParsedDocument::from_yaml(&yaml).unwrap();
+
+    assert_eq!(from_yaml, doc);
+    assert_eq!(from_yaml.to_string(), BOARD);
+}
+
+#[test]
+fn test_json_values_are_typed() {
+    let doc = ParsedDocument::try_from(BOARD).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&doc.to_json().unwrap()).unwrap();
+
+    assert_eq!(json["version"], BOARD_SCHEMA_VERSION);
+    assert_eq!(json["properties"][1]["type"], "Select");
+    assert_eq!(json["properties"][1]["options"][2], "Done");
+    assert_eq!(json["views"][0]["sort_type"], "Manual");
+    assert_eq!(json["views"][0]["column_sorts"][0]["column"], "Backlog");
+    assert_eq!(json["views"][0]["column_sorts"][0]["order"][0], 1);
+
+    let task1 = &json["cards"][0]["properties"];
+    assert_eq!(task1[2]["name"], "Points");
+    assert_eq!(task1[2]["value"], 3);
+    assert_eq!(task1[3]["value"], false);
+
+    // Values that would not survive a numeric round-trip stay as text
+    let task2 = &json["cards"][1]["properties"];
+    assert_eq!(task2[2]["value"], "2.50");
+    assert_eq!(task2[3]["value"], "2024-03-01");
+
+    let task3 = &json["cards"][2]["properties"];
+    assert_eq!(task3[1]["value"], "lots");
+    assert_eq!(task3[2]["value"], true);
+}
+
+#[test]
+fn test_import_handwritten_json() {
+    let json = r#"{
+        "version": 1,
+        "properties": [
+            { "name": "Status", "type": "Select", "options": ["Todo", "Done"] },
+            { "name": "Estimate", "type": "Number" }
+        ],
+        "views": [
+            { "name": "Board", "layout": "Board", "group": "Status" }
+        ],
+        "cards": [
+            {
+                "id": 0,
+                "title": "Write docs",
+                "properties": [
+                    { "name": "Status", "value": "Todo" },
+                    { "name": "Estimate", "value": 5 }
+                ]
+            }
+        ]
+    }"#;
+
+    let doc = ParsedDocument::from_json(json).unwrap();
+    assert_eq!(doc.views[0].sort_type, KanbanSortType::None);
+    assert!(doc.views[0].column_sorts.is_empty());
+    assert_eq!(doc.cards[0].description, "");
+    assert_eq!(doc.cards[0].properties[1].value, "5");
+
+    let board = Kanban::from_document(doc).unwrap();
+    let groups = board.get_cards_by_group("Board").unwrap();
+    assert_eq!(groups["Todo"].len(), 1);
+}
+
+#[test]
+fn test_reject_unsupported_schema_version() {
+    let json = r#"{ "version": 99, "properties": [], "views": [], "cards": [] }"#;
+    let err = ParsedDocument::from_json(json).unwrap_err();
+    assert!(err.to_string().contains("Unsupported board schema version"));
+
+    let yaml = "properties: []\n";
+    assert!(ParsedDocument::from_yaml(yaml).is_err());
+}
+
+#[test]
+fn test_import_schema_version_1() {
+    let json = r#"{
+        "version": 1,
+        "properties": [{ "name": "Status", "type": "Select", "options": ["Todo", "Done"] }],
+        "views": [],
+        "cards": [{ "id": 0, "title": "Write spec", "properties": [{ "name": "Status", "value": "Todo" }] }]
+    }"#;
+    let doc = ParsedDocument::from_json(json).unwrap();
+    assert_eq!(doc.cards[0].title, "Write spec");
+    assert_eq!(doc.cards[0].properties[0].value, "Todo");
+
+    // Exports always use the current version
+    let json: serde_json::Value = serde_json::from_str(&doc.to_json().unwrap()).unwrap();
+    assert_eq!(json["version"], BOARD_SCHEMA_VERSION);
+}
@@ -0,0 +1,244 @@
+// crates/kandown/src/test/test_formula.rs
+use crate::*;
+use std::collections::HashMap;
+
+const BOARD: &str = r#"---
+version: 2
+---
+
+# Properties
+- Status: Select
+	- Todo
+	- Done
+
+- Points: Number
+
+- Created: Date
+
+- Due: Date
+
+- Parent: Text
+
+- Age: Formula(today() - Created)
+
+- Overdue: Formula(Due < today() and Status != Done)
+
+- Points total: Formula(Points + sum_children(Points total))
+
+- Label: Formula(if(Overdue, "Late: " + Title, Title))
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+
+- By overdue
+  Layout: Board
+  Group: Overdue
+
+- Oldest
+  Layout: Table
+  Sort: Age
+
+- Biggest
+  Layout: Table
+  Sort: Points total
+  Sort Type: ReverseAlpha
+
+- Late
+  Layout: Table
+  Filter: Overdue = true and Points total > 10
+
+# Cards
+- Launch
+  Status: Todo
+  Points: 3
+  Created: 2024-03-01
+  Due: 2024-03-05
+
+- Design
+  Status: Done
+  Points: 2
+  Due: 2024-03-01
+  Parent: Launch
+
+- Build
+  Status: Todo
+  Points: 5
+  Created: 2024-03-09
+  Parent: Launch
+
+- API
+  Points: 1
+  Due: 2024-03-20
+  Parent: Build
+"#;
+
+fn board(markdown: &str) -> Kanban {
+    let mut board = Kanban::from_document(ParsedDocument::try_from(markdown).unwrap()).unwrap();
+    board.today = Some("2024-03-10".to_string());
+    board
+}
+
+fn titles(cards: &[std::rc::Rc<std::cell::RefCell<KanbanCard>>]) -> Vec<String> {
+    cards.iter().map(|c| c.borrow().title.clone()).collect()
+}
+
+#[test]
+fn test_formula_values() {
+    let board = board(BOARD);
+    let value =
+        |card: &str, name: &str| board.formula_value(&board.card_by_title[card].borrow(), name);
+
+    assert_eq!(value("Launch", "Age").as_deref(), Some("9"));
+    assert_eq!(value("Build", "Age").as_deref(), Some("1"));
+    assert_eq!(value("API", "Age"), None);
+
+    assert_eq!(value("Launch", "Overdue").as_deref(), Some("true"));
+    assert_eq!(value("Design", "Overdue").as_deref(), Some("false"));
+    assert_eq!(value("API", "Overdue").as_deref(), Some("false"));
+
+    // Rollups reach grandchildren through the children's own totals
+    assert_eq!(value("Launch", "Points total").as_deref(), Some("11"));
+    assert_eq!(value("Build", "Points total").as_deref(), Some("6"));
+    assert_eq!(value("Launch", "Label").as_deref(), Some("Late: Launch"));
+    assert_eq!(value("Build", "Label").as_deref(), Some("Build"));
+
+    // Links worked out beforehand give the same rollups
+    let links = board.card_links();
+    let launch = board.card_by_title["Launch"].borrow();
+    assert_eq!(
+        board.formula_value_with(&links, &launch, "Points total"),
+        value("Launch", "Points total")
+    );
+
+    // Plain properties are not formulas
+    assert_eq!(value("Launch", "Points"), None);
+
+    let doc = ParsedDocument::parse_complete(BOARD).unwrap();
+    assert_eq!(
+        doc.properties[5].property_type,
+        ParsedPropertyType::Formula {
+            expression: "today() - Created".to_string()
+        }
+    );
+    assert_eq!(doc.to_string(), BOARD);
+    assert_eq!(board.to_parsed_document().to_string(), BOARD);
+    assert!(doc.lint().is_empty());
+}
+
+#[test]
+fn test_formulas_in_views() {
+    let board = board(BOARD);
+
+    let groups: Vec<(String, Vec<String>)> = board
+        .get_ordered_groups("By overdue")
+        .unwrap()
+        .into_iter()
+        .map(|(column, cards)| (column, titles(&cards)))
+        .collect();
+    assert_eq!(
+        groups,
+        [
+            (
+                "false".to_string(),
+                vec!["Design".to_string(), "Build".to_string(), "API".to_string()]
+            ),
+            ("true".to_string(), vec!["Launch".to_string()]),
+        ]
+    );
+
+    let view = |name: &str| titles(&board.get_view_cards(name).unwrap());
+    assert_eq!(view("Oldest"), ["Build", "Launch", "Design", "API"]);
+    assert_eq!(view("Late"), ["Launch"]);
+    // Rollups of children sort like any other formula
+    assert_eq!(view("Biggest"), ["Launch", "Build", "Design", "API"]);
+}
+
+#[test]
+fn test_formulas_are_read_only() {
+    let mut board = board(BOARD);
+    let values = |name: &str, value: &str| HashMap::from([(name.to_string(), value.to_string())]);
+
+    let Err(error) = board.edit_card("Launch", None, None, values("Age", "3")) else {
+        panic!("Expected formula edits to fail");
+    };
+    assert_eq!(
+        error.to_string(),
+        "Age is computed by a formula and cannot be set"
+    );
+    assert!(
+        board
+            .add_card("Docs".to_string(), String::new(), values("Overdue", "true"))
+            .is_err()
+    );
+    assert!(board.move_card("Launch", "By overdue", "false").is_err());
+
+    let mut replica = BoardReplica::from_markdown(1, BOARD).unwrap();
+    let launch = replica.card_id("Launch").unwrap();
+    let field = CardField::Property("Age".to_string());
+    assert!(replica.set_field(launch, field, "3").is_err());
+}
+
+#[test]
+fn test_invalid_formulas() {
+    for (expression, message) in [
+        ("Points +", "Formula ends unexpectedly"),
+        ("(Points", "Missing ')' in formula"),
+        ("median(Points)", "Unknown function in formula: median()"),
+        ("if(Points, 1)", "Wrong number of arguments for if()"),
+        ("Points ; 2", "Unexpected ';' in formula"),
+        ("Points 2", "Unexpected '2' in formula"),
+    ] {
+        let Err(error) = Formula::parse(expression) else {
+            panic!("Expected '{expression}' to be rejected");
+        };
+        assert_eq!(error.to_string(), message);
+    }
+
+    let markdown = BOARD
+        .replace("Formula(today() - Created)", "Formula(today() -)")
+        .replace("  Points: 1\n", "  Points: 1\n  Age: 4\n");
+    let diagnostics = lint_markdown(&markdown);
+    let codes: Vec<&str> = diagnostics.iter().map(|d| d.code.as_str()).collect();
+    assert_eq!(codes, ["invalid-formula", "formula-value"]);
+
+    let Err(error) = Kanban::from_document(ParsedDocument::try_from(markdown.as_str()).unwrap())
+    else {
+        panic!("Expected the board to be rejected");
+    };
+    assert_eq!(
+        error.to_string(),
+        "Property 'Age' has an invalid formula: Formula ends unexpectedly"
+    );
+
+    // Formulas reading each other have no value instead of looping
+    let looping = BOARD
+        .replace("Formula(today() - Created)", "Formula(Label)")
+        .replace("\"Late: \" + Title", "Age");
+    let board = board(&looping);
+    let launch = board.card_by_title["Launch"].borrow();
+    assert_eq!(board.formula_value(&launch, "Age"), None);
+}
+
+#[test]
+fn test_date_arithmetic_out_of_range() {
+    let markdown = BOARD.replace(
+        "- Parent: Text\n",
+        "- Parent: Text\n\n\
+         - Next: Formula(Due + 1)\n\n\
+         - Later: Formula(Due + 99999999999999999999)\n\n\
+         - Earlier: Formula(Due - 99999999999999999999)\n\n\
+         - Far: Formula(Due + 3000000)\n",
+    );
+    let board = board(&markdown);
+    let card = board.card_by_title["Launch"].borrow();
+
+    assert_eq!(
+        board.formula_value(&card, "Next").as_deref(),
+        Some("2024-03-06")
+    );
+    assert_eq!(board.formula_value(&card, "Later"), None);
+    assert_eq!(board.formula_value(&card, "Earlier"), None);
+    assert_eq!(board.formula_value(&card, "Far"), None);
+}
@@ -0,0 +1,82 @@
+// crates/kandown/src/test/test_gfm.rs
+use crate::*;
+
+const BOARD: &str = r#"# Properties
+- Status: Select
+	- Backlog
+	- In Progress
+	- Done
+
+- Owner: Text
+
+- Points: Number
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+  Sort Type: Alpha
+
+- Open Work
+  Layout: Table
+  Sort: Points
+  Filter: Status != Done
+  Display: Status, Points
+
+# Cards
+- Write spec
+  Status: In Progress
+  Owner: alice
+  Points: 5
+
+- Ship it
+  Status: Done
+  Owner: bob
+  Points: 3
+
+- Add tests
+  Status: Backlog
+  Owner: carol | dave
+  Points: 10
+"#;
+
+fn board() -> Kanban {
+    Kanban::from_document(ParsedDocument::try_from(BOARD).unwrap()).unwrap()
+}
+
+#[test]
+fn test_render_board_view_gfm() {
+    let markdown = board().render_view_gfm("Board").unwrap();
+
+    assert_eq!(
+        markdown,
+        "## Backlog\n\n\
+         - [ ] Add tests (Owner: carol | dave, Points: 10)\n\n\
+         ## In Progress\n\n\
+         - [ ] Write spec (Owner: alice, Points: 5)\n\n\
+         ## Done\n\n\
+         - [x] Ship it (Owner: bob, Points: 3)\n\n"
+    );
+}
+
+#[test]
+fn test_render_table_view_gfm() {
+    let markdown = board().render_view_gfm("Open Work").unwrap();
+
+    assert_eq!(
+        markdown,
+        "| Title | Status | Points |\n\
+         | --- | --- | --- |\n\
+         | Write spec | In Progress | 5 |\n\
+         | Add tests | Backlog | 10 |\n"
+    );
+}
+
+#[test]
+fn test_render_table_view_gfm_escapes_pipes() {
+    let board = board();
+    board.view_by_name["Open Work"].borrow_mut().display.clear();
+
+    let markdown = board.render_view_gfm("Open Work").unwrap();
+    assert!(markdown.contains("| Add tests | Backlog | carol \\| dave | 10 |"));
+}
@@ -0,0 +1,176 @@
+// crates/kandown/src/test/test_hierarchy.rs
+use crate::*;
+use std::collections::HashMap;
+
+const BOARD: &str = r#"---
+version: 2
+---
+
+# Properties
+- Status: Select
+	- Todo
+	- Doing
+	- Done
+
+- Parent: Text
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+
+- Epics
+  Layout: Table
+  Filter: Parent = ""
+
+- Launch
+  Layout: Table
+  Filter: Parent = Launch
+
+# Cards
+- Launch
+  Status: Doing
+
+- Design
+  Status: Done
+  Parent: Launch
+
+- Build
+  Status: Doing
+  Parent: Launch
+
+- API
+  Status: Todo
+  Parent: Build
+
+- Website
+  Parent: Launch
+
+- Cleanup
+  Status: Todo
+"#;
+
+fn board(markdown: &str) -> Kanban {
+    Kanban::from_document(ParsedDocument::try_from(markdown).unwrap()).unwrap()
+}
+
+fn titles(cards: &[std::rc::Rc<std::cell::RefCell<KanbanCard>>]) -> Vec<String> {
+    cards.iter().map(|c| c.borrow().title.clone()).collect()
+}
+
+#[test]
+fn test_parent_tree_and_rollup() {
+    let board = board(BOARD);
+    let card = |title: &str| board.card_by_title[title].clone();
+
+    assert_eq!(
+        titles(&board.children(&card("Launch"))),
+        ["Design", "Build", "Website"]
+    );
+    assert_eq!(titles(&board.ancestors(&card("API"))), ["Build", "Launch"]);
+    assert_eq!(titles(&board.top_level_cards()), ["Launch", "Cleanup"]);
+    assert!(board.parent(&card("Cleanup")).is_none());
+
+    // Only direct children count, in column order with no status last
+    let rollup = board.child_rollup(&card("Launch")).unwrap();
+    assert_eq!(rollup.total, 3);
+    assert_eq!(rollup.done, 1);
+    assert_eq!(
+        rollup.by_status,
+        [
+            ("Doing".to_string(), 1),
+            ("Done".to_string(), 1),
+            (String::new(), 1)
+        ]
+    );
+    assert!(board.child_rollup(&card("API")).is_none());
+
+    // Views filter to top-level cards or to one epic
+    let view = |name: &str| titles(&board.get_view_cards(name).unwrap());
+    assert_eq!(view("Epics"), ["Launch", "Cleanup"]);
+    assert_eq!(view("Launch"), ["Design", "Build", "Website"]);
+
+    let doc = ParsedDocument::parse_complete(BOARD).unwrap();
+    assert_eq!(doc.to_string(), BOARD);
+    assert_eq!(board.to_parsed_document().to_string(), BOARD);
+    assert!(doc.lint().is_empty());
+}
+
+#[test]
+fn test_parent_edits_reject_cycles() {
+    let mut board = board(BOARD);
+    let parent = |title: &str| HashMap::from([("Parent".to_string(), title.to_string())]);
+
+    let Err(error) = board.edit_card("Launch", None, None, parent("API")) else {
+        panic!("Expected a cycle error");
+    };
+    assert_eq!(
+        error.to_string(),
+        "Card 'Launch' cannot have 'API' as parent, it would be its own ancestor"
+    );
+    assert!(
+        board
+            .edit_card("Build", None, None, parent("Build"))
+            .is_err()
+    );
+    assert!(
+        board
+            .edit_card("Cleanup", None, None, parent("Nope"))
+            .is_err()
+    );
+    assert!(
+        board
+            .add_card("Docs".to_string(), String::new(), parent("Nope"))
+            .is_err()
+    );
+
+    board
+        .add_card("Docs".to_string(), String::new(), parent("Build"))
+        .unwrap();
+    board
+        .edit_card("Cleanup", None, None, parent("Launch"))
+        .unwrap();
+
+    // Renaming a parent keeps its children attached
+    board
+        .edit_card("Build", Some("Backend".to_string()), None, HashMap::new())
+        .unwrap();
+    let backend = board.card_by_title["Backend"].clone();
+    assert_eq!(titles(&board.children(&backend)), ["API", "Docs"]);
+    assert_eq!(
+        board.card_by_title["API"].borrow().get_value("Parent"),
+        Some("Backend")
+    );
+
+    board.edit_card("API", None, None, parent("")).unwrap();
+    assert_eq!(titles(&board.top_level_cards()), ["Launch", "API"]);
+}
+
+#[test]
+fn test_lint_parent_links() {
+    let markdown = BOARD
+        .replace(
+            "- Launch\n  Status: Doing\n",
+            "- Launch\n  Status: Doing\n  Parent: API\n",
+        )
+        .replace("Parent: Launch\n\n- Cleanup", "Parent: Lanch\n\n- Cleanup");
+
+    let diagnostics = lint_markdown(&markdown);
+    let codes: Vec<(&str, Option<usize>)> = diagnostics
+        .iter()
+        .map(|d| (d.code.as_str(), d.location.as_ref().and_then(|l| l.line)))
+        .collect();
+    assert_eq!(
+        codes,
+        [("unknown-parent", Some(43)), ("parent-cycle", Some(27))]
+    );
+    assert_eq!(
+        diagnostics[1].message,
+        "Cards form a parent loop: 'Launch' -> 'API' -> 'Build' -> 'Launch'"
+    );
+
+    // Ancestors stop at the loop
+    let board = board(&markdown);
+    let api = board.card_by_title["API"].clone();
+    assert_eq!(titles(&board.ancestors(&api)), ["Build", "Launch"]);
+}
@@ -0,0 +1,80 @@
+// crates/kandown/src/test/test_history.rs
+use crate::*;
+
+const V1: &str = r#"# Properties
+- Status: Select
+	- Backlog
+	- Doing
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+
+# Cards
+- Write spec
+  Status: Backlog
+
+- Add tests
+  Status: Backlog
+"#;
+
+fn revision(id: &str, author: &str, markdown: String) -> Revision {
+    Revision {
+        id: id.to_string(),
+        author: Some(author.to_string()),
+        date: Some(format!("2026-10-0{}", &id[1..])),
+        markdown,
+    }
+}
+
+fn history() -> Vec<Activity> {
+    let v2 = V1.replace(
+        "- Write spec\n  Status: Backlog",
+        "- Write spec\n  Status: Doing",
+    );
+    let v3 = v2.replace("- Write spec", "- Write the spec");
+    let v4 = v3.replace(
+        "- Add tests\n  Status: Backlog\n",
+        "- Add tests\n  Status: Doing\n",
+    );
+
+    board_history(&[
+        revision("r1", "alice", V1.to_string()),
+        revision("r2", "bob", v2),
+        revision("r3", "carol", "# Cards\nnot a board".to_string()),
+        revision("r4", "carol", v3),
+        revision("r5", "bob", v4),
+    ])
+}
+
+#[test]
+fn test_board_history() {
+    let activity: Vec<String> = history().iter().map(ToString::to_string).collect();
+
+    assert_eq!(
+        activity,
+        [
+            "2026-10-01 alice: Added property 'Status' [r1]",
+            "2026-10-01 alice: Added view 'Board' [r1]",
+            "2026-10-01 alice: Added card 'Write spec' [r1]",
+            "2026-10-01 alice: Added card 'Add tests' [r1]",
+            "2026-10-02 bob: Moved card 'Write spec' from 'Backlog' to 'Doing' (Status) [r2]",
+            "2026-10-04 carol: Renamed card 'Write spec' to 'Write the spec' [r4]",
+            "2026-10-05 bob: Moved card 'Add tests' from 'Backlog' to 'Doing' (Status) [r5]",
+        ]
+    );
+}
+
+#[test]
+fn test_card_history_follows_renames() {
+    let activity = history();
+
+    let card: Vec<&str> = card_history(&activity, "Write the spec")
+        .iter()
+        .map(|item| item.revision.as_str())
+        .collect();
+    assert_eq!(card, ["r1", "r2", "r4"]);
+
+    assert!(card_history(&activity, "Unknown").is_empty());
+}
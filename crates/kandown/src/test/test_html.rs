@@ -0,0 +1,132 @@
+// crates/kandown/src/test/test_html.rs
+use crate::*;
+
+const BOARD: &str = r#"# Properties
+- Status: Select
+	- Backlog
+	- In Progress
+	- Done
+
+- Owner: Text
+
+- Due: Date
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+  Sort Type: ReverseAlpha
+
+- Table
+  Layout: Table
+  Sort: Due
+  Filter: Status != Done
+  Display: Owner, Due
+
+- Calendar
+  Layout: Calendar
+  Group: Due
+
+# Cards
+- Write <spec>
+  Status: In Progress
+  Owner: alice
+  Due: 2024-02-29
+  Needs **review** before <script>alert(1)</script> merge
+
+- Ship it
+  Status: Done
+  Owner: bob
+  Due: 2024-03-04
+
+- Add tests
+  Status: Backlog
+  Owner: carol
+
+- Benchmark
+  Status: Backlog
+  Due: 2024-02-01
+"#;
+
+fn board() -> Kanban {
+    Kanban::from_document(ParsedDocument::try_from(BOARD).unwrap()).unwrap()
+}
+
+#[test]
+fn test_render_board_html() {
+    let html = board().render_view_html("Board").unwrap();
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<style>"));
+    assert!(html.contains("<h2>Backlog <span class=\"count\">2</span></h2>"));
+
+    // Columns follow the Select options and cards follow the view's sorting
+    let backlog = html.find("<h2>Backlog").unwrap();
+    let in_progress = html.find("<h2>In Progress").unwrap();
+    let done = html.find("<h2>Done").unwrap();
+    assert!(backlog < in_progress && in_progress < done);
+    assert!(html.find("Benchmark").unwrap() < html.find("Add tests").unwrap());
+
+    // Titles are escaped and descriptions rendered from markdown
+    assert!(html.contains("<h3>Write &lt;spec&gt;</h3>"));
+    assert!(html.contains("<strong>review</strong>"));
+    assert!(!html.contains("<script>"));
+
+    // Only web, mail and relative link targets are kept
+    let mut doc = ParsedDocument::try_from(BOARD).unwrap();
+    doc.cards[1].description = "[a](javascript:alert(document.cookie)) [b](JAVASCRIPT:alert(1)) \
+        [c](vbscript:msgbox) ![d](data:text/html;base64,PHNjcmlwdD4=) \
+        [e](https://example.com/a:b) [f](mailto:bob@example.com) [g](notes/spec.md)"
+        .to_string();
+    let html = Kanban::from_document(doc)
+        .unwrap()
+        .render_view_html("Board")
+        .unwrap();
+    let lower = html.to_ascii_lowercase();
+    assert!(!lower.contains("javascript"));
+    assert!(!lower.contains("vbscript"));
+    assert!(!lower.contains("data:"));
+    assert!(html.contains("<a href=\"\">a</a>"));
+    assert!(html.contains("<img src=\"\" alt=\"d\" />"));
+    assert!(html.contains("<a href=\"https://example.com/a:b\">e</a>"));
+    assert!(html.contains("<a href=\"mailto:bob@example.com\">f</a>"));
+    assert!(html.contains("<a href=\"notes/spec.md\">g</a>"));
+
+    // Without display properties everything but the grouping is shown
+    assert!(html.contains("<dt>Owner</dt><dd>alice</dd>"));
+    assert!(!html.contains("<dt>Status</dt>"));
+}
+
+#[test]
+fn test_render_table_html() {
+    let html = board().render_view_html("Table").unwrap();
+
+    assert!(html.contains("<tr><th>Title</th><th>Owner</th><th>Due</th></tr>"));
+    assert!(!html.contains("Ship it"));
+
+    let benchmark = html.find("<tr><td>Benchmark</td>").unwrap();
+    let spec = html.find("<tr><td>Write &lt;spec&gt;</td>").unwrap();
+    let tests = html.find("<tr><td>Add tests</td>").unwrap();
+    assert!(benchmark < spec && spec < tests);
+}
+
+#[test]
+fn test_render_calendar_html() {
+    let html = board().render_view_html("Calendar").unwrap();
+
+    assert!(html.contains("<caption>February 2024</caption>"));
+    assert!(html.contains("<caption>March 2024</caption>"));
+    assert!(
+        html.contains("<td><span class=\"day\">29</span><ul><li>Write &lt;spec&gt;</li></ul></td>")
+    );
+    // February 2024 starts on a Thursday
+    assert!(html.contains(
+        "<tr><td class=\"outside\"></td><td class=\"outside\"></td><td class=\"outside\"></td><td><span class=\"day\">1</span>"
+    ));
+    assert!(html.contains("<h2>No date</h2>\n<ul>\n<li>Add tests</li>"));
+}
+
+#[test]
+fn test_render_unknown_view_html() {
+    assert!(board().render_view_html("Missing").is_err());
+}
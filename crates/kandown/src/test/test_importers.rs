@@ -0,0 +1,215 @@
+// crates/kandown/src/test/test_importers.rs
+use crate::*;
+
+// Imported documents must survive a trip through the markdown format
+fn reload(doc: &ParsedDocument) -> Kanban {
+    let markdown = doc.to_string();
+    let reparsed = ParsedDocument::try_from(markdown.as_str()).unwrap();
+    assert_eq!(&reparsed, doc, "markdown:\n{markdown}");
+    Kanban::from_document(reparsed).unwrap()
+}
+
+#[test]
+fn test_import_trello() {
+    let json = r#"{
+        "name": "Website Relaunch",
+        "lists": [
+            { "id": "l2", "name": "Doing", "closed": false, "pos": 2 },
+            { "id": "l1", "name": "To Do", "closed": false, "pos": 1 },
+            { "id": "l3", "name": "Old", "closed": true, "pos": 3 }
+        ],
+        "members": [
+            { "id": "m1", "fullName": "Alice Doe", "username": "alice" }
+        ],
+        "cards": [
+            {
+                "id": "c1", "name": "Design mockups", "idList": "l2", "pos": 1,
+                "desc": "See: https://example.com/figma\nThen review",
+                "labels": [{ "name": "Design", "color": "green" }, { "name": "", "color": "red" }],
+                "idMembers": ["m1"],
+                "due": "2024-03-05T17:00:00.000Z"
+            },
+            { "id": "c2", "name": "Write copy", "idList": "l1", "pos": 2 },
+            { "id": "c3", "name": "Archived", "idList": "l1", "closed": true },
+            { "id": "c4", "name": "In closed list", "idList": "l3" }
+        ]
+    }"#;
+
+    let doc = import_trello(json).unwrap();
+
+    assert_eq!(doc.properties[0].name, "List");
+    assert_eq!(
+        doc.properties[0].property_type,
+        ParsedPropertyType::Select {
+            options: vec!["To Do".to_string(), "Doing".to_string()]
+        }
+    );
+    let names: Vec<&str> = doc.properties.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["List", "Labels", "Members", "Due"]);
+
+    assert_eq!(doc.views.len(), 1);
+    assert_eq!(doc.views[0].name, "Website Relaunch");
+    assert_eq!(doc.views[0].group, Some("List".to_string()));
+
+    assert_eq!(doc.cards.len(), 2);
+    let card = &doc.cards[0];
+    assert_eq!(card.title, "Design mockups");
+    assert_eq!(
+        card.description,
+        "See: https://example.com/figma\nThen review"
+    );
+    let value = |name: &str| {
+        card.properties
+            .iter()
+            .find(|p| p.property_name == name)
+            .map(|p| p.value.as_str())
+    };
+    assert_eq!(value("Labels"), Some("Design, red"));
+    assert_eq!(value("Members"), Some("Alice Doe"));
+    assert_eq!(value("Due"), Some("2024-03-05"));
+
+    let board = reload(&doc);
+    let groups = board.get_cards_by_group("Website Relaunch").unwrap();
+    assert_eq!(groups["Doing"].len(), 1);
+    assert_eq!(groups["To Do"].len(), 1);
+}
+
+#[test]
+fn test_import_github_project() {
+    let json = r#"{
+        "items": [
+            {
+                "id": "PVTI_1",
+                "title": "Fix login",
+                "status": "In Progress",
+                "assignees": ["octocat", "hubot"],
+                "labels": ["bug"],
+                "estimate": 3,
+                "milestone": { "title": "v1.0" },
+                "content": { "type": "Issue", "number": 12, "body": "Steps to reproduce" }
+            },
+            {
+                "id": "PVTI_2",
+                "title": "Add dark mode",
+                "status": "Todo",
+                "estimate": 5,
+                "content": { "type": "DraftIssue", "body": "" }
+            },
+            {
+                "id": "PVTI_3",
+                "title": "Update docs",
+                "status": "Todo",
+                "content": { "type": "PullRequest", "body": "" }
+            }
+        ],
+        "totalCount": 3
+    }"#;
+
+    let doc = import_github_project(json, "Roadmap").unwrap();
+
+    let status = doc.properties.iter().find(|p| p.name == "Status").unwrap();
+    assert_eq!(
+        status.property_type,
+        ParsedPropertyType::Select {
+            options: vec!["In Progress".to_string(), "Todo".to_string()]
+        }
+    );
+    let estimate = doc
+        .properties
+        .iter()
+        .find(|p| p.name == "Estimate")
+        .unwrap();
+    assert_eq!(estimate.property_type, ParsedPropertyType::Number);
+    let assignees = doc
+        .properties
+        .iter()
+        .find(|p| p.name == "Assignees")
+        .unwrap();
+    assert_eq!(assignees.property_type, ParsedPropertyType::Text);
+
+    assert_eq!(doc.views[0].name, "Roadmap");
+    assert_eq!(doc.views[0].layout, ParsedViewType::Board);
+    assert_eq!(doc.views[0].group, Some("Status".to_string()));
+
+    assert_eq!(doc.cards[0].title, "Fix login");
+    assert_eq!(doc.cards[0].description, "Steps to reproduce");
+    assert!(
+        doc.cards[0]
+            .properties
+            .iter()
+            .any(|p| p.property_name == "Milestone" && p.value == "v1.0")
+    );
+    assert!(
+        doc.cards[0]
+            .properties
+            .iter()
+            .any(|p| p.property_name == "Assignees" && p.value == "octocat, hubot")
+    );
+
+    let board = reload(&doc);
+    let groups = board.get_cards_by_group("Roadmap").unwrap();
+    assert_eq!(groups["Todo"].len(), 2);
+}
+
+#[test]
+fn test_import_notion_csv() {
+    let csv = "\u{feff}Name,Status,Due,Done,Points,Notes\n\
+               Plan sprint,Not started,\"March 5, 2024\",No,3,Kickoff notes\n\
+               Retro,Done,2024/03/12,Yes,1,\n\
+               Demo,Not started,\"March 8, 2024 10:00 AM\",No,,Prepare slides\n";
+
+    let doc = import_notion_csv(csv, "Sprint 12").unwrap();
+
+    let types: Vec<(&str, &ParsedPropertyType)> = doc
+        .properties
+        .iter()
+        .map(|p| (p.name.as_str(), &p.property_type))
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            (
+                "Status",
+                &ParsedPropertyType::Select {
+                    options: vec!["Not started".to_string(), "Done".to_string()]
+                }
+            ),
+            ("Due", &ParsedPropertyType::Date),
+            ("Done", &ParsedPropertyType::Checkbox),
+            ("Points", &ParsedPropertyType::Number),
+            ("Notes", &ParsedPropertyType::Text),
+        ]
+    );
+
+    assert_eq!(doc.cards.len(), 3);
+    assert_eq!(doc.cards[0].title, "Plan sprint");
+    assert_eq!(doc.cards[0].properties[1].value, "2024-03-05");
+    assert_eq!(doc.cards[0].properties[2].value, "false");
+    assert_eq!(doc.cards[1].properties[1].value, "2024-03-12");
+    assert_eq!(doc.cards[1].properties[2].value, "true");
+    assert_eq!(doc.cards[2].properties[1].value, "2024-03-08");
+
+    assert_eq!(doc.views[0].name, "Sprint 12");
+    assert_eq!(doc.views[0].group, Some("Status".to_string()));
+
+    let board = reload(&doc);
+    let groups = board.get_cards_by_group("Sprint 12").unwrap();
+    assert_eq!(groups["Not started"].len(), 2);
+}
+
+#[test]
+fn test_normalize_date() {
+    assert_eq!(normalize_date("2024-03-05"), Some("2024-03-05".to_string()));
+    assert_eq!(
+        normalize_date("2024-03-05T17:00:00.000Z"),
+        Some("2024-03-05".to_string())
+    );
+    assert_eq!(normalize_date("2024/3/5"), Some("2024-03-05".to_string()));
+    assert_eq!(normalize_date("03/05/2024"), Some("2024-03-05".to_string()));
+    assert_eq!(
+        normalize_date("Sep 9, 2023 → Sep 12, 2023"),
+        Some("2023-09-09".to_string())
+    );
+    assert_eq!(normalize_date("not a date"), None);
+    assert_eq!(normalize_date("2024-13-01"), None);
+}
@@ -4,13 +4,14 @@
 use std::collections::HashMap;
 
 use crate::{
-    ColumnSort, Kanban, KanbanSortType, KanbanViewType, ParsedCard, ParsedDocument, ParsedProperty,
-    ParsedPropertyType, ParsedPropertyValue, ParsedView, ParsedViewType, parse_cards_section,
-    parse_view,
+    BoardMetadata, ColumnSort, FilterOp, Kanban, KanbanSortType, KanbanViewType, ParsedCard,
+    ParsedDocument, ParsedProperty, ParsedPropertyType, ParsedPropertyValue, ParsedView,
+    ParsedViewType, PropertyRules, Severity, ViewFilter,
 };
 
 fn create_test_document() -> ParsedDocument {
     ParsedDocument {
+        metadata: BoardMetadata::default(),
         properties: vec![
             ParsedProperty {
                 name: "Status".to_string(),
@@ -21,14 +22,17 @@ fn create_test_document() -> ParsedDocument {
                         "Done".to_string(),
                     ],
                 },
+                rules: PropertyRules::default(),
             },
             ParsedProperty {
                 name: "Owner".to_string(),
                 property_type: ParsedPropertyType::Text,
+                rules: PropertyRules::default(),
             },
             ParsedProperty {
                 name: "Due Date".to_string(),
                 property_type: ParsedPropertyType::Date,
+                rules: PropertyRules::default(),
             },
         ],
         views: vec![
@@ -58,6 +62,7 @@ fn create_test_document() -> ParsedDocument {
                 id: 0,
                 title: "Task 1".to_string(),
                 description: "Description for task 1".to_string(),
+                subtasks: Vec::new(),
                 properties: vec![
                     ParsedPropertyValue {
                         property_name: "Status".to_string(),
@@ -73,6 +78,7 @@ fn create_test_document() -> ParsedDocument {
                 id: 1,
                 title: "Task 2".to_string(),
                 description: "Description for task 2".to_string(),
+                subtasks: Vec::new(),
                 properties: vec![
                     ParsedPropertyValue {
                         property_name: "Status".to_string(),
@@ -88,6 +94,7 @@ fn create_test_document() -> ParsedDocument {
                 id: 2,
                 title: "Task 3".to_string(),
                 description: "Description for task 3".to_string(),
+                subtasks: Vec::new(),
                 properties: vec![
                     ParsedPropertyValue {
                         property_name: "Status".to_string(),
@@ -111,6 +118,7 @@ fn test_alpha_sort() {
         id: 3,
         title: "ATask".to_string(),
         description: "Description for ATask".to_string(),
+        subtasks: Vec::new(),
         properties: vec![
             ParsedPropertyValue {
                 property_name: "Status".to_string(),
@@ -127,6 +135,7 @@ fn test_alpha_sort() {
         id: 4,
         title: "ZTask".to_string(),
         description: "Description for ZTask".to_string(),
+        subtasks: Vec::new(),
         properties: vec![
             ParsedPropertyValue {
                 property_name: "Status".to_string(),
@@ -162,6 +171,7 @@ fn test_reverse_alpha_sort() {
         id: 3,
         title: "ATask".to_string(),
         description: "Description for ATask".to_string(),
+        subtasks: Vec::new(),
         properties: vec![
             ParsedPropertyValue {
                 property_name: "Status".to_string(),
@@ -178,6 +188,7 @@ fn test_reverse_alpha_sort() {
         id: 4,
         title: "ZTask".to_string(),
         description: "Description for ZTask".to_string(),
+        subtasks: Vec::new(),
         properties: vec![
             ParsedPropertyValue {
                 property_name: "Status".to_string(),
@@ -709,6 +720,7 @@ fn test_invalid_property_reference() {
         id: 4,
         title: "Invalid Card".to_string(),
         description: "This card has an invalid property".to_string(),
+        subtasks: Vec::new(),
         properties: vec![ParsedPropertyValue {
             property_name: "NonExistentProperty".to_string(),
             value: "Some Value".to_string(),
@@ -879,3 +891,161 @@ fn test_roundtrip_markdown() {
         assert_eq!(cards.len(), new_groups.get(group).unwrap().len());
     }
 }
+
+#[test]
+fn test_view_filter() {
+    let mut doc = create_test_document();
+    doc.views[0].filter = Some("Status != Done and Owner != \"Bob\"".to_string());
+
+    let board = Kanban::from_document(doc).unwrap();
+    let grouped_cards = board.get_cards_by_group("Board View").unwrap();
+
+    assert_eq!(grouped_cards["Backlog"].len(), 1);
+    assert!(grouped_cards["In Progress"].is_empty());
+    assert!(grouped_cards["Done"].is_empty());
+
+    let filter = ViewFilter::parse("Points >= 3 and Title = Task 1").unwrap();
+    assert_eq!(filter.conditions.len(), 2);
+    assert_eq!(filter.conditions[0].op, FilterOp::GtEq);
+    assert_eq!(filter.conditions[1].value, "Task 1");
+    assert!(filter.matches(|name| match name {
+        "Points" => Some("10"),
+        "Title" => Some("Task 1"),
+        _ => None,
+    }));
+    assert!(!filter.matches(|_| Some("2")));
+
+    assert!(ViewFilter::parse("Status Done").is_err());
+}
+
+#[test]
+fn test_invalid_filter_is_ignored() {
+    // Boards with free-form filter text still render; lint reports the filter
+    let mut doc = create_test_document();
+    doc.views[0].filter = Some("Status Done".to_string());
+    assert!(
+        doc.lint()
+            .iter()
+            .any(|d| d.code == "invalid-filter" && d.severity == Severity::Error)
+    );
+
+    let board = Kanban::from_document(doc).unwrap();
+    let grouped_cards = board.get_cards_by_group("Board View").unwrap();
+    let total: usize = grouped_cards.values().map(Vec::len).sum();
+    assert_eq!(total, 3);
+    assert_eq!(board.get_view_cards("Board View").unwrap().len(), 3);
+}
+
+#[test]
+fn test_remove_card() {
+    let mut doc = create_test_document();
+    doc.views[0].sort_type = KanbanSortType::Manual;
+    doc.views[0].column_sorts = vec![
+        ColumnSort {
+            column: "Done".to_string(),
+            order: vec![2],
+        },
+        ColumnSort {
+            column: "Backlog".to_string(),
+            order: vec![0],
+        },
+    ];
+
+    let mut board = Kanban::from_document(doc).unwrap();
+    board.remove_card("Task 1").unwrap();
+
+    assert_eq!(board.cards.len(), 2);
+    assert!(!board.card_by_title.contains_key("Task 1"));
+    assert!(board.remove_card("Task 1").is_err());
+
+    let grouped_cards = board.get_cards_by_group("Board View").unwrap();
+    assert!(grouped_cards["Backlog"].is_empty());
+
+    // IDs are renumbered to positions and manual orderings follow the columns
+    let parsed_doc = board.to_parsed_document();
+    assert_eq!(parsed_doc.cards[0].id, 0);
+    assert_eq!(parsed_doc.cards[1].title, "Task 3");
+    assert_eq!(parsed_doc.cards[1].id, 1);
+    assert_eq!(
+        parsed_doc.views[0].column_sorts,
+        vec![
+            ColumnSort {
+                column: "Backlog".to_string(),
+                order: vec![],
+            },
+            ColumnSort {
+                column: "Done".to_string(),
+                order: vec![1],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_edit_card() {
+    let mut board = Kanban::from_document(create_test_document()).unwrap();
+
+    let mut values = HashMap::new();
+    values.insert("Status".to_string(), "Done".to_string());
+    values.insert("Owner".to_string(), String::new());
+    values.insert("Due Date".to_string(), "2024-05-01".to_string());
+    board
+        .edit_card(
+            "Task 1",
+            Some("Task One".to_string()),
+            Some("Updated".to_string()),
+            values,
+        )
+        .unwrap();
+
+    let card = board.card_by_title["Task One"].borrow();
+    assert_eq!(card.description, "Updated");
+    assert_eq!(card.get_value("Status"), Some("Done"));
+    assert_eq!(card.get_value("Owner"), None);
+    assert_eq!(card.get_value("Due Date"), Some("2024-05-01"));
+    assert!(!board.card_by_title.contains_key("Task 1"));
+    drop(card);
+
+    // Invalid values leave the card untouched
+    let mut values = HashMap::new();
+    values.insert("Owner".to_string(), "Zoe".to_string());
+    values.insert("Status".to_string(), "Nope".to_string());
+    assert!(board.edit_card("Task 2", None, None, values).is_err());
+    assert_eq!(
+        board.card_by_title["Task 2"].borrow().get_value("Owner"),
+        Some("Bob")
+    );
+
+    // Titles must stay unique
+    assert!(
+        board
+            .edit_card("Task 2", Some("Task 3".to_string()), None, HashMap::new())
+            .is_err()
+    );
+}
+
+#[test]
+fn test_from_document_with_diagnostics() {
+    let mut doc = create_test_document();
+    doc.views[0].sort_by = Some("Priority".to_string());
+    doc.views[1].display = Some("Owner, Estimate".to_string());
+
+    let (board, diagnostics) = Kanban::from_document_with_diagnostics(doc).unwrap();
+
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+    assert_eq!(diagnostics[0].code, "unknown-view-property");
+    assert_eq!(diagnostics[0].location.as_ref().unwrap().name, "Board View");
+    assert_eq!(diagnostics[1].code, "unknown-display-property");
+    assert_eq!(diagnostics[1].location.as_ref().unwrap().index, 1);
+    assert!(diagnostics[1].message.contains("Estimate"));
+
+    // The unknown references are skipped rather than failing the board
+    assert_eq!(
+        board.view_by_name["Calendar View"].borrow().display.len(),
+        1
+    );
+
+    let (_, diagnostics) = Kanban::from_document_with_diagnostics(create_test_document()).unwrap();
+    assert!(diagnostics.is_empty());
+}
@@ -0,0 +1,126 @@
+// crates/kandown/src/test/test_keywords.rs
+use crate::*;
+
+const GERMAN: &str = "---
+version: 2
+locale: de
+---
+
+# Eigenschaften
+- Status: Select
+\t- Offen
+\t- Erledigt
+\tStandard: Offen
+
+- Punkte: Number
+\tMax: 8
+
+# Ansichten
+- Tafel
+  Layout: Board
+  Gruppe: Status
+  Sortierart: Manual
+    - Offen
+      - 1
+      - 0
+
+- Liste
+  Layout: Table
+  Sortierung: Punkte
+  Anzeige: Status, Punkte
+
+# Karten
+- Entwurf
+  Status: Offen
+  Punkte: 3
+
+- Umsetzung
+  Status: Offen
+";
+
+#[test]
+fn test_german_board() {
+    let doc = ParsedDocument::parse_complete(GERMAN).unwrap();
+    assert_eq!(doc.properties.len(), 2);
+    assert_eq!(doc.properties[0].rules.default.as_deref(), Some("Offen"));
+    assert_eq!(doc.properties[1].rules.max, Some(8.0));
+    assert_eq!(doc.views[0].group.as_deref(), Some("Status"));
+    assert_eq!(doc.views[0].sort_type, KanbanSortType::Manual);
+    assert_eq!(doc.views[0].column_sorts[0].order, [1, 0]);
+    assert_eq!(doc.views[1].sort_by.as_deref(), Some("Punkte"));
+    assert_eq!(doc.views[1].display.as_deref(), Some("Status, Punkte"));
+    assert_eq!(doc.cards.len(), 2);
+
+    assert_eq!(doc.to_string(), GERMAN);
+    let board = Kanban::from_document(doc.clone()).unwrap();
+    assert_eq!(board.to_parsed_document().to_string(), GERMAN);
+
+    // Diagnostics find their lines under the German headings
+    let diagnostics = lint_markdown(&GERMAN.replace("Punkte: 3", "Punkte: 30"));
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].location.as_ref().unwrap().line, Some(30));
+
+    let Err(error) = ParsedDocument::parse_complete(&GERMAN.replace("locale: de", "locale: fr"))
+    else {
+        panic!("Expected an unknown locale to be rejected");
+    };
+    assert!(
+        error
+            .to_string()
+            .contains("Unknown locale: fr (expected one of: en, de)"),
+        "{error}"
+    );
+
+    // Boards with an unknown locale are not quietly written in English
+    let mut doc = doc;
+    doc.metadata.locale = Some("fr".to_string());
+    let unknown = "Unknown locale: fr (expected one of: en, de)";
+    let error = doc.to_markdown_with(&ParserConfig::default()).unwrap_err();
+    assert_eq!(error.to_string(), unknown);
+    let Err(error) = Kanban::from_document(doc.clone()) else {
+        panic!("Expected an unknown locale to be rejected");
+    };
+    assert_eq!(error.to_string(), unknown);
+    let json = doc.to_json().unwrap();
+    let error = ParsedDocument::from_json(&json).unwrap_err();
+    assert_eq!(error.to_string(), unknown);
+}
+
+#[test]
+fn test_parser_config() {
+    // Boards without a locale are read and written with the given keywords
+    let body = &GERMAN[GERMAN.find("# Eigenschaften").unwrap()..];
+    let german = ParserConfig::german();
+    let doc = ParsedDocument::parse_complete_with(body, &german).unwrap();
+    assert_eq!(doc.metadata.locale, None);
+    assert_eq!(doc.views.len(), 2);
+    assert_eq!(doc.to_markdown_with(&german).unwrap(), body);
+
+    let english = doc.to_string();
+    assert!(english.starts_with("# Properties\n"));
+    assert!(english.contains("  Group: Status\n  Sort Type: Manual\n"));
+    assert!(english.contains("\tDefault: Offen\n"));
+    assert_eq!(ParsedDocument::parse_complete(&english).unwrap(), doc);
+
+    // German headings are not English ones
+    assert!(ParsedDocument::parse_complete(body).is_err());
+
+    // Other languages can be configured word by word
+    let custom = ParserConfig {
+        cards: "Tasks".to_string(),
+        ..ParserConfig::english()
+    };
+    let doc = ParsedDocument::parse_complete_with("# Tasks\n- Write docs\n", &custom).unwrap();
+    assert_eq!(doc.cards[0].title, "Write docs");
+    assert_eq!(
+        doc.to_markdown_with(&custom).unwrap(),
+        "# Tasks\n- Write docs\n"
+    );
+
+    // Diagnostics find their lines under the configured headings too
+    let markdown =
+        "# Properties\n- Status: Select\n\t- Todo\n\n# Tasks\n- Write docs\n  Status: Blocked\n";
+    let diagnostics = lint_markdown_with(markdown, &custom);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].location.as_ref().unwrap().line, Some(6));
+}
@@ -0,0 +1,101 @@
+// crates/kandown/src/test/test_lint.rs
+use crate::*;
+
+const BOARD: &str = r#"# Properties
+- Status: Select
+	- Backlog
+	- Done
+
+- Points: Number
+
+- Urgent: Checkbox
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+  Sort Type: Manual
+    - Backlog
+      - 0
+      - 7
+    - Blocked
+      - 1
+  Display: Points, Owner
+
+- Broken
+  Layout: Table
+  Sort: Estimate
+  Filter: Status Done
+
+# Cards
+- Write spec
+  Status: Backlog
+  Points: 3
+
+- Write spec
+  Status: Doing
+  Urgent: yes
+
+- Ship it
+  Owner: bob
+  Points: lots
+"#;
+
+fn codes(diagnostics: &[Diagnostic]) -> Vec<(&str, Severity, Option<usize>)> {
+    diagnostics
+        .iter()
+        .map(|d| {
+            (
+                d.code.as_str(),
+                d.severity,
+                d.location.as_ref().and_then(|l| l.line),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_lint_board() {
+    let diagnostics = lint_markdown(BOARD);
+
+    assert_eq!(
+        codes(&diagnostics),
+        vec![
+            ("unknown-display-property", Severity::Warning, Some(11)),
+            ("unknown-sort-card", Severity::Warning, Some(11)),
+            ("unknown-sort-column", Severity::Warning, Some(11)),
+            ("unknown-view-property", Severity::Error, Some(22)),
+            ("invalid-filter", Severity::Error, Some(22)),
+            ("duplicate-card-title", Severity::Error, Some(32)),
+            ("invalid-value", Severity::Error, Some(32)),
+            ("invalid-value", Severity::Error, Some(32)),
+            ("unknown-property", Severity::Error, Some(36)),
+            ("invalid-value", Severity::Warning, Some(36)),
+        ]
+    );
+
+    let location = diagnostics[5].location.as_ref().unwrap();
+    assert_eq!(location.section, DocumentSection::Cards);
+    assert_eq!(location.index, 1);
+    assert_eq!(location.name, "Write spec");
+    assert!(
+        diagnostics[6]
+            .message
+            .contains("expected one of: Backlog, Done")
+    );
+}
+
+#[test]
+fn test_lint_clean_board() {
+    let board = "# Properties\n- Status: Select\n\t- Todo\n\n# Cards\n- Task\n  Status: Todo\n";
+    assert!(lint_markdown(board).is_empty());
+}
+
+#[test]
+fn test_lint_reports_parse_errors() {
+    let diagnostics = lint_markdown("# Cards\n- Task\n\nStray text\n");
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, "parse-error");
+    assert!(diagnostics[0].message.contains("line 4"));
+}
@@ -0,0 +1,131 @@
+// crates/kandown/src/test/test_merge.rs
+use crate::*;
+
+const BASE: &str = r#"# Properties
+- Status: Select
+	- Backlog
+	- Done
+
+- Owner: Text
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+  Sort Type: Manual
+    - Backlog
+      - 0
+      - 1
+
+# Cards
+- Write spec
+  Status: Backlog
+
+- Add tests
+  Status: Backlog
+  Owner: carol
+"#;
+
+#[test]
+fn test_merge_combines_independent_changes() {
+    // Ours finishes the spec and adds a card at the end
+    let ours = BASE.replace(
+        "- Write spec\n  Status: Backlog",
+        "- Write spec\n  Status: Done",
+    ) + "\n- Ship it\n  Owner: alice\n";
+    // Theirs adds a card at the top, which shifts every position, reorders
+    // the column and describes another card
+    let theirs = BASE
+        .replace("# Cards\n", "# Cards\n- Triage bugs\n  Status: Backlog\n\n")
+        .replace(
+            "      - 0\n      - 1\n",
+            "      - 2\n      - 1\n      - 0\n",
+        )
+        .replace("  Owner: carol\n", "  Owner: carol\n  Cover the parser\n");
+
+    let (merged, conflicts) = merge_markdown(BASE, &ours, &theirs).unwrap();
+    assert!(conflicts.is_empty(), "{conflicts:?}");
+    assert_eq!(
+        merged,
+        r#"# Properties
+- Status: Select
+	- Backlog
+	- Done
+
+- Owner: Text
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+  Sort Type: Manual
+    - Backlog
+      - 2
+      - 1
+      - 0
+
+# Cards
+- Triage bugs
+  Status: Backlog
+
+- Write spec
+  Status: Done
+
+- Add tests
+  Status: Backlog
+  Owner: carol
+  Cover the parser
+
+- Ship it
+  Owner: alice
+"#
+    );
+}
+
+#[test]
+fn test_merge_reports_conflicts() {
+    let ours = BASE
+        .replace("  Owner: carol\n", "  Owner: dave\n")
+        .replace("- Write spec\n  Status: Backlog\n\n", "");
+    let theirs = BASE.replace("  Owner: carol\n", "  Owner: erin\n").replace(
+        "- Write spec\n  Status: Backlog",
+        "- Write spec\n  Status: Done",
+    );
+
+    let (merged, conflicts) = merge_markdown(BASE, &ours, &theirs).unwrap();
+
+    // Conflicting values keep our side; a card changed on one side is kept
+    // even though the other side deleted it
+    assert!(merged.contains("- Add tests\n  Status: Backlog\n  Owner: dave\n"));
+    assert!(merged.contains("- Write spec\n  Status: Done\n"));
+
+    let messages: Vec<(String, Option<usize>)> = conflicts
+        .iter()
+        .map(|d| (d.message.clone(), d.location.as_ref().and_then(|l| l.line)))
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            (
+                "Card 'Add tests' has conflicting values for 'Owner': 'dave' and 'erin'"
+                    .to_string(),
+                Some(20)
+            ),
+            (
+                "Card 'Write spec' was deleted in ours but changed on the other side".to_string(),
+                Some(17)
+            ),
+        ]
+    );
+    assert!(conflicts.iter().all(|d| d.code == "merge-conflict"));
+}
+
+#[test]
+fn test_merge_rejects_duplicate_titles() {
+    let theirs = BASE.replace("- Add tests", "- Write spec");
+    let err = merge_markdown(BASE, BASE, &theirs).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Cannot merge: card title 'Write spec' is used twice in theirs"
+    );
+}
@@ -0,0 +1,121 @@
+// crates/kandown/src/test/test_metadata.rs
+use crate::*;
+
+const BOARD: &str = "---
+title: Roadmap
+description: Plans for the spring launch
+owner: Sam
+version: 2
+default_view: Table
+timezone: Europe/Berlin
+---
+
+# Properties
+- Status: Select
+\t- Todo
+\t- Done
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+
+- Table
+  Layout: Table
+
+# Cards
+- Launch
+  Status: Todo
+";
+
+#[test]
+fn test_front_matter_round_trip() {
+    let doc = ParsedDocument::parse_complete(BOARD).unwrap();
+    assert_eq!(
+        doc.metadata,
+        BoardMetadata {
+            title: Some("Roadmap".to_string()),
+            description: Some("Plans for the spring launch".to_string()),
+            owner: Some("Sam".to_string()),
+            version: Some(2),
+            default_view: Some("Table".to_string()),
+            timezone: Some("Europe/Berlin".to_string()),
+            locale: None,
+        }
+    );
+    assert_eq!(doc.to_string(), BOARD);
+    assert!(doc.lint().is_empty());
+
+    let exchange = BoardExchange::from(&doc);
+    assert_eq!(ParsedDocument::try_from(exchange).unwrap(), doc);
+
+    // Boards without front matter are unchanged
+    let plain = &BOARD[BOARD.find("# Properties").unwrap()..];
+    let doc = ParsedDocument::parse_complete(plain).unwrap();
+    assert!(doc.metadata.is_empty());
+    assert_eq!(doc.to_string(), plain);
+}
+
+#[test]
+fn test_board_metadata() {
+    let mut board = Kanban::from_document(ParsedDocument::try_from(BOARD).unwrap()).unwrap();
+    assert_eq!(board.metadata.title.as_deref(), Some("Roadmap"));
+    assert_eq!(board.default_view().unwrap().borrow().name, "Table");
+
+    board.metadata.default_view = None;
+    board.metadata.owner = Some("Ana".to_string());
+    assert_eq!(board.default_view().unwrap().borrow().name, "Board");
+    assert!(
+        board
+            .to_parsed_document()
+            .to_string()
+            .starts_with("---\ntitle: Roadmap\ndescription: Plans for the spring launch\nowner: Ana\nversion: 2\ntimezone: Europe/Berlin\n---\n\n# Properties\n")
+    );
+
+    let diagnostics = lint_markdown(&BOARD.replace("default_view: Table", "default_view: Gantt"));
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "warning[unknown-default-view]: The default view 'Gantt' does not exist"
+    );
+}
+
+#[test]
+fn test_invalid_front_matter() {
+    for (markdown, message) in [
+        (
+            BOARD.replace("owner: Sam", "team: Web"),
+            "unknown field `team`",
+        ),
+        (BOARD.replace("version: 2", "version: two"), "version"),
+        (
+            BOARD.replacen("---\n\n", "\n", 1),
+            "Front matter is missing its closing ---",
+        ),
+    ] {
+        let Err(error) = ParsedDocument::parse_complete(&markdown) else {
+            panic!("Expected the front matter to be rejected:\n{markdown}");
+        };
+        assert!(error.to_string().contains(message), "{error}");
+    }
+}
+
+#[test]
+fn test_merge_metadata() {
+    let base = ParsedDocument::parse_complete(BOARD).unwrap();
+    let ours = ParsedDocument::parse_complete(&BOARD.replace("owner: Sam", "owner: Ana")).unwrap();
+    let theirs =
+        ParsedDocument::parse_complete(&BOARD.replace("title: Roadmap", "title: Launch")).unwrap();
+
+    let merged = ParsedDocument::merge(&base, &ours, &base).unwrap();
+    assert!(merged.conflicts.is_empty());
+    assert_eq!(merged.document.metadata.owner.as_deref(), Some("Ana"));
+
+    let merged = ParsedDocument::merge(&base, &ours, &theirs).unwrap();
+    assert_eq!(merged.conflicts.len(), 1);
+    assert_eq!(
+        merged.conflicts[0].message,
+        "Board metadata was changed differently on both sides"
+    );
+    assert_eq!(merged.document.metadata, ours.metadata);
+}
@@ -0,0 +1,180 @@
+// crates/kandown/src/test/test_migrate.rs
+use crate::*;
+
+// Written before `Sort Type` existed, with the sort type under `Sort`
+const LEGACY: &str = "# Properties
+- Status: Select
+  - Backlog
+  - Done
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+  Sort: Manual
+    - Backlog
+      - 1
+      - 0
+
+- Table
+  Layout: Table
+  Sort: Status
+
+# Cards
+- Build
+  Status: Backlog
+
+- Test
+  Status: Backlog
+";
+
+#[test]
+fn test_migrate_legacy_board() {
+    let (doc, report) = ParsedDocument::parse_and_migrate(LEGACY).unwrap();
+    assert_eq!(report.from_version, 1);
+    assert_eq!(report.to_version, FORMAT_VERSION);
+    assert_eq!(report.changes.len(), 1);
+    assert_eq!(
+        report.changes[0].message,
+        "View 'Board': 'Sort: Manual' is now written 'Sort Type: Manual'"
+    );
+    assert_eq!(report.changes[0].location.as_ref().unwrap().line, Some(7));
+
+    assert_eq!(doc.metadata.version, Some(FORMAT_VERSION));
+    assert_eq!(doc.views[0].sort_by, None);
+    assert_eq!(doc.views[0].sort_type, KanbanSortType::Manual);
+    assert_eq!(doc.views[0].column_sorts[0].order, [1, 0]);
+    assert_eq!(doc.views[1].sort_by.as_deref(), Some("Status"));
+
+    let markdown = doc.to_string();
+    assert!(
+        markdown
+            .starts_with("---\nversion: 2\n---\n\n# Properties\n- Status: Select\n\t- Backlog\n")
+    );
+    assert!(
+        markdown.contains(
+            "  Group: Status\n  Sort Type: Manual\n    - Backlog\n      - 1\n      - 0\n"
+        )
+    );
+
+    // Upgraded boards need nothing more
+    let (again, report) = ParsedDocument::parse_and_migrate(&markdown).unwrap();
+    assert!(report.is_empty());
+    assert_eq!(report.from_version, FORMAT_VERSION);
+    assert_eq!(again, doc);
+}
+
+#[test]
+fn test_boards_load_migrated() {
+    let doc = ParsedDocument::try_from(LEGACY).unwrap();
+    let (board, diagnostics) = Kanban::from_document_with_diagnostics(doc).unwrap();
+    let codes: Vec<&str> = diagnostics.iter().map(|d| d.code.as_str()).collect();
+    assert_eq!(codes, ["migrated"]);
+
+    let titles: Vec<String> = board.get_ordered_groups("Board").unwrap()[0]
+        .1
+        .iter()
+        .map(|c| c.borrow().title.clone())
+        .collect();
+    assert_eq!(titles, ["Test", "Build"]);
+
+    // Lint looks at the board as it loads rather than at the old syntax
+    let codes: Vec<String> = lint_markdown(LEGACY).into_iter().map(|d| d.code).collect();
+    assert_eq!(codes, ["migrated"]);
+}
+
+#[test]
+fn test_migrate_leaves_current_boards_alone() {
+    // A property named like a sort type is a real sort
+    let markdown = LEGACY
+        .replace("- Status: Select", "- Manual: Text\n\n- Status: Select")
+        .replace(
+            "  Status: Backlog\n\n- Test",
+            "  Manual: yes\n  Status: Backlog\n\n- Test",
+        );
+    let (doc, report) = ParsedDocument::parse_and_migrate(&markdown).unwrap();
+    assert!(report.is_empty());
+    assert_eq!(doc.metadata.version, Some(FORMAT_VERSION));
+    assert_eq!(doc.views[0].sort_by.as_deref(), Some("Manual"));
+
+    let newer = format!("---\nversion: {}\n---\n\n{LEGACY}", FORMAT_VERSION + 1);
+    let Err(error) = ParsedDocument::parse_and_migrate(&newer) else {
+        panic!("Expected a newer format to be rejected");
+    };
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "Unsupported board format version: {} (expected at most {FORMAT_VERSION})",
+            FORMAT_VERSION + 1
+        )
+    );
+    assert!(Kanban::from_document(ParsedDocument::try_from(newer.as_str()).unwrap()).is_err());
+    let codes: Vec<String> = lint_markdown(&newer).into_iter().map(|d| d.code).collect();
+    assert_eq!(codes[0], "unsupported-version");
+}
+
+#[test]
+fn test_format_keeps_legacy_manual_orderings() {
+    let formatted = ParsedDocument::format_markdown(LEGACY).unwrap();
+    assert!(formatted.starts_with("---\nversion: 2\n---\n\n"));
+    assert!(formatted.contains("  Sort Type: Manual\n    - Backlog\n      - 1\n      - 0\n"));
+    assert_eq!(
+        ParsedDocument::format_markdown(&formatted).unwrap(),
+        formatted
+    );
+
+    // Orderings on views that aren't manually sorted would be lost
+    let sorted = LEGACY.replace("  Sort: Manual\n", "  Sort Type: Alpha\n");
+    let Err(error) = ParsedDocument::format_markdown(&sorted) else {
+        panic!("Expected orderings on an alphabetical view to be rejected");
+    };
+    assert!(
+        error
+            .to_string()
+            .starts_with("View 'Board' lists card orderings but is not sorted manually"),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_new_boards_are_marked() {
+    // Boards written by this version say so, and read back as it
+    let mut board = Kanban::from_document(ParsedDocument::try_from("# Cards\n").unwrap()).unwrap();
+    board
+        .add_card("Build".to_string(), String::new(), Default::default())
+        .unwrap();
+    let markdown = board.to_parsed_document().to_string();
+    assert_eq!(markdown, "---\nversion: 2\n---\n\n# Cards\n- Build\n");
+
+    let doc = ParsedDocument::parse_complete(&markdown).unwrap();
+    assert_eq!(doc.format_version(), FORMAT_VERSION);
+    let board = Kanban::from_document(doc).unwrap();
+    assert_eq!(board.to_parsed_document().to_string(), markdown);
+
+    let imported = import_trello(r#"{ "name": "Team", "lists": [], "cards": [] }"#).unwrap();
+    assert_eq!(imported.format_version(), FORMAT_VERSION);
+}
+
+#[test]
+fn test_unindented_select_options_in_legacy_boards() {
+    let legacy = "# Properties\n- Status: Select\n- Todo\n- Done\n- Owner: Text\n\n\
+                  # Cards\n- Build\n  Status: Todo\n  Owner: alice\n";
+
+    let (doc, _) = ParsedDocument::parse_and_migrate(legacy).unwrap();
+    assert_eq!(
+        doc.properties[0].property_type,
+        ParsedPropertyType::Select {
+            options: vec!["Todo".to_string(), "Done".to_string()]
+        }
+    );
+    assert_eq!(doc.properties[1].name, "Owner");
+    assert!(
+        doc.to_string()
+            .contains("- Status: Select\n\t- Todo\n\t- Done\n\n- Owner: Text\n")
+    );
+    assert!(Kanban::from_document(ParsedDocument::try_from(legacy).unwrap()).is_ok());
+
+    // Current boards must indent their options
+    let current = format!("---\nversion: 2\n---\n\n{legacy}");
+    assert!(ParsedDocument::parse_complete(&current).is_err());
+}
@@ -4,12 +4,12 @@ use crate::*;
 #[test]
 fn test_property_parser() {
     let input = "- Owner: Text\n";
-    let (_rest, property) = parse_property(input).unwrap();
+    let (_rest, property) = parse_property(&ParserConfig::default(), false, input).unwrap();
     assert_eq!(property.name, "Owner");
     assert_eq!(property.property_type, ParsedPropertyType::Text);
 
     let input = "- Status: Select\n\t- Backlog\n\t- In Progress\n\t- Done\n";
-    let (_rest, property) = parse_property(input).unwrap();
+    let (_rest, property) = parse_property(&ParserConfig::default(), false, input).unwrap();
     assert_eq!(property.name, "Status");
     assert_eq!(
         property.property_type,
@@ -42,7 +42,7 @@ fn test_card_parser() {
 #[test]
 fn test_view_parser() {
     let input = "- Task Board\n  Layout: Board\n  Group: Status\n";
-    let (_rest, view) = parse_view(input).unwrap();
+    let (_rest, view) = parse_view(&ParserConfig::default(), input).unwrap();
     assert_eq!(view.name, "Task Board");
     assert_eq!(view.layout, ParsedViewType::Board);
     assert_eq!(view.group, Some("Status".to_string()));
@@ -263,7 +263,7 @@ fn test_parse_sort_options() {
   Group: Status
   Sort Type: Alpha
 "#;
-    let (_rest, view) = parse_view(input).unwrap();
+    let (_rest, view) = parse_view(&ParserConfig::default(), input).unwrap();
     assert_eq!(view.name, "Task Board");
     assert_eq!(view.layout, ParsedViewType::Board);
     assert_eq!(view.sort_type, KanbanSortType::Alpha);
@@ -274,7 +274,7 @@ fn test_parse_sort_options() {
   Group: Status
   Sort Type: ReverseAlpha
 "#;
-    let (_rest, view) = parse_view(input).unwrap();
+    let (_rest, view) = parse_view(&ParserConfig::default(), input).unwrap();
     assert_eq!(view.sort_type, KanbanSortType::ReverseAlpha);
 
     // Test parsing a view with Manual sort
@@ -288,7 +288,7 @@ fn test_parse_sort_options() {
     - In Progress
       - 2
 "#;
-    let (_rest, view) = parse_view(input).unwrap();
+    let (_rest, view) = parse_view(&ParserConfig::default(), input).unwrap();
     assert_eq!(view.sort_type, KanbanSortType::Manual);
     assert_eq!(view.column_sorts.len(), 2);
 
@@ -318,7 +318,7 @@ fn test_parse_sort_options() {
     - In Progress
       - 2
 "#;
-    let (_rest, view) = parse_view(input).unwrap();
+    let (_rest, view) = parse_view(&ParserConfig::default(), input).unwrap();
     assert_eq!(view.sort_type, KanbanSortType::Manual);
     assert_eq!(view.column_sorts.len(), 2);
 
@@ -349,7 +349,7 @@ fn test_parse_document_with_card_ids() {
   Status: In Progress
   Description for task 2
 "#;
-    let (_rest, cards) = parse_cards_section(input).unwrap();
+    let (_rest, cards) = parse_cards_section(&ParserConfig::default(), input).unwrap();
 
     // Verify cards have IDs assigned based on position
     assert_eq!(cards.len(), 2);
@@ -368,6 +368,46 @@ fn test_parse_invalid_manual_sort() {
       - abc
 "#;
 
-    let result = parse_view(input);
+    let result = parse_view(&ParserConfig::default(), input);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_format_markdown() {
+    let input = "\n\n# Properties\n\
+                 * Status: Select\n    - Backlog\n  - Done\n\
+                 1. Owner: Text\n\n\n\
+                 #Views\n\
+                 + Board\n  Layout: Board\n  Group: Status\n\n\n\
+                 # Cards\n\
+                 - Write spec\n  Owner: alice\n  Status: Backlog\n  Draft it first\n\
+                 - Ship it\n   Status: Done\n";
+
+    let formatted = ParsedDocument::format_markdown(input).unwrap();
+    assert_eq!(
+        formatted,
+        "---\nversion: 2\n---\n\n\
+         # Properties\n\
+         - Status: Select\n\t- Backlog\n\t- Done\n\n\
+         - Owner: Text\n\n\
+         # Views\n\
+         - Board\n  Layout: Board\n  Group: Status\n\n\
+         # Cards\n\
+         - Write spec\n  Status: Backlog\n  Owner: alice\n  Draft it first\n\n\
+         - Ship it\n  Status: Done\n"
+    );
+
+    // Formatting is idempotent
+    assert_eq!(
+        ParsedDocument::format_markdown(&formatted).unwrap(),
+        formatted
+    );
+}
+
+#[test]
+fn test_format_markdown_rejects_unparsed_content() {
+    let input = "# Cards\n- Task\n  Status: Done\n\nSome stray paragraph\n";
+
+    let err = ParsedDocument::format_markdown(input).unwrap_err();
+    assert!(err.to_string().contains("line 5"), "{err}");
+}
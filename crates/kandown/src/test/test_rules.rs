@@ -0,0 +1,241 @@
+// crates/kandown/src/test/test_rules.rs
+use crate::*;
+use std::collections::HashMap;
+
+const BOARD: &str = "---
+version: 2
+---
+
+# Properties
+- Status: Select
+\t- Backlog
+\t- Doing
+\t- Done
+\tDefault: Backlog
+\tRequired: true
+
+- Points: Number
+\tMin: 0
+\tMax: 13
+
+- Ticket: Text
+\tPattern: [A-Z]+-[0-9]+
+
+- Owner: Text
+\tRequired: true
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+
+# Cards
+- Launch
+  Status: Doing
+  Points: 5
+  Ticket: WEB-12
+  Owner: Sam
+";
+
+fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_rules_round_trip() {
+    let doc = ParsedDocument::parse_complete(BOARD).unwrap();
+    assert_eq!(
+        doc.properties[0].rules,
+        PropertyRules {
+            default: Some("Backlog".to_string()),
+            required: true,
+            ..PropertyRules::default()
+        }
+    );
+    assert_eq!(doc.properties[1].rules.min, Some(0.0));
+    assert_eq!(doc.properties[1].rules.max, Some(13.0));
+    assert_eq!(
+        doc.properties[2].rules.pattern.as_deref(),
+        Some("[A-Z]+-[0-9]+")
+    );
+    assert_eq!(doc.to_string(), BOARD);
+    assert!(doc.lint().is_empty());
+
+    let board = Kanban::from_document(doc.clone()).unwrap();
+    assert_eq!(board.to_parsed_document().to_string(), BOARD);
+    let exchange = BoardExchange::from(&doc);
+    assert_eq!(ParsedDocument::try_from(exchange).unwrap(), doc);
+
+    for (setting, message) in [
+        (
+            "\tRequired: maybe\n",
+            "Invalid Required for property Owner: maybe",
+        ),
+        ("\tMax: lots\n", "Invalid Max for property Owner: lots"),
+        (
+            "\tColor: red\n",
+            "Unknown setting for property Owner: Color",
+        ),
+    ] {
+        let markdown = BOARD.replace(
+            "\tRequired: true\n\n# Views",
+            &format!("{setting}\n# Views"),
+        );
+        let Err(error) = ParsedDocument::parse_complete(&markdown) else {
+            panic!("Expected '{setting}' to be rejected");
+        };
+        assert!(error.to_string().contains(message), "{error}");
+    }
+}
+
+#[test]
+fn test_rules_on_new_cards() {
+    let mut board = Kanban::from_document(ParsedDocument::try_from(BOARD).unwrap()).unwrap();
+
+    let card = board
+        .add_card(
+            "Docs".to_string(),
+            String::new(),
+            values(&[("Owner", "Ana")]),
+        )
+        .unwrap();
+    assert_eq!(card.borrow().get_value("Status"), Some("Backlog"));
+
+    let Err(error) = board.add_card("Tests".to_string(), String::new(), HashMap::new()) else {
+        panic!("Expected a missing required property to fail");
+    };
+    assert_eq!(error.to_string(), "Missing required property: Owner");
+
+    for (name, value, message) in [
+        ("Points", "21", "Points must be at most 13, got 21"),
+        ("Points", "-1", "Points must be at least 0, got -1"),
+        ("Points", "many", "Points must be a number, got 'many'"),
+        (
+            "Ticket",
+            "web-1",
+            "Ticket must match the pattern [A-Z]+-[0-9]+, got 'web-1'",
+        ),
+    ] {
+        let Err(error) = board.edit_card("Launch", None, None, values(&[(name, value)])) else {
+            panic!("Expected {name} = {value} to be rejected");
+        };
+        assert_eq!(error.to_string(), message);
+    }
+
+    let Err(error) = board.edit_card("Launch", None, None, values(&[("Owner", "")])) else {
+        panic!("Expected clearing a required property to fail");
+    };
+    assert_eq!(error.to_string(), "Owner is required and cannot be removed");
+    board
+        .edit_card("Launch", None, None, values(&[("Points", "8")]))
+        .unwrap();
+
+    let mut replica = BoardReplica::from_markdown(1, BOARD).unwrap();
+    let launch = replica.card_id("Launch").unwrap();
+    let field = |name: &str| CardField::Property(name.to_string());
+    assert!(replica.set_field(launch, field("Points"), "40").is_err());
+    assert!(replica.set_field(launch, field("Owner"), "").is_err());
+    replica.set_field(launch, field("Ticket"), "API-7").unwrap();
+}
+
+#[test]
+fn test_rules_on_synced_cards() {
+    let mut hub = SyncHub::new(BOARD).unwrap();
+    let (_, welcome) = hub.join();
+    let mut alice = BoardReplica::from_welcome(&welcome).unwrap();
+
+    // Inserted cards get defaults like cards added to the board
+    let op = alice
+        .insert_card("Docs", "", values(&[("Owner", "Ana")]))
+        .unwrap();
+    assert!(
+        alice
+            .to_markdown()
+            .unwrap()
+            .ends_with("- Docs\n  Status: Backlog\n  Owner: Ana\n")
+    );
+
+    let Err(error) = alice.insert_card("Tests", "", HashMap::new()) else {
+        panic!("Expected a missing required property to fail");
+    };
+    assert_eq!(error.to_string(), "Missing required property: Owner");
+    assert!(
+        alice
+            .insert_card("Tests", "", values(&[("Owner", "Ana"), ("Status", "")]))
+            .is_err()
+    );
+
+    // Every replica fills in and enforces the same rules
+    let Operation::InsertCard { id, .. } = op.clone() else {
+        panic!("Expected an insert");
+    };
+    let missing = Operation::InsertCard {
+        id: Timestamp {
+            counter: id.counter + 1,
+            ..id
+        },
+        title: "Tests".to_string(),
+        description: String::new(),
+        properties: Default::default(),
+    };
+    let (accepted, rejected) = hub.receive(vec![op, missing]);
+    assert_eq!(accepted.len(), 1);
+    assert!(matches!(
+        &rejected[..],
+        [SyncMessage::Rejected { reason, .. }] if reason == "Missing required property: Owner"
+    ));
+    assert_eq!(
+        hub.board().to_markdown().unwrap(),
+        alice.to_markdown().unwrap()
+    );
+}
+
+#[test]
+fn test_rules_lint() {
+    let markdown = BOARD
+        .replace("Default: Backlog", "Default: Later")
+        .replace("Pattern: [A-Z]+-[0-9]+", "Pattern: [A-Z")
+        .replace("  Points: 5\n", "  Points: 50\n")
+        .replace("  Owner: Sam\n", "");
+    let diagnostics = lint_markdown(&markdown);
+    let messages: Vec<(&str, &str)> = diagnostics
+        .iter()
+        .map(|d| (d.code.as_str(), d.message.as_str()))
+        .collect();
+    assert_eq!(messages.len(), 4);
+    assert_eq!(
+        messages[0],
+        (
+            "invalid-default",
+            "Property 'Status' has an invalid default 'Later': expected one of: Backlog, Doing, Done"
+        )
+    );
+    assert_eq!(messages[1].0, "invalid-pattern");
+    assert_eq!(
+        messages[2],
+        (
+            "invalid-value",
+            "Card 'Launch' has an invalid value: Points must be at most 13, got 50"
+        )
+    );
+    assert_eq!(
+        messages[3],
+        (
+            "missing-required",
+            "Card 'Launch' is missing required property 'Owner'"
+        )
+    );
+
+    let Err(error) = Kanban::from_document(ParsedDocument::try_from(markdown.as_str()).unwrap())
+    else {
+        panic!("Expected the board to be rejected");
+    };
+    assert!(
+        error
+            .to_string()
+            .starts_with("Property 'Ticket' has an invalid pattern:")
+    );
+}
@@ -0,0 +1,86 @@
+// crates/kandown/src/test/test_sections.rs
+use crate::*;
+
+const SPLIT: &str = "# Cards
+- Design
+  Status: Todo
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+
+# Properties
+- Status: Select
+  - Todo
+  - Done
+
+# Cards
+- Build
+  Status: Done
+
+- Ship
+  Status: Todo
+";
+
+#[test]
+fn test_sections_in_any_order() {
+    let doc = ParsedDocument::parse_complete(SPLIT).unwrap();
+    assert_eq!(doc.properties.len(), 1);
+    assert_eq!(doc.views.len(), 1);
+
+    // Cards from every section, numbered in file order
+    let cards: Vec<(usize, &str)> = doc.cards.iter().map(|c| (c.id, c.title.as_str())).collect();
+    assert_eq!(cards, [(0, "Design"), (1, "Build"), (2, "Ship")]);
+
+    // Written back in canonical order with a single card section
+    let markdown = doc.to_string();
+    assert!(markdown.starts_with("# Properties\n"));
+    assert_eq!(markdown.matches("# Cards").count(), 1);
+    assert!(markdown.find("# Views").unwrap() < markdown.find("# Cards").unwrap());
+    assert_eq!(ParsedDocument::parse_complete(&markdown).unwrap(), doc);
+
+    assert!(Kanban::from_document(doc).is_ok());
+}
+
+#[test]
+fn test_empty_sections() {
+    let doc = ParsedDocument::parse_complete("# Properties\n\n# Views\n\n# Cards\n").unwrap();
+    assert!(doc.properties.is_empty());
+    assert!(doc.views.is_empty());
+    assert!(doc.cards.is_empty());
+}
+
+#[test]
+fn test_section_errors() {
+    let error = |markdown: &str| match ParsedDocument::parse_complete(markdown) {
+        Ok(_) => panic!("Expected an error for:\n{markdown}"),
+        Err(e) => e.to_string(),
+    };
+
+    let duplicate = SPLIT.replace(
+        "# Cards\n- Build",
+        "# Properties\n- Points: Number\n\n# Cards\n- Build",
+    );
+    assert_eq!(
+        error(&duplicate),
+        "Duplicate # Properties section at line 15"
+    );
+
+    let unknown = SPLIT.replace("# Views", "# Notes\nSome text\n\n# Views");
+    assert_eq!(
+        error(&unknown),
+        "Unknown section at line 5: # Notes (expected one of: # Properties, # Views, # Cards)"
+    );
+
+    // Headings only match whole words
+    assert!(error(&SPLIT.replace("# Views", "# Viewsx")).contains("Unknown section at line 5"));
+
+    // Content that isn't a card no longer ends the board silently
+    let stray = format!("{SPLIT}\nSome trailing notes\n");
+    assert_eq!(
+        error(&stray),
+        "Unrecognized content at line 22: Some trailing notes"
+    );
+    assert!(ParsedDocument::try_from(stray.as_str()).is_err());
+}
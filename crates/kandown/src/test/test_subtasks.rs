@@ -0,0 +1,168 @@
+// crates/kandown/src/test/test_subtasks.rs
+use crate::*;
+
+const BOARD: &str = r#"# Properties
+- Status: Select
+	- Todo
+	- Done
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+
+- By progress
+  Layout: Table
+  Sort: Progress
+  Filter: Progress < 100
+
+# Cards
+- Release
+  Status: Todo
+  Cut the branch first
+  - [x] Tag: v1.2
+  - [ ] Publish crates
+  - [X] Write notes
+
+- Docs
+  Status: Todo
+  - [ ] Update README
+
+- Cleanup
+  Status: Done
+"#;
+
+fn board() -> Kanban {
+    Kanban::from_document(ParsedDocument::try_from(BOARD).unwrap()).unwrap()
+}
+
+fn subtask(text: &str, done: bool) -> Subtask {
+    Subtask {
+        text: text.to_string(),
+        done,
+    }
+}
+
+#[test]
+fn test_parse_checklists() {
+    let doc = ParsedDocument::parse_complete(BOARD).unwrap();
+    assert_eq!(doc.cards.len(), 3);
+
+    let release = &doc.cards[0];
+    assert_eq!(release.description, "Cut the branch first");
+    assert_eq!(
+        release.subtasks,
+        [
+            subtask("Tag: v1.2", true),
+            subtask("Publish crates", false),
+            subtask("Write notes", true),
+        ]
+    );
+    assert_eq!(doc.cards[1].subtasks, [subtask("Update README", false)]);
+    assert!(doc.cards[2].subtasks.is_empty());
+
+    // Checked items are written back in lowercase
+    assert_eq!(doc.to_string(), BOARD.replace("[X]", "[x]"));
+    assert!(doc.lint().is_empty());
+}
+
+#[test]
+fn test_progress_in_views() {
+    let board = board();
+    let release = board.card_by_title["Release"].borrow();
+    assert_eq!(release.progress(), Some((2, 3)));
+    assert_eq!(release.computed_value("Subtasks").as_deref(), Some("2/3"));
+    assert_eq!(release.computed_value("Progress").as_deref(), Some("66"));
+    assert_eq!(board.card_by_title["Cleanup"].borrow().progress(), None);
+    drop(release);
+
+    // Sorted by progress with cards without a checklist last; the filter
+    // drops finished checklists
+    let titles = |board: &Kanban| -> Vec<String> {
+        board
+            .get_view_cards("By progress")
+            .unwrap()
+            .iter()
+            .map(|c| c.borrow().title.clone())
+            .collect()
+    };
+    assert_eq!(titles(&board), ["Docs", "Release", "Cleanup"]);
+
+    board.toggle_subtask("Release", 1).unwrap();
+    assert_eq!(titles(&board), ["Docs", "Cleanup"]);
+
+    let doc = board.to_parsed_document();
+    assert_eq!(doc.views[1].sort_by.as_deref(), Some("Progress"));
+}
+
+#[test]
+fn test_subtask_operations() {
+    let board = board();
+
+    assert!(board.toggle_subtask("Docs", 0).unwrap());
+    assert!(!board.toggle_subtask("Docs", 0).unwrap());
+    board.add_subtask("Docs", "Add examples").unwrap();
+    board
+        .add_subtask("Cleanup", " Delete old branches ")
+        .unwrap();
+    assert!(board.add_subtask("Docs", "  ").is_err());
+    assert!(board.toggle_subtask("Docs", 5).is_err());
+
+    let removed = board.remove_subtask("Release", 0).unwrap();
+    assert_eq!(removed, subtask("Tag: v1.2", true));
+
+    let doc = board.to_parsed_document();
+    assert_eq!(
+        doc.cards[1].subtasks,
+        [
+            subtask("Update README", false),
+            subtask("Add examples", false)
+        ]
+    );
+    assert_eq!(
+        doc.cards[2].subtasks,
+        [subtask("Delete old branches", false)]
+    );
+}
+
+#[test]
+fn test_merge_and_sync_checklists() {
+    // Checking off different items on each side merges cleanly
+    let ours = BOARD.replace("- [ ] Publish crates", "- [x] Publish crates");
+    let theirs = BOARD.replace("- [x] Tag: v1.2", "- [ ] Tag: v1.2");
+    let (merged, conflicts) = merge_markdown(BOARD, &ours, &theirs).unwrap();
+    assert!(conflicts.is_empty());
+    assert!(merged.contains("  - [ ] Tag: v1.2\n  - [x] Publish crates\n"));
+
+    let changes = ParsedDocument::parse_complete(BOARD)
+        .unwrap()
+        .diff(&ParsedDocument::parse_complete(&ours).unwrap());
+    assert_eq!(
+        changes,
+        [BoardChange::SubtasksChanged {
+            card: "Release".to_string(),
+            from: Some("2/3".to_string()),
+            to: Some("3/3".to_string()),
+        }]
+    );
+
+    // Concurrent toggles of different items both survive
+    let mut alice = BoardReplica::from_markdown(1, BOARD).unwrap();
+    let mut bob = BoardReplica::from_markdown(2, BOARD).unwrap();
+    let release = alice.card_id("Release").unwrap();
+    let ops = [
+        alice.toggle_subtask(release, 1).unwrap(),
+        bob.toggle_subtask(release, 0).unwrap(),
+        bob.add_subtask(release, "Announce").unwrap(),
+    ];
+    for op in &ops {
+        alice.apply(op).unwrap();
+        bob.apply(op).unwrap();
+    }
+
+    let markdown = alice.to_markdown().unwrap();
+    assert_eq!(markdown, bob.to_markdown().unwrap());
+    assert!(markdown.contains(
+        "  - [ ] Tag: v1.2\n  - [x] Publish crates\n  - [x] Write notes\n  - [ ] Announce\n"
+    ));
+}
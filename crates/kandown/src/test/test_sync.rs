@@ -0,0 +1,196 @@
+// crates/kandown/src/test/test_sync.rs
+use std::collections::HashMap;
+
+use crate::*;
+
+const BOARD: &str = r#"---
+version: 2
+---
+
+# Properties
+- Status: Select
+	- Backlog
+	- Done
+
+- Owner: Text
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+  Sort Type: Manual
+    - Backlog
+      - 1
+      - 0
+
+# Cards
+- Write spec
+  Status: Backlog
+
+- Add tests
+  Status: Backlog
+  Owner: carol
+"#;
+
+fn join(hub: &mut SyncHub) -> BoardReplica {
+    let (_, welcome) = hub.join();
+    BoardReplica::from_welcome(&welcome).unwrap()
+}
+
+// Every ordering of the operations, for checking that delivery order does not
+// matter
+fn permutations(ops: &[Operation]) -> Vec<Vec<Operation>> {
+    if ops.is_empty() {
+        return vec![Vec::new()];
+    }
+
+    let mut result = Vec::new();
+    for idx in 0..ops.len() {
+        let mut rest = ops.to_vec();
+        let first = rest.remove(idx);
+        for mut tail in permutations(&rest) {
+            tail.insert(0, first.clone());
+            result.push(tail);
+        }
+    }
+    result
+}
+
+#[test]
+fn test_replica_round_trips_base_document() {
+    let board = BoardReplica::from_markdown(1, BOARD).unwrap();
+    assert_eq!(board.to_markdown().unwrap(), BOARD);
+}
+
+#[test]
+fn test_concurrent_edits_converge() {
+    let mut hub = SyncHub::new(BOARD).unwrap();
+    let mut alice = join(&mut hub);
+    let mut bob = join(&mut hub);
+    let mut carol = join(&mut hub);
+
+    let spec = alice.card_id("Write spec").unwrap();
+    let tests = alice.card_id("Add tests").unwrap();
+
+    // Concurrent edits: conflicting moves, an edit to a card someone else
+    // deletes, inserts with the same title and a column reorder
+    let ops = vec![
+        alice.move_card(spec, "Board", "Done").unwrap(),
+        bob.set_field(spec, CardField::Property("Status".into()), "Backlog")
+            .unwrap(),
+        alice
+            .set_field(tests, CardField::Description, "Cover the parser")
+            .unwrap(),
+        alice
+            .insert_card(
+                "Ship it",
+                "",
+                HashMap::from([("Owner".into(), "alice".into())]),
+            )
+            .unwrap(),
+        bob.insert_card("Ship it", "Tag a release", HashMap::new())
+            .unwrap(),
+        carol
+            .reorder_column("Board", "Backlog", vec![spec, tests])
+            .unwrap(),
+        carol.delete_card(tests).unwrap(),
+    ];
+
+    let expected = {
+        let mut board = BoardReplica::from_markdown(9, BOARD).unwrap();
+        for op in &ops {
+            assert!(board.apply(op).unwrap());
+        }
+        board.to_markdown().unwrap()
+    };
+
+    // Bob's move has the same counter as Alice's but a higher replica id
+    assert!(expected.contains("- Write spec\n  Status: Backlog\n"));
+    assert!(!expected.contains("Add tests"));
+    // Same titles are told apart in insertion order; Bob's insert came first
+    assert!(expected.contains("- Ship it\n  Tag a release\n"));
+    assert!(expected.contains("- Ship it (2)\n  Owner: alice\n"));
+
+    for order in permutations(&ops) {
+        let mut board = BoardReplica::from_markdown(9, BOARD).unwrap();
+        for op in &order {
+            board.apply(op).unwrap();
+        }
+        assert_eq!(board.to_markdown().unwrap(), expected);
+    }
+
+    // Each client receives everyone's operations, including its own again
+    for replica in [&mut alice, &mut bob, &mut carol] {
+        let message = SyncMessage::Operations {
+            operations: ops.clone(),
+        };
+        replica.apply_message(&message).unwrap();
+        assert!(!replica.apply_message(&message).unwrap());
+        assert_eq!(replica.to_markdown().unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_hub_relays_and_catches_up() {
+    let mut hub = SyncHub::new(BOARD).unwrap();
+    let mut alice = join(&mut hub);
+
+    let spec = alice.card_id("Write spec").unwrap();
+    let op = alice.move_card(spec, "Board", "Done").unwrap();
+
+    let (broadcast, rejected) = hub.receive(vec![op.clone()]);
+    assert_eq!(broadcast, vec![op.clone()]);
+    assert!(rejected.is_empty());
+
+    // Resending is harmless
+    let (broadcast, _) = hub.receive(vec![op]);
+    assert!(broadcast.is_empty());
+
+    // A late joiner starts from the base board plus the log
+    let bob = join(&mut hub);
+    assert_eq!(bob.replica(), 2);
+    assert_eq!(bob.to_markdown().unwrap(), alice.to_markdown().unwrap());
+
+    let invalid = Operation::SetField {
+        id: Timestamp {
+            counter: 10,
+            replica: 1,
+        },
+        card: spec,
+        field: CardField::Property("Status".into()),
+        value: "Someday".into(),
+    };
+    let (broadcast, rejected) = hub.receive(vec![invalid]);
+    assert!(broadcast.is_empty());
+    assert!(matches!(
+        &rejected[..],
+        [SyncMessage::Rejected { reason, .. }] if reason == "Invalid value for Status: Someday"
+    ));
+    assert_eq!(
+        hub.board().to_markdown().unwrap(),
+        alice.to_markdown().unwrap()
+    );
+}
+
+#[test]
+fn test_sync_message_json() {
+    let message = SyncMessage::Operations {
+        operations: vec![Operation::DeleteCard {
+            id: Timestamp {
+                counter: 3,
+                replica: 1,
+            },
+            card: Timestamp {
+                counter: 1,
+                replica: 0,
+            },
+        }],
+    };
+
+    let json = serde_json::to_string(&message).unwrap();
+    assert_eq!(
+        json,
+        r#"{"type":"operations","operations":[{"type":"delete_card","id":{"counter":3,"replica":1},"card":{"counter":1,"replica":0}}]}"#
+    );
+    assert_eq!(serde_json::from_str::<SyncMessage>(&json).unwrap(), message);
+}
@@ -0,0 +1,167 @@
+// crates/kandown/src/test/test_workspace.rs
+use std::{env, fs};
+
+use crate::*;
+
+const API: &str = r#"# Properties
+- Status: Select
+	- Todo
+	- Doing
+	- Done
+- Owner: Text
+- Blocks: Text
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+
+# Cards
+- Design endpoints
+  Status: Done
+  Owner: alice
+
+- Rate limiting
+  Status: Doing
+  Owner: bob
+  Blocks: web#login-page, web#9
+"#;
+
+const WEB: &str = r#"# Properties
+- Status: Select
+	- Todo
+	- Review
+	- Done
+- Owner: Text
+
+# Views
+- Board
+  Layout: Board
+  Group: Status
+
+# Cards
+- Login page
+  Status: Review
+  Owner: alice
+
+- Settings page
+  Status: Todo
+  Owner: alice
+"#;
+
+fn workspace() -> Workspace {
+    let mut workspace = Workspace::new();
+    for (name, markdown) in [("api", API), ("web", WEB)] {
+        let doc = ParsedDocument::try_from(markdown).unwrap();
+        workspace.insert(name, Kanban::from_document(doc).unwrap());
+    }
+    workspace
+}
+
+fn titles(cards: &[WorkspaceCard]) -> Vec<String> {
+    cards
+        .iter()
+        .map(|c| format!("{}#{}", c.board, c.card.borrow().title))
+        .collect()
+}
+
+#[test]
+fn test_references_resolve_across_boards() {
+    let workspace = workspace();
+
+    let references = workspace.references();
+    assert_eq!(references.len(), 2);
+    assert_eq!(references[0].card, "Rate limiting");
+    assert_eq!(references[0].property, "Blocks");
+    assert_eq!(references[0].resolved.as_deref(), Some("Login page"));
+    assert_eq!(references[1].target_card, "9");
+    assert_eq!(references[1].resolved, None);
+
+    // Ids and exact titles resolve as well as slugs
+    assert!(workspace.resolve("web", "1").is_some());
+    assert!(workspace.resolve("web", "Settings page").is_some());
+    assert!(workspace.resolve("mobile", "0").is_none());
+
+    let blocking = workspace.references_to("web", "Login page");
+    assert_eq!(blocking.len(), 1);
+    assert_eq!(blocking[0].board, "api");
+
+    let diagnostics = workspace.reference_diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    let (board, warnings) = &diagnostics[0];
+    assert_eq!(board, "api");
+    assert_eq!(warnings[0].code, "unresolved-reference");
+    assert_eq!(warnings[0].location.as_ref().unwrap().index, 1);
+}
+
+#[test]
+fn test_aggregated_views() {
+    let workspace = workspace();
+
+    let alice = workspace.cards_where("Owner = alice").unwrap();
+    assert_eq!(
+        titles(&alice),
+        [
+            "api#Design endpoints",
+            "web#Login page",
+            "web#Settings page"
+        ]
+    );
+
+    let on_web = workspace.cards_where("Board = web").unwrap();
+    assert_eq!(on_web.len(), 2);
+    assert!(workspace.cards_where("Owner ~ ").is_err());
+
+    // Columns merge the select options of every board
+    let columns: Vec<(String, Vec<String>)> = workspace
+        .grouped_by("Status", "Owner = alice")
+        .unwrap()
+        .into_iter()
+        .map(|(column, cards)| (column, titles(&cards)))
+        .collect();
+    assert_eq!(
+        columns,
+        [
+            ("Todo".to_string(), vec!["web#Settings page".to_string()]),
+            ("Done".to_string(), vec!["api#Design endpoints".to_string()]),
+            ("Review".to_string(), vec!["web#Login page".to_string()]),
+        ]
+    );
+}
+
+#[test]
+fn test_load_directory() {
+    let dir = env::temp_dir().join(format!("kandown-workspace-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("api.md"), API).unwrap();
+    fs::write(dir.join("web.md"), WEB).unwrap();
+    fs::write(dir.join("README.md"), "Some notes\n").unwrap();
+    fs::write(dir.join("notes.txt"), "Not a board").unwrap();
+
+    let (workspace, diagnostics) = Workspace::load(&dir).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(workspace.boards.keys().collect::<Vec<_>>(), ["api", "web"]);
+    assert_eq!(workspace.paths["web"], dir.join("web.md"));
+
+    let codes: Vec<(String, &str, Option<usize>)> = diagnostics
+        .iter()
+        .flat_map(|(path, diagnostics)| {
+            let file = path.file_name().unwrap().to_string_lossy().to_string();
+            diagnostics.iter().map(move |d| {
+                (
+                    file.clone(),
+                    d.code.as_str(),
+                    d.location.as_ref().and_then(|l| l.line),
+                )
+            })
+        })
+        .collect();
+    assert_eq!(
+        codes,
+        [
+            ("README.md".to_string(), "invalid-board", None),
+            ("api.md".to_string(), "unresolved-reference", Some(19)),
+        ]
+    );
+}
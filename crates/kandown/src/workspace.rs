@@ -0,0 +1,318 @@
+// crates/kandown/src/workspace.rs
+
+use anyhow::{Context, Result, anyhow};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use crate::{
+    Diagnostic, DocumentSection, Kanban, KanbanCard, KanbanPropertyType, ParsedDocument,
+    ViewFilter, locate_diagnostics,
+};
+
+/// A set of boards, each named after its file, whose cards can refer to cards
+/// on other boards as `board#card`
+#[derive(Default)]
+pub struct Workspace {
+    pub boards: BTreeMap<String, Kanban>,
+    pub paths: BTreeMap<String, PathBuf>, // Source file of each board loaded from disk
+}
+
+// Diagnostics for one file of a workspace
+pub type FileDiagnostics = (PathBuf, Vec<Diagnostic>);
+
+/// A card together with the name of the board it is on
+#[derive(Clone)]
+pub struct WorkspaceCard {
+    pub board: String,
+    pub card: Rc<RefCell<KanbanCard>>,
+}
+
+/// A reference from a card's property value to a card on another board
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardReference {
+    pub board: String, // Board and card holding the reference
+    pub card: String,
+    pub property: String,
+    pub target_board: String,
+    pub target_card: String, // As written: a card id, title or title slug
+    pub resolved: Option<String>, // Title of the referenced card, if it exists
+}
+
+impl Workspace {
+    pub fn new() -> Self {
+        Workspace::default()
+    }
+
+    pub fn insert(&mut self, name: &str, board: Kanban) {
+        self.boards.insert(name.to_string(), board);
+    }
+
+    // Load every `.md` file in a directory as a board named after the file.
+    // Files that are not valid boards are skipped with an error diagnostic;
+    // board warnings and unresolved references come back as well, by file.
+    pub fn load(dir: &Path) -> Result<(Workspace, Vec<FileDiagnostics>)> {
+        let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+            .with_context(|| format!("Failed to read {}", dir.display()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "md"))
+            .collect();
+        paths.sort();
+
+        let mut workspace = Workspace::new();
+        let mut sources = HashMap::new();
+        let mut diagnostics = Vec::new();
+
+        for path in paths {
+            let Some(name) = path.file_stem().map(|s| s.to_string_lossy().to_string()) else {
+                continue;
+            };
+            let markdown = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+
+            let loaded = ParsedDocument::parse_complete(&markdown)
+                .and_then(Kanban::from_document_with_diagnostics);
+            match loaded {
+                Ok((board, mut warnings)) => {
+                    locate_diagnostics(&markdown, &mut warnings);
+                    if !warnings.is_empty() {
+                        diagnostics.push((path.clone(), warnings));
+                    }
+                    workspace.boards.insert(name.clone(), board);
+                    workspace.paths.insert(name.clone(), path);
+                    sources.insert(name, markdown);
+                }
+                Err(e) => diagnostics.push((
+                    path,
+                    vec![Diagnostic::error(
+                        "invalid-board",
+                        format!("Not loaded: {e:#}"),
+                    )],
+                )),
+            }
+        }
+
+        for (name, mut warnings) in workspace.reference_diagnostics() {
+            locate_diagnostics(&sources[&name], &mut warnings);
+            let path = workspace.paths[&name].clone();
+            match diagnostics.iter_mut().find(|(p, _)| *p == path) {
+                Some((_, existing)) => existing.extend(warnings),
+                None => diagnostics.push((path, warnings)),
+            }
+        }
+
+        Ok((workspace, diagnostics))
+    }
+
+    // Find a card on a board by id, exact title or title slug
+    pub fn resolve(&self, board: &str, card: &str) -> Option<Rc<RefCell<KanbanCard>>> {
+        let kanban = self.boards.get(board)?;
+
+        if let Ok(id) = card.parse::<usize>()
+            && let Some(card_rc) = kanban.card_by_id.get(&id)
+        {
+            return Some(card_rc.clone());
+        }
+        if let Some(card_rc) = kanban.card_by_title.get(card) {
+            return Some(card_rc.clone());
+        }
+
+        let slug = slugify(card);
+        kanban
+            .cards
+            .iter()
+            .find(|card_rc| slugify(&card_rc.borrow().title) == slug)
+            .cloned()
+    }
+
+    // Every `board#card` reference in card values across the workspace. A
+    // value may hold several references separated by commas.
+    pub fn references(&self) -> Vec<CardReference> {
+        let mut references = Vec::new();
+
+        for (board_name, board) in &self.boards {
+            for card_rc in &board.cards {
+                let card = card_rc.borrow();
+                for (prop_rc, value) in &card.properties {
+                    for (target_board, target_card) in value.split(',').filter_map(parse_reference)
+                    {
+                        references.push(CardReference {
+                            board: board_name.clone(),
+                            card: card.title.clone(),
+                            property: prop_rc.borrow().name.clone(),
+                            target_board: target_board.to_string(),
+                            target_card: target_card.to_string(),
+                            resolved: self
+                                .resolve(target_board, target_card)
+                                .map(|target| target.borrow().title.clone()),
+                        });
+                    }
+                }
+            }
+        }
+
+        references
+    }
+
+    // References to a card, e.g. everything on other boards it blocks
+    pub fn references_to(&self, board: &str, title: &str) -> Vec<CardReference> {
+        self.references()
+            .into_iter()
+            .filter(|r| r.target_board == board && r.resolved.as_deref() == Some(title))
+            .collect()
+    }
+
+    // Warnings for references to boards or cards that do not exist, by board
+    pub fn reference_diagnostics(&self) -> Vec<(String, Vec<Diagnostic>)> {
+        let mut by_board: BTreeMap<String, Vec<Diagnostic>> = BTreeMap::new();
+
+        for reference in self.references() {
+            if reference.resolved.is_some() {
+                continue;
+            }
+
+            let message = if self.boards.contains_key(&reference.target_board) {
+                format!(
+                    "Card '{}' refers to '{}#{}' in {}, but board '{}' has no such card",
+                    reference.card,
+                    reference.target_board,
+                    reference.target_card,
+                    reference.property,
+                    reference.target_board
+                )
+            } else {
+                format!(
+                    "Card '{}' refers to '{}#{}' in {}, but there is no board '{}'",
+                    reference.card,
+                    reference.target_board,
+                    reference.target_card,
+                    reference.property,
+                    reference.target_board
+                )
+            };
+            let index = self.boards[&reference.board].card_by_title[&reference.card]
+                .borrow()
+                .id;
+
+            by_board.entry(reference.board).or_default().push(
+                Diagnostic::warning("unresolved-reference", message).at(
+                    DocumentSection::Cards,
+                    index,
+                    &reference.card,
+                ),
+            );
+        }
+
+        by_board.into_iter().collect()
+    }
+
+    // Cards on any board that pass a view filter. `Board` can be used in the
+    // filter as the name of the card's board.
+    pub fn cards_where(&self, filter: &str) -> Result<Vec<WorkspaceCard>> {
+        let filter = if filter.trim().is_empty() {
+            None
+        } else {
+            Some(ViewFilter::parse(filter).map_err(|e| anyhow!("Invalid filter: {e}"))?)
+        };
+
+        let mut cards = Vec::new();
+        for (board_name, board) in &self.boards {
+            for card_rc in &board.cards {
+                let card = card_rc.borrow();
+                let matches = filter.as_ref().is_none_or(|filter| {
+                    filter.matches(|name| {
+                        board
+                            .field_value(&card, name)
+                            .or_else(|| (name == "Board").then_some(board_name.into()))
+                    })
+                });
+                if matches {
+                    cards.push(WorkspaceCard {
+                        board: board_name.clone(),
+                        card: card_rc.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(cards)
+    }
+
+    // Cards passing a filter grouped by a property, like a board view spanning
+    // the whole workspace. Columns follow the select options of the boards in
+    // name order, then other values sorted, then cards without a value.
+    pub fn grouped_by(
+        &self,
+        property: &str,
+        filter: &str,
+    ) -> Result<Vec<(String, Vec<WorkspaceCard>)>> {
+        let mut groups: HashMap<String, Vec<WorkspaceCard>> = HashMap::new();
+        for card in self.cards_where(filter)? {
+            let value = match property {
+                "Board" => card.board.clone(),
+                _ => card
+                    .card
+                    .borrow()
+                    .get_value(property)
+                    .unwrap_or_default()
+                    .to_string(),
+            };
+            groups.entry(value).or_default().push(card);
+        }
+
+        let mut column_order: Vec<String> = Vec::new();
+        for board in self.boards.values() {
+            if let Some(prop_rc) = board.property_by_name.get(property)
+                && let KanbanPropertyType::Select { options } = &prop_rc.borrow().property_type
+            {
+                for option in options {
+                    if !column_order.contains(option) {
+                        column_order.push(option.clone());
+                    }
+                }
+            }
+        }
+
+        let mut others: Vec<String> = groups
+            .keys()
+            .filter(|key| !key.is_empty() && !column_order.contains(key))
+            .cloned()
+            .collect();
+        others.sort();
+        column_order.extend(others);
+        column_order.push(String::new());
+
+        Ok(column_order
+            .into_iter()
+            .filter_map(|column| groups.remove(&column).map(|cards| (column, cards)))
+            .collect())
+    }
+}
+
+// Split `board#card` into its parts. Values with spaces around the `#`, or
+// without a board name, are ordinary text rather than references.
+fn parse_reference(value: &str) -> Option<(&str, &str)> {
+    let (board, card) = value.trim().split_once('#')?;
+    let is_name = |s: &str| {
+        !s.is_empty()
+            && s.chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    };
+
+    (is_name(board) && !card.is_empty() && !card.starts_with(' ')).then_some((board, card))
+}
+
+// Lowercase a title and join its words with dashes, e.g. "Write the spec"
+// becomes "write-the-spec"
+fn slugify(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
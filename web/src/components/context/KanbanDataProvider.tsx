@@ -8,6 +8,7 @@ interface KanbanDataContextType {
     currentView: string
     viewsList: string[]
     boardData: KanbanViewData | null
+    diagnostics: KanbanViewData['diagnostics']
     loading: boolean
     markdown: string
     setCurrentView: (view: string) => void
@@ -36,6 +37,7 @@ export function KanbanDataProvider({board, children}: KanbanDataProviderProps) {
     const [boardData, setBoardData] = useState<KanbanViewData | null>(null)
     const [loading, setLoading] = useState(true)
     const [markdown, setMarkdown] = useState('')
+    const [diagnostics, setDiagnostics] = useState<KanbanViewData['diagnostics']>([])
 
     // Initialize data when the board changes
     useEffect(() => {
@@ -48,10 +50,21 @@ export function KanbanDataProvider({board, children}: KanbanDataProviderProps) {
             console.debug('[KanbanDataProvider] Available views:', views)
             setViewsList(views)
 
-            if (views.length > 0) {
+            // Open the board's default view if it names one that exists
+            const metadata = JSON.parse(board.getMetadata())
+            if (views.includes(metadata.defaultView)) {
+                setCurrentView(metadata.defaultView)
+            } else if (views.length > 0) {
                 setCurrentView(views[0])
             }
 
+            // Warnings from building the board (unknown properties and the like)
+            const boardDiagnostics = JSON.parse(board.getDiagnostics())
+            boardDiagnostics.forEach((diagnostic: any) =>
+                console.warn(`[KanbanDataProvider] ${diagnostic.code}: ${diagnostic.message}`),
+            )
+            setDiagnostics(boardDiagnostics)
+
             // Get initial markdown representation
             setMarkdown(board.getMarkdown())
             setLoading(false)
@@ -162,6 +175,7 @@ export function KanbanDataProvider({board, children}: KanbanDataProviderProps) {
         currentView,
         viewsList,
         boardData,
+        diagnostics,
         loading,
         markdown,
         setCurrentView,